@@ -0,0 +1,175 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks comparing `exn` with other error handling crates.
+//!
+//! Each group measures the same operation with every crate. `anyhow` has no notion of an error
+//! tree, so the aggregation group only measures `exn` and `error-stack`.
+//!
+//! Run with `cargo bench -p benches --bench compare`.
+
+use std::fmt::Write;
+use std::hint::black_box;
+
+use anyhow::Context;
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use exn::Exn;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn exn_chain(depth: usize) -> Exn<Error> {
+    let mut exn = Exn::new(black_box(Error("leaf")));
+    for _ in 0..depth {
+        exn = exn.raise(Error("layer"));
+    }
+    exn
+}
+
+fn anyhow_chain(depth: usize) -> anyhow::Error {
+    let mut err = anyhow::Error::new(black_box(Error("leaf")));
+    for _ in 0..depth {
+        err = err.context(Error("layer"));
+    }
+    err
+}
+
+fn error_stack_chain(depth: usize) -> error_stack::Report<Error> {
+    let mut report = error_stack::Report::new(black_box(Error("leaf")));
+    for _ in 0..depth {
+        report = report.change_context(Error("layer"));
+    }
+    report
+}
+
+fn create(c: &mut Criterion) {
+    let mut group = c.benchmark_group("create");
+    group.bench_function("exn", |b| b.iter(|| exn_chain(0)));
+    group.bench_function("anyhow", |b| b.iter(|| anyhow_chain(0)));
+    group.bench_function("error-stack", |b| b.iter(|| error_stack_chain(0)));
+    group.finish();
+}
+
+fn raise(c: &mut Criterion) {
+    let mut group = c.benchmark_group("raise_1");
+    group.bench_function("exn", |b| b.iter(|| exn_chain(1)));
+    group.bench_function("anyhow", |b| b.iter(|| anyhow_chain(1)));
+    group.bench_function("error-stack", |b| b.iter(|| error_stack_chain(1)));
+    group.finish();
+
+    let mut group = c.benchmark_group("raise_5");
+    group.bench_function("exn", |b| b.iter(|| exn_chain(5)));
+    group.bench_function("anyhow", |b| b.iter(|| anyhow_chain(5)));
+    group.bench_function("error-stack", |b| b.iter(|| error_stack_chain(5)));
+    group.finish();
+}
+
+fn result(c: &mut Criterion) {
+    fn fail() -> Result<(), Error> {
+        Err(black_box(Error("leaf")))
+    }
+
+    let mut group = c.benchmark_group("or_raise");
+    group.bench_function("exn", |b| {
+        b.iter(|| {
+            use exn::ResultExt;
+            fail().or_raise(|| Error("layer"))
+        })
+    });
+    group.bench_function("anyhow", |b| b.iter(|| fail().context("layer")));
+    group.bench_function("error-stack", |b| {
+        b.iter(|| {
+            use error_stack::ResultExt;
+            fail().change_context(Error("layer"))
+        })
+    });
+    group.finish();
+}
+
+fn aggregate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("aggregate_8");
+    group.bench_function("exn", |b| {
+        b.iter(|| Exn::raise_all(Error("parent"), (0..8).map(|_| exn_chain(1))))
+    });
+    group.bench_function("error-stack", |b| {
+        b.iter(|| {
+            let children = (0..8).map(|_| error_stack_chain(1));
+            let report: Option<error_stack::Report<[Error]>> = children.collect();
+            report.map(|report| report.change_context(Error("parent")))
+        })
+    });
+    group.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let exn = exn_chain(5);
+    let anyhow = anyhow_chain(5);
+    let error_stack = error_stack_chain(5);
+    let mut buf = String::new();
+
+    let mut group = c.benchmark_group("format_display");
+    group.bench_function("exn", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{exn:#}")
+        })
+    });
+    group.bench_function("anyhow", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{anyhow:#}")
+        })
+    });
+    group.bench_function("error-stack", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{error_stack:#}")
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("format_debug");
+    group.bench_function("exn", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{exn:?}")
+        })
+    });
+    group.bench_function("anyhow", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{anyhow:?}")
+        })
+    });
+    group.bench_function("error-stack", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{error_stack:?}")
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, create, raise, result, aggregate, format);
+criterion_main!(benches);
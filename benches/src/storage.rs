@@ -0,0 +1,87 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks of the storage of exception trees.
+//!
+//! Most exceptions are linear chains that are created and dropped without ever being formatted,
+//! so these benchmarks measure exactly that.
+//!
+//! Run with `cargo bench -p benches --bench storage`.
+
+use std::hint::black_box;
+
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use exn::Exn;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug)]
+struct Unit;
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unit error")
+    }
+}
+
+impl std::error::Error for Unit {}
+
+fn storage(c: &mut Criterion) {
+    c.bench_function("new", |b| b.iter(|| Exn::new(black_box(Error("leaf")))));
+
+    c.bench_function("chain_1", |b| {
+        b.iter(|| Exn::new(black_box(Error("leaf"))).raise(Error("layer")))
+    });
+
+    c.bench_function("chain_5", |b| {
+        b.iter(|| {
+            let mut exn = Exn::new(black_box(Error("leaf")));
+            for _ in 0..5 {
+                exn = exn.raise(Error("layer"));
+            }
+            exn
+        })
+    });
+
+    c.bench_function("chain_5_zero_sized", |b| {
+        b.iter(|| {
+            let mut exn = Exn::new(black_box(Unit));
+            for _ in 0..5 {
+                exn = exn.raise(Unit);
+            }
+            exn
+        })
+    });
+
+    c.bench_function("tree_4", |b| {
+        b.iter(|| {
+            let children = (0..4).map(|_| Exn::new(black_box(Error("leaf"))));
+            Exn::raise_all(Error("parent"), children)
+        })
+    });
+}
+
+criterion_group!(benches, storage);
+criterion_main!(benches);
@@ -17,19 +17,24 @@
 //! This example shows how a library can:
 //! - Use `exn::Result` internally for context-rich errors.
 //! - Downcast internal errors at the public API boundary to produce a flat, machine-friendly
-//!   `LibError`.
+//!   `LibError`, with a `BoundaryMap` table of rules.
 //! - Return `Exn<LibError>` so context is preserved in frames while internal errors stay private.
 
 use std::error::Error;
+use std::sync::LazyLock;
+use std::time::Duration;
 
 use derive_more::Display;
-use exn::Exn;
-use exn::Frame;
+use exn::BoundaryMap;
+use exn::Kinded;
 use exn::Result;
 use exn::ResultExt;
+use exn::Retryable;
 use exn::bail;
 
 fn main() {
+    library::init();
+
     demo(429);
     eprintln!();
     demo(404);
@@ -46,8 +51,8 @@ fn demo(user_id: u64) {
                 return;
             }
             Err(err) => {
-                // Retry for errors the library marks as retryable.
-                if attempt < 3 && err.is_retryable() {
+                // Retry for errors the library marks as retryable, anywhere in the tree.
+                if attempt < 3 && err.retry_hint().is_some() {
                     eprintln!("{}", err);
                     eprintln!("Retryable error, attempting retry #{}", attempt + 1);
                     eprintln!();
@@ -92,14 +97,6 @@ mod library {
     }
 
     impl LibError {
-        pub fn kind(&self) -> LibErrorKind {
-            self.kind
-        }
-
-        pub fn is_retryable(&self) -> bool {
-            matches!(self.kind, LibErrorKind::RateLimited)
-        }
-
         fn not_found(resource: &'static str, id: u64) -> Self {
             Self {
                 kind: LibErrorKind::NotFound,
@@ -124,41 +121,46 @@ mod library {
 
     impl Error for LibError {}
 
+    impl Kinded for LibError {
+        type Kind = LibErrorKind;
+
+        fn kind(&self) -> LibErrorKind {
+            self.kind
+        }
+    }
+
+    impl Retryable for LibError {
+        fn retry_after(&self) -> Option<Duration> {
+            match self.kind {
+                LibErrorKind::RateLimited => Some(Duration::ZERO),
+                LibErrorKind::NotFound | LibErrorKind::Internal => None,
+            }
+        }
+    }
+
+    /// Register the error types of the library with `exn`.
+    pub fn init() {
+        exn::register_retryable::<LibError>();
+    }
+
     /// Public API: returns `Exn<LibError>` while keeping internal errors private.
     pub fn fetch_profile(user_id: u64) -> Result<Profile, LibError> {
-        // Explicit boundary mapping: downcast internal errors into a flat `LibError`.
-        service::fetch_profile(user_id).map_err(map_to_lib_error)
+        // Context stays in frames; only `LibError` is public.
+        service::fetch_profile(user_id).map_err(|err| BOUNDARY.apply(err))
     }
 
-    fn map_to_lib_error(err: Exn<service::ServiceError>) -> Exn<LibError> {
-        let lib_error = if let Some(db_error) = find_error::<db::DbError>(&err) {
-            match db_error {
+    // Explicit boundary mapping: downcast internal errors into a flat `LibError`.
+    static BOUNDARY: LazyLock<BoundaryMap<LibError>> = LazyLock::new(|| {
+        BoundaryMap::new(|| LibError::internal("unexpected library error"))
+            .rule(|err: &db::DbError| match err {
                 db::DbError::NotFound { user_id } => LibError::not_found("user", *user_id),
                 db::DbError::ConnectionDropped => LibError::rate_limited(),
-            }
-        } else if let Some(http_error) = find_error::<http::HttpError>(&err) {
-            match http_error {
+            })
+            .rule(|err: &http::HttpError| match err {
                 http::HttpError::RateLimited => LibError::rate_limited(),
                 http::HttpError::Unavailable => LibError::internal("upstream service unavailable"),
-            }
-        } else {
-            LibError::internal("unexpected library error")
-        };
-
-        // Context stays in frames; only `LibError` is public.
-        err.raise(lib_error)
-    }
-
-    fn find_error<T: Error + 'static>(exn: &Exn<impl Error + Send + Sync>) -> Option<&T> {
-        fn walk<T: Error + 'static>(frame: &Frame) -> Option<&T> {
-            if let Some(err) = frame.error().downcast_ref::<T>() {
-                return Some(err);
-            }
-            frame.children().iter().find_map(walk::<T>)
-        }
-
-        walk(exn.frame())
-    }
+            })
+    });
 
     mod service {
         use super::*;
@@ -251,16 +253,16 @@ mod library {
 // Retryable error, attempting retry #3
 //
 // Action: Retried too many times, aborting
-// Error: RateLimited: rate limited by upstream, at examples/src/library-boundary.rs:149:13
+// Error: RateLimited: rate limited by upstream, at examples/src/library-boundary.rs:149:64
 // |
-// |-> failed to fetch profile for user 429, at examples/src/library-boundary.rs:170:55
+// |-> failed to fetch profile for user 429, at examples/src/library-boundary.rs:172:55
 // |
-// |-> HTTP 429: too many requests, at examples/src/library-boundary.rs:218:24
+// |-> HTTP 429: too many requests, at examples/src/library-boundary.rs:220:24
 //
 // Start demo for user: 404
 // Action: Return 404
-// Error: NotFound: user 404 not found, at examples/src/library-boundary.rs:149:13
+// Error: NotFound: user 404 not found, at examples/src/library-boundary.rs:149:64
 // |
-// |-> failed to fetch profile for user 404, at examples/src/library-boundary.rs:169:47
+// |-> failed to fetch profile for user 404, at examples/src/library-boundary.rs:171:47
 // |
-// |-> no row for user_id 404, at examples/src/library-boundary.rs:189:24
+// |-> no row for user_id 404, at examples/src/library-boundary.rs:191:24
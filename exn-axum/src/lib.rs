@@ -0,0 +1,165 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`axum`](https://docs.rs/axum) responses for [`exn`](https://docs.rs/exn) exceptions.
+//!
+//! Handlers return [`HttpExn<E, P>`], which converts from an [`Exn<E>`] with `?` and implements
+//! [`IntoResponse`]. The status code is chosen by the [`StatusPolicy`] `P`, typically matching on
+//! the kind of the flat boundary error `E`. The body is the exception tree in debug builds, with
+//! [sensitive](Exn::mark_sensitive) frames redacted, and only the canonical reason of the status
+//! code in release builds.
+//!
+//! # Examples
+//!
+//! ```
+//! use exn::Exn;
+//! use exn_axum::HttpExn;
+//! use exn_axum::StatusPolicy;
+//! use http::StatusCode;
+//!
+//! #[derive(Debug)]
+//! enum ApiError {
+//!     NotFound,
+//!     Internal,
+//! }
+//!
+//! impl core::fmt::Display for ApiError {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "{self:?}")
+//!     }
+//! }
+//!
+//! impl core::error::Error for ApiError {}
+//!
+//! struct ApiPolicy;
+//!
+//! impl StatusPolicy<ApiError> for ApiPolicy {
+//!     fn status(exn: &Exn<ApiError>) -> StatusCode {
+//!         match **exn {
+//!             ApiError::NotFound => StatusCode::NOT_FOUND,
+//!             ApiError::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+//!         }
+//!     }
+//! }
+//!
+//! fn find_user(id: u64) -> exn::Result<String, ApiError> {
+//!     exn::bail!(ApiError::NotFound)
+//! }
+//!
+//! // a handler for `axum::routing::get`
+//! async fn get_user() -> Result<String, HttpExn<ApiError, ApiPolicy>> {
+//!     let user = find_user(42)?;
+//!     Ok(user)
+//! }
+//! ```
+
+#![deny(missing_docs)]
+
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use axum_core::response::IntoResponse;
+use axum_core::response::Response;
+use exn::Exn;
+use exn::report::Compact;
+use http::HeaderValue;
+use http::StatusCode;
+use http::header::CONTENT_TYPE;
+
+/// A policy choosing the status code of the response to an exception.
+pub trait StatusPolicy<E: Error + Send + Sync + 'static> {
+    /// Return the status code of the response to `exn`.
+    fn status(exn: &Exn<E>) -> StatusCode;
+}
+
+/// The default [`StatusPolicy`], responding with `500 Internal Server Error` to every exception.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InternalServerError;
+
+impl<E: Error + Send + Sync + 'static> StatusPolicy<E> for InternalServerError {
+    fn status(_: &Exn<E>) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
+
+/// An exception that can be returned from `axum` handlers.
+///
+/// See the [crate documentation](crate) for more information.
+pub struct HttpExn<E: Error + Send + Sync + 'static, P = InternalServerError> {
+    exn: Exn<E>,
+    policy: PhantomData<fn() -> P>,
+}
+
+impl<E: Error + Send + Sync + 'static, P> HttpExn<E, P> {
+    /// Create a response to the exception `exn`.
+    pub fn new(exn: Exn<E>) -> Self {
+        Self {
+            exn,
+            policy: PhantomData,
+        }
+    }
+
+    /// Return the exception.
+    pub fn exn(&self) -> &Exn<E> {
+        &self.exn
+    }
+
+    /// Extract the exception.
+    pub fn into_exn(self) -> Exn<E> {
+        self.exn
+    }
+}
+
+impl<E: Error + Send + Sync + 'static, P> From<Exn<E>> for HttpExn<E, P> {
+    fn from(exn: Exn<E>) -> Self {
+        Self::new(exn)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static, P> fmt::Debug for HttpExn<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.exn, f)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static, P> fmt::Display for HttpExn<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.exn, f)
+    }
+}
+
+impl<E, P> IntoResponse for HttpExn<E, P>
+where
+    E: Error + Send + Sync + 'static,
+    P: StatusPolicy<E>,
+{
+    fn into_response(self) -> Response {
+        let status = P::status(&self.exn);
+        let body = if cfg!(debug_assertions) {
+            Compact::new(self.exn.frame())
+                .with_redaction(true)
+                .to_string()
+        } else {
+            status.canonical_reason().unwrap_or_default().to_string()
+        };
+
+        let mut response = (status, body).into_response();
+        response.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; charset=utf-8"),
+        );
+        response
+    }
+}
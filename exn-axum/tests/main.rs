@@ -0,0 +1,108 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum_core::response::IntoResponse;
+use exn::Exn;
+use exn_axum::HttpExn;
+use exn_axum::StatusPolicy;
+use http::StatusCode;
+use http_body_util::BodyExt;
+
+#[derive(Debug)]
+enum ApiError {
+    NotFound,
+    Internal,
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::NotFound => write!(f, "user not found"),
+            ApiError::Internal => write!(f, "internal error"),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+struct ApiPolicy;
+
+impl StatusPolicy<ApiError> for ApiPolicy {
+    fn status(exn: &Exn<ApiError>) -> StatusCode {
+        match **exn {
+            ApiError::NotFound => StatusCode::NOT_FOUND,
+            ApiError::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+fn body(response: axum_core::response::Response) -> String {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let bytes = runtime
+        .block_on(response.into_body().collect())
+        .unwrap()
+        .to_bytes();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn find_user(id: u64) -> exn::Result<String, ApiError> {
+    match id {
+        0 => exn::bail!(ApiError::NotFound),
+        _ => Ok(format!("user {id}")),
+    }
+}
+
+fn handler(id: u64) -> Result<String, HttpExn<ApiError, ApiPolicy>> {
+    Ok(find_user(id)?)
+}
+
+#[test]
+fn status_policy() {
+    assert_eq!(handler(42).unwrap(), "user 42");
+
+    let response = handler(0).unwrap_err().into_response();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert!(body(response).starts_with("user not found, at exn-axum/tests/main.rs:"));
+
+    let exn = Exn::new(ApiError::Internal);
+    let response = HttpExn::<_, ApiPolicy>::from(exn).into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn default_policy() {
+    let exn: HttpExn<ApiError> = Exn::new(ApiError::NotFound).into();
+    let response = exn.into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/plain; charset=utf-8"
+    );
+}
+
+#[test]
+fn redacted_body() {
+    let exn = Exn::new(ApiError::Internal)
+        .attach("token=hunter2")
+        .mark_sensitive()
+        .raise(ApiError::NotFound);
+    let response = HttpExn::<_, ApiPolicy>::from(exn).into_response();
+    let body = body(response);
+    assert!(body.starts_with("user not found"), "{body}");
+    assert!(body.contains("«redacted»"), "{body}");
+    assert!(!body.contains("internal error"), "{body}");
+    assert!(!body.contains("hunter2"), "{body}");
+}
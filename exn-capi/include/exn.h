@@ -0,0 +1,75 @@
+/*
+ * Copyright 2025 FastLabs Developers
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * C API for exn exception trees.
+ *
+ * Strings returned by this API are owned by the caller and must be freed with
+ * exn_string_free(). Frames are borrowed from the exception owning them and are
+ * valid until it is freed with exn_free().
+ */
+
+#ifndef EXN_H
+#define EXN_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An exception tree. */
+typedef struct exn_t exn_t;
+
+/* A frame of an exception tree. */
+typedef struct exn_frame_t exn_frame_t;
+
+/* Create a new exception; `file` may be NULL. Returns NULL if `message` is NULL. */
+exn_t *exn_new(const char *message, const char *file, uint32_t line);
+
+/* Raise a new exception, making `exn` its child; `exn` is consumed. */
+exn_t *exn_raise(exn_t *exn, const char *message, const char *file, uint32_t line);
+
+/* Free an exception and all its frames. */
+void exn_free(exn_t *exn);
+
+/* Return the topmost frame of an exception. */
+const exn_frame_t *exn_frame(const exn_t *exn);
+
+/* Render the whole exception tree. */
+char *exn_debug(const exn_t *exn);
+
+/* Inspect a frame. */
+char *exn_frame_message(const exn_frame_t *frame);
+char *exn_frame_file(const exn_frame_t *frame);
+uint32_t exn_frame_line(const exn_frame_t *frame);
+uint32_t exn_frame_column(const exn_frame_t *frame);
+size_t exn_frame_children_count(const exn_frame_t *frame);
+const exn_frame_t *exn_frame_child(const exn_frame_t *frame, size_t index);
+
+/* Free a string returned by this API. */
+void exn_string_free(char *string);
+
+/* Create and raise exceptions located at the caller. */
+#define EXN_NEW(message) exn_new((message), __FILE__, __LINE__)
+#define EXN_RAISE(exn, message) exn_raise((exn), (message), __FILE__, __LINE__)
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* EXN_H */
@@ -0,0 +1,429 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A C API for [`exn`](https://docs.rs/exn) exception trees.
+//!
+//! C code, and bindings built on C such as Python extensions, handle exceptions through an opaque
+//! `exn_t` owning an exception tree, and inspect its frames through borrowed `exn_frame_t`
+//! pointers. The declarations are in `include/exn.h`:
+//!
+//! * [`exn_new`] and [`exn_raise`] create exceptions, and [`exn_free`] frees them.
+//! * [`exn_frame`] and [`exn_frame_child`] walk the frames of an exception tree.
+//! * [`exn_frame_message`], [`exn_frame_file`], [`exn_frame_line`], [`exn_frame_column`] and
+//!   [`exn_frame_children_count`] inspect a frame.
+//! * [`exn_debug`] renders the whole exception tree.
+//!
+//! Strings returned by this API are owned by the caller and must be freed with
+//! [`exn_string_free`].
+//!
+//! Rust code hands its exceptions over to C with [`into_raw`].
+//!
+//! # Locations
+//!
+//! Rust records the location of a frame at compile time, so frames created from C cannot have a
+//! location of their own. Instead, [`exn_new`] and [`exn_raise`] take the location from the
+//! caller, e.g., `__FILE__` and `__LINE__`, and keep it on their [`CError`];
+//! [`exn_frame_file`] and friends report it in place of the location of the frame.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::ffi::CStr;
+//!
+//! use exn_capi::*;
+//!
+//! unsafe {
+//!     let exn = exn_new(c"connection reset".as_ptr(), c"net.c".as_ptr(), 42);
+//!     let exn = exn_raise(exn, c"failed to fetch".as_ptr(), c"main.c".as_ptr(), 7);
+//!
+//!     let frame = exn_frame(exn);
+//!     assert_eq!(exn_frame_children_count(frame), 1);
+//!
+//!     let child = exn_frame_child(frame, 0);
+//!     let message = exn_frame_message(child);
+//!     assert_eq!(CStr::from_ptr(message).to_str(), Ok("connection reset"));
+//!     assert_eq!(exn_frame_line(child), 42);
+//!
+//!     exn_string_free(message);
+//!     exn_free(exn);
+//! }
+//! ```
+
+#![deny(missing_docs)]
+#![allow(non_camel_case_types)]
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::ffi::c_char;
+use std::fmt;
+use std::ptr;
+
+use exn::Exn;
+use exn::Frame;
+use exn::FrameLocation;
+
+/// An exception tree owned by C code.
+///
+/// Created with [`exn_new`], [`exn_raise`] or [`into_raw`], and freed with [`exn_free`].
+pub struct exn_t {
+    tree: Box<dyn Tree>,
+}
+
+/// A frame of an exception tree, borrowed from the [`exn_t`] owning the tree.
+#[repr(transparent)]
+pub struct exn_frame_t(Frame);
+
+/// An error created from C code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CError {
+    message: String,
+    file: Option<String>,
+    line: u32,
+}
+
+impl CError {
+    /// Return the message of this error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Return the file of the C code that created this error, if given.
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    /// Return the line of the C code that created this error.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+}
+
+impl fmt::Display for CError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for CError {}
+
+/// An exception tree with its top-level error type erased.
+trait Tree: Send + Sync + 'static {
+    fn frame(&self) -> &Frame;
+
+    fn raise(self: Box<Self>, error: CError) -> Exn<CError>;
+}
+
+impl<E: Error + Send + Sync + 'static> Tree for Exn<E> {
+    fn frame(&self) -> &Frame {
+        Exn::frame(self)
+    }
+
+    fn raise(self: Box<Self>, error: CError) -> Exn<CError> {
+        Exn::raise(*self, error)
+    }
+}
+
+/// Hand `exn` over to C code, which becomes responsible for freeing it with [`exn_free`].
+pub fn into_raw<E: Error + Send + Sync + 'static>(exn: Exn<E>) -> *mut exn_t {
+    Box::into_raw(Box::new(exn_t {
+        tree: Box::new(exn),
+    }))
+}
+
+/// Create a new exception with the given message, created at `file` and `line`.
+///
+/// `file` may be null, in which case reports print the location as `<unknown>:line`. Returns null
+/// if `message` is null.
+///
+/// # Safety
+///
+/// `message` and `file` must be null or point to nul-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_new(
+    message: *const c_char,
+    file: *const c_char,
+    line: u32,
+) -> *mut exn_t {
+    // SAFETY: guaranteed by the caller.
+    match unsafe { new_error(message, file, line) } {
+        Some(error) => into_raw(located(Exn::new(error))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Raise a new exception with the given message, created at `file` and `line`; this makes `exn`
+/// the child of the new exception.
+///
+/// `exn` is consumed, even if null is returned. `file` may be null, in which case reports print the
+/// location as `<unknown>:line`. Returns null if `exn` or `message` is null.
+///
+/// # Safety
+///
+/// `exn` must be null or an exception that has not been freed. `message` and `file` must be null
+/// or point to nul-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_raise(
+    exn: *mut exn_t,
+    message: *const c_char,
+    file: *const c_char,
+    line: u32,
+) -> *mut exn_t {
+    if exn.is_null() {
+        return ptr::null_mut();
+    }
+    // SAFETY: guaranteed by the caller.
+    let exn = unsafe { Box::from_raw(exn) };
+    // SAFETY: guaranteed by the caller.
+    match unsafe { new_error(message, file, line) } {
+        Some(error) => into_raw(located(exn.tree.raise(error))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Free an exception and all its frames.
+///
+/// Does nothing if `exn` is null.
+///
+/// # Safety
+///
+/// `exn` must be null or an exception that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_free(exn: *mut exn_t) {
+    if !exn.is_null() {
+        // SAFETY: guaranteed by the caller.
+        drop(unsafe { Box::from_raw(exn) });
+    }
+}
+
+/// Return the topmost frame of an exception, valid until the exception is freed.
+///
+/// Returns null if `exn` is null.
+///
+/// # Safety
+///
+/// `exn` must be null or an exception that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_frame(exn: *const exn_t) -> *const exn_frame_t {
+    // SAFETY: guaranteed by the caller.
+    match unsafe { exn.as_ref() } {
+        Some(exn) => to_raw(exn.tree.frame()),
+        None => ptr::null(),
+    }
+}
+
+/// Render the whole exception tree, as Rust does with `{:?}`.
+///
+/// Returns null if `exn` is null. The string must be freed with [`exn_string_free`].
+///
+/// # Safety
+///
+/// `exn` must be null or an exception that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_debug(exn: *const exn_t) -> *mut c_char {
+    // SAFETY: guaranteed by the caller.
+    match unsafe { exn.as_ref() } {
+        Some(exn) => into_c_string(format!("{:?}", exn.tree.frame())),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Return the error message of a frame.
+///
+/// Returns null if `frame` is null. The string must be freed with [`exn_string_free`].
+///
+/// # Safety
+///
+/// `frame` must be null or a frame whose exception has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_frame_message(frame: *const exn_frame_t) -> *mut c_char {
+    // SAFETY: guaranteed by the caller.
+    match unsafe { from_raw(frame) } {
+        Some(frame) => into_c_string(frame.error().to_string()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Return the file of the source code location of a frame.
+///
+/// Returns null if `frame` is null, or if it was created from C code without a file. The string
+/// must be freed with [`exn_string_free`].
+///
+/// # Safety
+///
+/// `frame` must be null or a frame whose exception has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_frame_file(frame: *const exn_frame_t) -> *mut c_char {
+    // SAFETY: guaranteed by the caller.
+    let Some(frame) = (unsafe { from_raw(frame) }) else {
+        return ptr::null_mut();
+    };
+    match frame.error().downcast_ref::<CError>() {
+        Some(error) => error.file().map_or(ptr::null_mut(), into_c_string),
+        None => frame
+            .known_location()
+            .and_then(|location| location.path().map(Cow::into_owned))
+            .map_or(ptr::null_mut(), into_c_string),
+    }
+}
+
+/// Return the line of the source code location of a frame.
+///
+/// Returns 0 if `frame` is null.
+///
+/// # Safety
+///
+/// `frame` must be null or a frame whose exception has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_frame_line(frame: *const exn_frame_t) -> u32 {
+    // SAFETY: guaranteed by the caller.
+    let Some(frame) = (unsafe { from_raw(frame) }) else {
+        return 0;
+    };
+    match frame.error().downcast_ref::<CError>() {
+        Some(error) => error.line(),
+        None => frame
+            .known_location()
+            .and_then(|location| location.line())
+            .unwrap_or(0),
+    }
+}
+
+/// Return the column of the source code location of a frame.
+///
+/// Returns 0 if `frame` is null, or if it was created from C code.
+///
+/// # Safety
+///
+/// `frame` must be null or a frame whose exception has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_frame_column(frame: *const exn_frame_t) -> u32 {
+    // SAFETY: guaranteed by the caller.
+    let Some(frame) = (unsafe { from_raw(frame) }) else {
+        return 0;
+    };
+    match frame.error().downcast_ref::<CError>() {
+        Some(_) => 0,
+        None => frame
+            .known_location()
+            .and_then(|location| location.column())
+            .unwrap_or(0),
+    }
+}
+
+/// Return the number of children of a frame.
+///
+/// Returns 0 if `frame` is null.
+///
+/// # Safety
+///
+/// `frame` must be null or a frame whose exception has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_frame_children_count(frame: *const exn_frame_t) -> usize {
+    // SAFETY: guaranteed by the caller.
+    unsafe { from_raw(frame) }.map_or(0, |frame| frame.children().len())
+}
+
+/// Return the child of a frame at `index`, valid until its exception is freed.
+///
+/// Returns null if `frame` is null or `index` is out of bounds.
+///
+/// # Safety
+///
+/// `frame` must be null or a frame whose exception has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_frame_child(
+    frame: *const exn_frame_t,
+    index: usize,
+) -> *const exn_frame_t {
+    // SAFETY: guaranteed by the caller.
+    unsafe { from_raw(frame) }
+        .and_then(|frame| frame.children().get(index))
+        .map_or(ptr::null(), to_raw)
+}
+
+/// Free a string returned by this API.
+///
+/// Does nothing if `string` is null.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by this API that has not been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn exn_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: guaranteed by the caller.
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// # Safety
+///
+/// `message` and `file` must be null or point to nul-terminated strings.
+unsafe fn new_error(message: *const c_char, file: *const c_char, line: u32) -> Option<CError> {
+    // SAFETY: guaranteed by the caller.
+    let message = unsafe { from_c_str(message) }?;
+    // SAFETY: guaranteed by the caller.
+    let file = unsafe { from_c_str(file) };
+    Some(CError {
+        message,
+        file,
+        line,
+    })
+}
+
+/// Locate the topmost frame of `exn` at the C code that created it, so that reports print it
+/// rather than a location in this crate.
+fn located(exn: Exn<CError>) -> Exn<CError> {
+    let location = match &exn.file {
+        Some(file) => FrameLocation::file(file.clone(), exn.line, 0),
+        None => FrameLocation::opaque(format!("<unknown>:{}", exn.line)),
+    };
+    exn.with_location(location)
+}
+
+/// # Safety
+///
+/// `string` must be null or point to a nul-terminated string.
+unsafe fn from_c_str(string: *const c_char) -> Option<String> {
+    if string.is_null() {
+        return None;
+    }
+    // SAFETY: guaranteed by the caller.
+    let string = unsafe { CStr::from_ptr(string) };
+    Some(string.to_string_lossy().into_owned())
+}
+
+fn into_c_string(string: impl Into<Vec<u8>>) -> *mut c_char {
+    let mut bytes = string.into();
+    // interior nul bytes would truncate the string on the C side anyway
+    bytes.retain(|&byte| byte != 0);
+    CString::new(bytes)
+        .expect("nul bytes have been removed")
+        .into_raw()
+}
+
+fn to_raw(frame: &Frame) -> *const exn_frame_t {
+    ptr::from_ref(frame).cast()
+}
+
+/// # Safety
+///
+/// `frame` must be null or a frame whose exception has not been freed.
+unsafe fn from_raw<'a>(frame: *const exn_frame_t) -> Option<&'a Frame> {
+    // SAFETY: `exn_frame_t` is a transparent wrapper of `Frame`, and the rest is guaranteed by
+    // the caller.
+    unsafe { frame.cast::<Frame>().as_ref() }
+}
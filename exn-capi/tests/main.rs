@@ -0,0 +1,120 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ffi::CStr;
+use std::ffi::c_char;
+use std::ptr;
+
+use exn::Exn;
+use exn_capi::*;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn take_string(string: *mut c_char) -> Option<String> {
+    if string.is_null() {
+        return None;
+    }
+    let owned = unsafe { CStr::from_ptr(string) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { exn_string_free(string) };
+    Some(owned)
+}
+
+#[test]
+fn rust_tree() {
+    let exn = Exn::raise_all(
+        Error("failed to sync"),
+        [Exn::new(Error("disk full")), Exn::new(Error("timed out"))],
+    );
+    let location = *exn.frame().location();
+    let exn = into_raw(exn);
+
+    unsafe {
+        let frame = exn_frame(exn);
+        assert_eq!(
+            take_string(exn_frame_message(frame)).unwrap(),
+            "failed to sync"
+        );
+        assert_eq!(take_string(exn_frame_file(frame)).unwrap(), location.file());
+        assert_eq!(exn_frame_line(frame), location.line());
+        assert_eq!(exn_frame_column(frame), location.column());
+        assert_eq!(exn_frame_children_count(frame), 2);
+
+        let child = exn_frame_child(frame, 1);
+        assert_eq!(take_string(exn_frame_message(child)).unwrap(), "timed out");
+        assert_eq!(exn_frame_children_count(child), 0);
+        assert!(exn_frame_child(frame, 2).is_null());
+
+        let debug = take_string(exn_debug(exn)).unwrap();
+        assert!(debug.starts_with("failed to sync, at "));
+
+        exn_free(exn);
+    }
+}
+
+#[test]
+fn c_tree() {
+    unsafe {
+        let exn = exn_new(c"connection reset".as_ptr(), ptr::null(), 3);
+        let exn = exn_raise(exn, c"failed to fetch".as_ptr(), c"main.c".as_ptr(), 7);
+
+        let frame = exn_frame(exn);
+        assert_eq!(
+            take_string(exn_frame_message(frame)).unwrap(),
+            "failed to fetch"
+        );
+        assert_eq!(take_string(exn_frame_file(frame)).unwrap(), "main.c");
+        assert_eq!(exn_frame_line(frame), 7);
+        assert_eq!(exn_frame_column(frame), 0);
+        assert_eq!(
+            take_string(exn_debug(exn)).unwrap(),
+            "failed to fetch, at main.c:7:0\n|\n|-> connection reset, at <unknown>:3"
+        );
+
+        let child = exn_frame_child(frame, 0);
+        assert_eq!(
+            take_string(exn_frame_message(child)).unwrap(),
+            "connection reset"
+        );
+        assert_eq!(take_string(exn_frame_file(child)), None);
+        assert_eq!(exn_frame_line(child), 3);
+
+        exn_free(exn);
+    }
+}
+
+#[test]
+fn null() {
+    unsafe {
+        assert!(exn_new(ptr::null(), ptr::null(), 0).is_null());
+        assert!(exn_raise(ptr::null_mut(), c"message".as_ptr(), ptr::null(), 0).is_null());
+        assert!(exn_frame(ptr::null()).is_null());
+        assert!(exn_debug(ptr::null()).is_null());
+        assert!(exn_frame_message(ptr::null()).is_null());
+        assert_eq!(exn_frame_children_count(ptr::null()), 0);
+        assert!(exn_frame_child(ptr::null(), 0).is_null());
+        exn_free(ptr::null_mut());
+        exn_string_free(ptr::null_mut());
+    }
+}
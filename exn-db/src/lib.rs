@@ -0,0 +1,170 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Classification of database errors found in [`exn`](https://docs.rs/exn) exceptions.
+//!
+//! [`DbErrorExt::db_error_kind`] finds the first database error in an exception tree and
+//! classifies it as a [`DbErrorKind`], so that logic such as retrying on serialization failures
+//! does not need driver-specific downcasts at every call site.
+//!
+//! The supported drivers are enabled by features:
+//!
+//! * `sqlx`: frames whose error is a `sqlx::Error`.
+//! * `diesel`: frames whose error is a `diesel::result::Error`.
+//!
+//! # Examples
+//!
+//! ```
+//! use exn_db::DbErrorExt;
+//!
+//! fn should_retry<E: core::error::Error + Send + Sync>(exn: &exn::Exn<E>) -> bool {
+//!     exn.db_error_kind().is_some_and(|kind| kind.is_transient())
+//! }
+//! ```
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+use std::error::Error;
+use std::fmt;
+
+use exn::Exn;
+use exn::Frame;
+
+/// The kind of a database error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DbErrorKind {
+    /// A unique or primary key constraint was violated.
+    UniqueViolation,
+    /// A foreign key constraint was violated.
+    ForeignKeyViolation,
+    /// A not-null constraint was violated.
+    NotNullViolation,
+    /// A check constraint was violated.
+    CheckViolation,
+    /// A transaction could not be serialized with concurrent transactions.
+    SerializationFailure,
+    /// A transaction was aborted to resolve a deadlock.
+    Deadlock,
+    /// The connection to the database was lost or could not be used.
+    ConnectionLost,
+}
+
+impl DbErrorKind {
+    /// Return `true` if the failed operation may succeed when retried, i.e., for serialization
+    /// failures, deadlocks and lost connections.
+    pub fn is_transient(self) -> bool {
+        matches!(
+            self,
+            DbErrorKind::SerializationFailure | DbErrorKind::Deadlock | DbErrorKind::ConnectionLost
+        )
+    }
+}
+
+impl fmt::Display for DbErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DbErrorKind::UniqueViolation => write!(f, "unique violation"),
+            DbErrorKind::ForeignKeyViolation => write!(f, "foreign key violation"),
+            DbErrorKind::NotNullViolation => write!(f, "not-null violation"),
+            DbErrorKind::CheckViolation => write!(f, "check violation"),
+            DbErrorKind::SerializationFailure => write!(f, "serialization failure"),
+            DbErrorKind::Deadlock => write!(f, "deadlock"),
+            DbErrorKind::ConnectionLost => write!(f, "connection lost"),
+        }
+    }
+}
+
+/// An extension trait to classify the database errors of exception trees.
+pub trait DbErrorExt {
+    /// Return the kind of the first classified database error, visiting frames depth-first.
+    ///
+    /// Returns `None` if no frame holds a database error of a supported driver, or if such errors
+    /// are of no known kind.
+    fn db_error_kind(&self) -> Option<DbErrorKind>;
+}
+
+impl<E: Error + Send + Sync + 'static> DbErrorExt for Exn<E> {
+    fn db_error_kind(&self) -> Option<DbErrorKind> {
+        self.frame().db_error_kind()
+    }
+}
+
+impl DbErrorExt for Frame {
+    fn db_error_kind(&self) -> Option<DbErrorKind> {
+        self.frames().find_map(|frame| classify(frame.error()))
+    }
+}
+
+#[allow(unused_variables)]
+fn classify(error: &(dyn Error + Send + Sync + 'static)) -> Option<DbErrorKind> {
+    #[cfg(feature = "sqlx")]
+    if let Some(error) = error.downcast_ref::<sqlx_core::Error>() {
+        return classify_sqlx(error);
+    }
+    #[cfg(feature = "diesel")]
+    if let Some(error) = error.downcast_ref::<diesel::result::Error>() {
+        return classify_diesel(error);
+    }
+    None
+}
+
+#[cfg(feature = "sqlx")]
+fn classify_sqlx(error: &sqlx_core::Error) -> Option<DbErrorKind> {
+    use sqlx_core::error::ErrorKind;
+
+    match error {
+        sqlx_core::Error::Database(error) => {
+            // SQLSTATE codes of the transaction rollback class
+            match error.code().as_deref() {
+                Some("40001") => return Some(DbErrorKind::SerializationFailure),
+                Some("40P01") => return Some(DbErrorKind::Deadlock),
+                _ => {}
+            }
+            match error.kind() {
+                ErrorKind::UniqueViolation => Some(DbErrorKind::UniqueViolation),
+                ErrorKind::ForeignKeyViolation => Some(DbErrorKind::ForeignKeyViolation),
+                ErrorKind::NotNullViolation => Some(DbErrorKind::NotNullViolation),
+                ErrorKind::CheckViolation => Some(DbErrorKind::CheckViolation),
+                _ => None,
+            }
+        }
+        sqlx_core::Error::Io(_)
+        | sqlx_core::Error::Tls(_)
+        | sqlx_core::Error::PoolClosed
+        | sqlx_core::Error::WorkerCrashed => Some(DbErrorKind::ConnectionLost),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "diesel")]
+fn classify_diesel(error: &diesel::result::Error) -> Option<DbErrorKind> {
+    use diesel::result::DatabaseErrorKind;
+
+    match error {
+        diesel::result::Error::DatabaseError(kind, _) => match kind {
+            DatabaseErrorKind::UniqueViolation => Some(DbErrorKind::UniqueViolation),
+            DatabaseErrorKind::ForeignKeyViolation => Some(DbErrorKind::ForeignKeyViolation),
+            DatabaseErrorKind::NotNullViolation => Some(DbErrorKind::NotNullViolation),
+            DatabaseErrorKind::CheckViolation => Some(DbErrorKind::CheckViolation),
+            DatabaseErrorKind::SerializationFailure => Some(DbErrorKind::SerializationFailure),
+            DatabaseErrorKind::ClosedConnection | DatabaseErrorKind::UnableToSendCommand => {
+                Some(DbErrorKind::ConnectionLost)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
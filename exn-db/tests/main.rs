@@ -0,0 +1,146 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use exn::Exn;
+use exn_db::DbErrorExt;
+use exn_db::DbErrorKind;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[test]
+fn unclassified() {
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+    assert_eq!(exn.db_error_kind(), None);
+    assert!(DbErrorKind::Deadlock.is_transient());
+    assert!(!DbErrorKind::UniqueViolation.is_transient());
+}
+
+#[cfg(feature = "sqlx")]
+#[test]
+fn sqlx() {
+    use std::borrow::Cow;
+
+    use sqlx_core::error::DatabaseError;
+    use sqlx_core::error::ErrorKind;
+
+    #[derive(Debug)]
+    struct PgError {
+        code: &'static str,
+        kind: fn() -> ErrorKind,
+    }
+
+    impl std::fmt::Display for PgError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "error {}", self.code)
+        }
+    }
+
+    impl std::error::Error for PgError {}
+
+    impl DatabaseError for PgError {
+        fn message(&self) -> &str {
+            "database error"
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.code))
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            (self.kind)()
+        }
+    }
+
+    let classify = |error: sqlx_core::Error| {
+        Exn::new(error)
+            .raise(Error("failed to save user"))
+            .db_error_kind()
+    };
+
+    let unique = PgError {
+        code: "23505",
+        kind: || ErrorKind::UniqueViolation,
+    };
+    let serialization = PgError {
+        code: "40001",
+        kind: || ErrorKind::Other,
+    };
+    let deadlock = PgError {
+        code: "40P01",
+        kind: || ErrorKind::Other,
+    };
+    let io = std::io::Error::from(std::io::ErrorKind::ConnectionReset);
+
+    let database = |error| sqlx_core::Error::Database(Box::new(error));
+    assert_eq!(
+        classify(database(unique)),
+        Some(DbErrorKind::UniqueViolation)
+    );
+    assert_eq!(
+        classify(database(serialization)),
+        Some(DbErrorKind::SerializationFailure)
+    );
+    assert_eq!(classify(database(deadlock)), Some(DbErrorKind::Deadlock));
+    assert_eq!(
+        classify(sqlx_core::Error::Io(io)),
+        Some(DbErrorKind::ConnectionLost)
+    );
+    assert_eq!(classify(sqlx_core::Error::RowNotFound), None);
+}
+
+#[cfg(feature = "diesel")]
+#[test]
+fn diesel() {
+    use diesel::result::DatabaseErrorKind;
+
+    let classify = |kind| {
+        let error = diesel::result::Error::DatabaseError(kind, Box::new("conflict".to_string()));
+        Exn::raise_all(Error("failed to save users"), [Exn::new(error)]).db_error_kind()
+    };
+
+    assert_eq!(
+        classify(DatabaseErrorKind::SerializationFailure),
+        Some(DbErrorKind::SerializationFailure)
+    );
+    assert_eq!(
+        classify(DatabaseErrorKind::ForeignKeyViolation),
+        Some(DbErrorKind::ForeignKeyViolation)
+    );
+    assert_eq!(
+        classify(DatabaseErrorKind::ClosedConnection),
+        Some(DbErrorKind::ConnectionLost)
+    );
+    assert_eq!(classify(DatabaseErrorKind::ReadOnlyTransaction), None);
+}
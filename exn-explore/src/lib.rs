@@ -0,0 +1,307 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interactively explore [`exn`](https://docs.rs/exn) exception trees serialized as JSON, e.g.,
+//! with `serde_json::to_string(&exn.to_string_tree())` and the `serde` and `unstable` features of
+//! `exn`.
+//!
+//! Huge aggregated failures are hard to triage from a static dump. The `exn-explore` binary loads
+//! a serialized tree and reads commands from the standard input to fold and unfold subtrees,
+//! search messages and open the location of a frame in `$EDITOR`:
+//!
+//! ```text
+//! $ exn-explore failure.json
+//!   0 [-] failed to run the batch, at src/batch.rs:42:10
+//!   1   [+] item 17 failed, at src/item.rs:88:5 (3 hidden)
+//!   5     failed to commit, at src/db.rs:21:9
+//! > /timeout
+//! ```
+//!
+//! Type `help` for the list of commands. [`Explorer`] implements the explorer for embedding in
+//! other tools.
+//!
+//! # Examples
+//!
+//! ```
+//! use exn_explore::Explorer;
+//!
+//! let exn = exn::Exn::raise_all(
+//!     std::io::Error::other("batch failed"),
+//!     [exn::Exn::new(std::io::Error::other("item 1 timed out"))],
+//! );
+//! let json = serde_json::to_string(&exn.to_string_tree()).unwrap();
+//!
+//! let mut explorer = Explorer::from_json(&json).unwrap();
+//! assert_eq!(explorer.search("timed out"), [1]);
+//! explorer.fold(0);
+//! assert_eq!(explorer.render().lines().count(), 1);
+//! ```
+
+#![deny(missing_docs)]
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Write as _;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+
+use exn::Exn;
+use exn::ResultExt;
+use exn::unstable::StringTree;
+
+/// The error of an exception raised when a tree cannot be loaded.
+#[derive(Debug)]
+pub struct LoadError(String);
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// A node of the explored tree.
+#[derive(Debug)]
+struct Node {
+    tree: StringTree,
+    depth: usize,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+/// An explorer of an exception tree, with the state of folded subtrees.
+///
+/// Nodes are numbered in depth-first pre-order; the topmost frame is node 0.
+#[derive(Debug)]
+pub struct Explorer {
+    nodes: Vec<Node>,
+    folded: BTreeSet<usize>,
+}
+
+impl Explorer {
+    /// Create an explorer of `tree`, with all subtrees unfolded.
+    pub fn new(tree: &StringTree) -> Self {
+        fn walk(tree: &StringTree, depth: usize, parent: Option<usize>, nodes: &mut Vec<Node>) {
+            let index = nodes.len();
+            nodes.push(Node {
+                tree: tree.clone(),
+                depth,
+                parent,
+                children: Vec::new(),
+            });
+            for child in tree.children() {
+                let child_index = nodes.len();
+                nodes[index].children.push(child_index);
+                walk(child, depth + 1, Some(index), nodes);
+            }
+        }
+
+        let mut nodes = Vec::new();
+        walk(tree, 0, None, &mut nodes);
+        Self {
+            nodes,
+            folded: BTreeSet::new(),
+        }
+    }
+
+    /// Create an explorer of a tree serialized as JSON.
+    pub fn from_json(json: &str) -> Result<Self, Exn<LoadError>> {
+        let tree = serde_json::from_str::<StringTree>(json)
+            .or_raise(|| LoadError("failed to parse the exception tree".to_string()))?;
+        Ok(Self::new(&tree))
+    }
+
+    /// Return the number of nodes in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return `true` if the tree has no nodes, which never happens.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Return the node numbered `index`, without its children.
+    pub fn node(&self, index: usize) -> Option<&StringTree> {
+        self.nodes.get(index).map(|node| &node.tree)
+    }
+
+    /// Fold the subtree of node `index`, hiding its descendants. Returns `false` if there is no
+    /// such node.
+    pub fn fold(&mut self, index: usize) -> bool {
+        if index >= self.nodes.len() {
+            return false;
+        }
+        self.folded.insert(index);
+        true
+    }
+
+    /// Unfold the subtree of node `index`, showing its children. Returns `false` if there is no
+    /// such node.
+    pub fn unfold(&mut self, index: usize) -> bool {
+        if index >= self.nodes.len() {
+            return false;
+        }
+        self.folded.remove(&index);
+        true
+    }
+
+    /// Fold the subtrees of all nodes with children.
+    pub fn fold_all(&mut self) {
+        let parents = self.nodes.iter().enumerate();
+        let parents = parents.filter(|(_, node)| !node.children.is_empty());
+        self.folded = parents.map(|(index, _)| index).collect();
+    }
+
+    /// Unfold all subtrees.
+    pub fn unfold_all(&mut self) {
+        self.folded.clear();
+    }
+
+    /// Return the nodes whose message contains `query`, ignoring case, unfolding their
+    /// ancestors so that they are all shown.
+    pub fn search(&mut self, query: &str) -> Vec<usize> {
+        let query = query.to_lowercase();
+        let matches = (0..self.nodes.len())
+            .filter(|&index| {
+                let message = self.nodes[index].tree.message();
+                message.to_lowercase().contains(&query)
+            })
+            .collect::<Vec<_>>();
+        for &index in &matches {
+            let mut parent = self.nodes[index].parent;
+            while let Some(index) = parent {
+                self.folded.remove(&index);
+                parent = self.nodes[index].parent;
+            }
+        }
+        matches
+    }
+
+    /// Render the visible nodes, one per line, with their number, a fold marker, their message
+    /// and their location.
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        let mut index = 0;
+        while let Some(node) = self.nodes.get(index) {
+            let marker = match (node.children.is_empty(), self.folded.contains(&index)) {
+                (true, _) => "   ",
+                (false, false) => "[-]",
+                (false, true) => "[+]",
+            };
+            let tree = &node.tree;
+            let _ = write!(
+                output,
+                "{index:>3} {:indent$}{marker} {}, at {}:{}:{}",
+                "",
+                tree.message(),
+                tree.file(),
+                tree.line(),
+                tree.column(),
+                indent = node.depth * 2,
+            );
+            let hidden = self.subtree_len(index) - 1;
+            if self.folded.contains(&index) {
+                let _ = write!(output, " ({hidden} hidden)");
+                index += hidden;
+            }
+            output.push('\n');
+            index += 1;
+        }
+        output
+    }
+
+    /// Run the explorer, reading commands from `input` and writing to `output` until `quit` or
+    /// the end of `input`.
+    ///
+    /// The `open` command calls `open` with the node to open.
+    pub fn run<R, W, F>(&mut self, input: R, mut output: W, mut open: F) -> io::Result<()>
+    where
+        R: BufRead,
+        W: Write,
+        F: FnMut(&StringTree) -> io::Result<()>,
+    {
+        write!(output, "{}> ", self.render())?;
+        output.flush()?;
+        for line in input.lines() {
+            let line = line?;
+            let line = line.trim();
+            let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+            let argument = argument.trim();
+            match (command, argument) {
+                ("q" | "quit", _) => return Ok(()),
+                ("" | "l" | "list", _) => write!(output, "{}", self.render())?,
+                ("f" | "fold", "all") => {
+                    self.fold_all();
+                    write!(output, "{}", self.render())?;
+                }
+                ("u" | "unfold", "all") => {
+                    self.unfold_all();
+                    write!(output, "{}", self.render())?;
+                }
+                ("f" | "fold", index) => match index.parse() {
+                    Ok(index) if self.fold(index) => write!(output, "{}", self.render())?,
+                    _ => writeln!(output, "no node {index}")?,
+                },
+                ("u" | "unfold", index) => match index.parse() {
+                    Ok(index) if self.unfold(index) => write!(output, "{}", self.render())?,
+                    _ => writeln!(output, "no node {index}")?,
+                },
+                ("o" | "open", index) => match index.parse().ok().and_then(|i| self.node(i)) {
+                    Some(node) => open(node)?,
+                    None => writeln!(output, "no node {index}")?,
+                },
+                ("h" | "help" | "?", _) => write!(output, "{HELP}")?,
+                _ => {
+                    let query = line.strip_prefix('/').or_else(|| {
+                        let query = line.strip_prefix("search ");
+                        query.or_else(|| line.strip_prefix("s "))
+                    });
+                    match query {
+                        Some(query) => {
+                            let matches = self.search(query.trim());
+                            write!(output, "{}", self.render())?;
+                            writeln!(output, "{} matching node(s): {matches:?}", matches.len())?;
+                        }
+                        None => writeln!(output, "unknown command `{line}`; type `help`")?,
+                    }
+                }
+            }
+            write!(output, "> ")?;
+            output.flush()?;
+        }
+        writeln!(output)
+    }
+
+    /// Return the number of nodes in the subtree of node `index`, including itself.
+    fn subtree_len(&self, index: usize) -> usize {
+        let children = self.nodes[index].children.iter();
+        1 + children
+            .map(|&child| self.subtree_len(child))
+            .sum::<usize>()
+    }
+}
+
+const HELP: &str = "\
+commands:
+  list, l           show the tree
+  fold, f N|all     fold the subtree of node N, or all subtrees
+  unfold, u N|all   unfold the subtree of node N, or all subtrees
+  /TEXT             search messages, unfolding the matching nodes
+  open, o N         open the location of node N in $EDITOR
+  help, h, ?        show this help
+  quit, q           exit
+";
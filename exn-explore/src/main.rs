@@ -0,0 +1,72 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::io;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+
+use clap::Parser;
+use exn::ResultExt;
+use exn_explore::Explorer;
+
+/// Interactively explore an exn exception tree serialized as JSON.
+#[derive(Parser)]
+#[command(name = "exn-explore")]
+struct Args {
+    /// The file holding the serialized tree.
+    path: PathBuf,
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn main() -> exn::Result<(), Error> {
+    let args = Args::parse();
+    let path = args.path.display();
+
+    let json =
+        std::fs::read_to_string(&args.path).or_raise(|| Error(format!("failed to read {path}")))?;
+    let mut explorer =
+        Explorer::from_json(&json).or_raise(|| Error(format!("failed to load {path}")))?;
+
+    let editor = std::env::var_os("EDITOR");
+    explorer
+        .run(io::stdin().lock(), io::stdout().lock(), |node| {
+            let location = format!("{}:{}:{}", node.file(), node.line(), node.column());
+            match &editor {
+                Some(editor) => {
+                    let status = Command::new(editor)
+                        .arg(format!("+{}", node.line()))
+                        .arg(node.file())
+                        .status()?;
+                    if !status.success() {
+                        writeln!(io::stderr(), "failed to open {location}")?;
+                    }
+                    Ok(())
+                }
+                None => writeln!(io::stdout(), "{location}"),
+            }
+        })
+        .or_raise(|| Error("failed to run the explorer".to_string()))
+}
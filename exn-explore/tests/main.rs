@@ -0,0 +1,114 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use exn::Exn;
+use exn_explore::Explorer;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn explorer() -> Explorer {
+    let exn = Exn::raise_all(
+        Error("batch failed"),
+        [
+            Exn::new(Error("connection reset")).raise(Error("item 1 failed")),
+            Exn::new(Error("item 7 timed out")),
+        ],
+    );
+    let json = serde_json::to_string(&exn.to_string_tree()).unwrap();
+    Explorer::from_json(&json).unwrap()
+}
+
+fn strip_locations(output: &str) -> String {
+    let lines = output.lines().map(|line| match line.split_once(", at ") {
+        Some((message, location)) => {
+            let hidden = location.find(" (").map_or("", |i| &location[i..]);
+            format!("{message}{hidden}")
+        }
+        None => line.to_string(),
+    });
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+#[test]
+fn fold_and_search() {
+    let mut explorer = explorer();
+    assert_eq!(explorer.len(), 4);
+    assert_eq!(
+        strip_locations(&explorer.render()),
+        "  0 [-] batch failed
+  1   [-] item 1 failed
+  2         connection reset
+  3       item 7 timed out"
+    );
+
+    explorer.fold_all();
+    assert_eq!(
+        strip_locations(&explorer.render()),
+        "  0 [+] batch failed (3 hidden)"
+    );
+
+    assert_eq!(explorer.search("RESET"), [2]);
+    assert_eq!(
+        strip_locations(&explorer.render()),
+        "  0 [-] batch failed
+  1   [-] item 1 failed
+  2         connection reset
+  3       item 7 timed out"
+    );
+
+    assert!(explorer.fold(1));
+    assert!(!explorer.fold(4));
+    assert_eq!(
+        strip_locations(&explorer.render()),
+        "  0 [-] batch failed
+  1   [+] item 1 failed (1 hidden)
+  3       item 7 timed out"
+    );
+}
+
+#[test]
+fn run() {
+    let mut explorer = explorer();
+    let input = b"fold 0\n/timed\nopen 3\nopen 9\nbogus\nquit\nlist\n";
+    let mut output = Vec::new();
+    let mut opened = Vec::new();
+    explorer
+        .run(&input[..], &mut output, |node| {
+            opened.push(node.message().to_string());
+            Ok(())
+        })
+        .unwrap();
+
+    let output = strip_locations(&String::from_utf8(output).unwrap());
+    assert!(output.contains(">   0 [+] batch failed (3 hidden)\n"));
+    assert!(output.contains("1 matching node(s): [3]"));
+    assert!(output.contains("no node 9"));
+    assert!(output.contains("unknown command `bogus`"));
+    assert_eq!(opened, ["item 7 timed out"]);
+}
+
+#[test]
+fn invalid_json() {
+    let exn = Explorer::from_json("{").unwrap_err();
+    assert_eq!(exn.to_string(), "failed to parse the exception tree");
+}
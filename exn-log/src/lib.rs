@@ -0,0 +1,196 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Log [`exn`](https://docs.rs/exn) exception trees with the [`log`](https://docs.rs/log) crate.
+//!
+//! [`error!`] and [`warn!`] log an exception tree as one record. The message of the record is the
+//! multi-line rendering of the tree with [sensitive](exn::Exn::mark_sensitive) frames redacted,
+//! for loggers that ignore key-values; structured loggers also get one key-value per frame, in
+//! depth-first pre-order:
+//!
+//! * the key is `exn.{index}`, the topmost frame having index 0;
+//! * the value is the error message of the frame and its location, or `«redacted»` instead of the
+//!   message for [sensitive](exn::Exn::mark_sensitive) frames.
+//!
+//! # Examples
+//!
+//! ```
+//! let exn = exn::Exn::new(std::io::Error::other("connection reset"));
+//!
+//! exn_log::error!(exn);
+//! exn_log::warn!(target: "db", &exn);
+//! ```
+
+#![deny(missing_docs)]
+
+use exn::Frame;
+use log::kv;
+
+/// Log an exception tree at the error level.
+///
+/// The argument may be an [`Exn`](exn::Exn), a [`Frame`](exn::Frame), a
+/// [`SharedExn`](exn::SharedExn) or a reference to any of them. The target defaults to the
+/// module path of the caller.
+#[macro_export]
+macro_rules! error {
+    (target: $target:expr, $exn:expr $(,)?) => {
+        $crate::log!(target: $target, $crate::__private::Level::Error, $exn)
+    };
+    ($exn:expr $(,)?) => {
+        $crate::log!($crate::__private::Level::Error, $exn)
+    };
+}
+
+/// Log an exception tree at the warn level.
+///
+/// See [`error!`] for the accepted arguments.
+#[macro_export]
+macro_rules! warn {
+    (target: $target:expr, $exn:expr $(,)?) => {
+        $crate::log!(target: $target, $crate::__private::Level::Warn, $exn)
+    };
+    ($exn:expr $(,)?) => {
+        $crate::log!($crate::__private::Level::Warn, $exn)
+    };
+}
+
+/// Log an exception tree at the given level.
+///
+/// See [`error!`] for the accepted arguments.
+#[macro_export]
+macro_rules! log {
+    (target: $target:expr, $level:expr, $exn:expr $(,)?) => {
+        $crate::__private::log(
+            $level,
+            $target,
+            ::core::module_path!(),
+            ::core::file!(),
+            ::core::line!(),
+            $crate::__private::AsFrame::as_frame(&$exn),
+        )
+    };
+    ($level:expr, $exn:expr $(,)?) => {
+        $crate::log!(target: ::core::module_path!(), $level, $exn)
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::error::Error;
+
+    use exn::Exn;
+    use exn::Frame;
+    use exn::SharedExn;
+    pub use log::Level;
+    use log::Metadata;
+    use log::Record;
+
+    use crate::KeyValues;
+
+    /// A value holding an exception tree.
+    pub trait AsFrame {
+        fn as_frame(&self) -> &Frame;
+    }
+
+    impl<E: Error + Send + Sync + 'static> AsFrame for Exn<E> {
+        fn as_frame(&self) -> &Frame {
+            self.frame()
+        }
+    }
+
+    impl<E: Error + Send + Sync + 'static> AsFrame for SharedExn<E> {
+        fn as_frame(&self) -> &Frame {
+            self.frame()
+        }
+    }
+
+    impl AsFrame for Frame {
+        fn as_frame(&self) -> &Frame {
+            self
+        }
+    }
+
+    impl<T: AsFrame + ?Sized> AsFrame for &T {
+        fn as_frame(&self) -> &Frame {
+            (**self).as_frame()
+        }
+    }
+
+    /// Log the exception tree rooted at `frame` as one record.
+    pub fn log(
+        level: Level,
+        target: &str,
+        module_path: &'static str,
+        file: &'static str,
+        line: u32,
+        frame: &Frame,
+    ) {
+        let metadata = Metadata::builder().level(level).target(target).build();
+        let logger = log::logger();
+        if level > log::max_level() || !logger.enabled(&metadata) {
+            return;
+        }
+
+        let report = exn::report::Compact::new(frame).with_redaction(true);
+        let key_values = KeyValues::new(frame);
+        logger.log(
+            &Record::builder()
+                .metadata(metadata)
+                .args(format_args!("{report:?}"))
+                .module_path_static(Some(module_path))
+                .file_static(Some(file))
+                .line(Some(line))
+                .key_values(&key_values)
+                .build(),
+        );
+    }
+}
+
+/// The key-values of the frames of an exception tree.
+struct KeyValues {
+    pairs: Vec<(String, String)>,
+}
+
+impl KeyValues {
+    fn new(frame: &Frame) -> Self {
+        fn walk(frame: &Frame, pairs: &mut Vec<(String, String)>) {
+            let message = frame.redacted_message();
+            let value = match frame.known_location() {
+                Some(location) => format!("{message}, at {location}"),
+                None => message.to_string(),
+            };
+            pairs.push((format!("exn.{}", pairs.len()), value));
+            for child in frame.children() {
+                walk(child, pairs);
+            }
+        }
+
+        let mut pairs = Vec::new();
+        walk(frame, &mut pairs);
+        Self { pairs }
+    }
+}
+
+impl kv::Source for KeyValues {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn kv::VisitSource<'kvs>) -> Result<(), kv::Error> {
+        for (key, value) in &self.pairs {
+            visitor.visit_pair(kv::Key::from_str(key), kv::Value::from(value.as_str()))?;
+        }
+        Ok(())
+    }
+
+    fn count(&self) -> usize {
+        self.pairs.len()
+    }
+}
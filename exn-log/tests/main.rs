@@ -0,0 +1,130 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Mutex;
+
+use exn::Exn;
+use log::Log;
+use log::Metadata;
+use log::Record;
+use log::kv::Key;
+use log::kv::Value;
+use log::kv::VisitSource;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug)]
+struct Captured {
+    level: log::Level,
+    target: String,
+    message: String,
+    key_values: Vec<(String, String)>,
+}
+
+struct Logger(Mutex<Vec<Captured>>);
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        struct Visitor(Vec<(String, String)>);
+
+        impl<'kvs> VisitSource<'kvs> for Visitor {
+            fn visit_pair(
+                &mut self,
+                key: Key<'kvs>,
+                value: Value<'kvs>,
+            ) -> Result<(), log::kv::Error> {
+                self.0.push((key.to_string(), value.to_string()));
+                Ok(())
+            }
+        }
+
+        let mut visitor = Visitor(Vec::new());
+        record.key_values().visit(&mut visitor).unwrap();
+        self.0.lock().unwrap().push(Captured {
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+            key_values: visitor.0,
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Logger = Logger(Mutex::new(Vec::new()));
+
+// the logger is process-global, so all cases run in a single test
+#[test]
+fn log_exn() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Error("secret")).mark_sensitive(),
+            Exn::new(Error("inner")),
+        ],
+    );
+    exn_log::error!(&exn);
+    exn_log::warn!(target: "db", exn.frame());
+    exn_log::log!(log::Level::Info, exn);
+
+    let captured = std::mem::take(&mut *LOGGER.0.lock().unwrap());
+    assert_eq!(captured.len(), 2);
+
+    let error = &captured[0];
+    assert_eq!(error.level, log::Level::Error);
+    assert_eq!(error.target, module_path!());
+    assert!(
+        error
+            .message
+            .starts_with("outer, at exn-log/tests/main.rs:")
+    );
+    assert!(error.message.contains("\n|-> inner, at "));
+    assert!(error.message.contains("\n|-> «redacted», at "));
+    assert!(!error.message.contains("secret"), "{}", error.message);
+    let keys = error.key_values.iter().map(|(k, _)| k).collect::<Vec<_>>();
+    assert_eq!(keys, ["exn.0", "exn.1", "exn.2"]);
+    assert!(
+        error.key_values[0]
+            .1
+            .starts_with("outer, at exn-log/tests/main.rs:")
+    );
+    assert!(error.key_values[1].1.starts_with("«redacted», at "));
+    assert!(
+        error.key_values.iter().all(|(_, v)| !v.contains("secret")),
+        "{:?}",
+        error.key_values
+    );
+    assert!(error.key_values[2].1.starts_with("inner, at "));
+
+    let warn = &captured[1];
+    assert_eq!(warn.level, log::Level::Warn);
+    assert_eq!(warn.target, "db");
+    assert_eq!(warn.message, error.message);
+}
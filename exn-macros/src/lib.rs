@@ -0,0 +1,345 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Procedural macros for the [`exn`](https://docs.rs/exn) crate.
+//!
+//! Do not depend on this crate directly; enable the `macros` feature of `exn` instead.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use quote::quote;
+use quote::quote_spanned;
+use syn::Attribute;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Expr;
+use syn::ItemFn;
+use syn::Meta;
+use syn::Path;
+use syn::parse_macro_input;
+use syn::spanned::Spanned;
+
+/// Raise every error propagated from the function under one context error.
+///
+/// The argument is converted into the error type `E` of the function, which must return an
+/// `exn::Result<T, E>`. Every `?` in the function body may propagate any error or exception; the
+/// propagated exception becomes the child of a new exception raised with the context error and
+/// located at the attribute.
+///
+/// Since the question marks no longer convert into `Exn<E>`, `bail!` and `ensure!` cannot be used
+/// in the body; return `Err(err.into())` instead.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// struct AppError(&'static str);
+///
+/// impl core::fmt::Display for AppError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for AppError {}
+///
+/// #[exn::context(AppError("failed to load config"))]
+/// fn load_config(input: &str) -> exn::Result<u16, AppError> {
+///     let port = input.trim().parse::<u16>()?;
+///     Ok(port)
+/// }
+///
+/// let exn = load_config("http").unwrap_err();
+/// assert_eq!(
+///     format!("{exn:#}"),
+///     "failed to load config: invalid digit found in string"
+/// );
+/// ```
+#[proc_macro_attribute]
+pub fn context(args: TokenStream, item: TokenStream) -> TokenStream {
+    let err = parse_macro_input!(args as Expr);
+    let mut func = parse_macro_input!(item as ItemFn);
+
+    let body = &func.block;
+    let block = if func.sig.asyncness.is_some() {
+        quote! {{
+            let __exn_result: ::core::result::Result<_, ::exn::__private::Erased> =
+                async move #body.await;
+            ::exn::__private::context(__exn_result, || ::core::convert::Into::into(#err))
+        }}
+    } else {
+        quote! {{
+            let __exn_body = move || -> ::core::result::Result<_, ::exn::__private::Erased> #body;
+            ::exn::__private::context(__exn_body(), || ::core::convert::Into::into(#err))
+        }}
+    };
+    *func.block = syn::parse2(block).expect("generated block must be valid");
+
+    quote!(#func).into()
+}
+
+/// Run `main`, reporting the exception it returns, if any, with the exit code of the tree.
+///
+/// The function must take no arguments and return `exn::Result<(), E>`. When it fails, the
+/// exception tree is printed to standard error, and the process exits with code 130 if the tree
+/// contains a cancellation, 124 if it contains a timeout, and 1 otherwise.
+///
+/// Asynchronous functions are supported with the attribute of a runtime below this one, e.g.,
+/// `#[tokio::main]`. This attribute requires the `std` feature of `exn`.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// struct AppError;
+///
+/// impl core::fmt::Display for AppError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "failed to start the app")
+///     }
+/// }
+///
+/// impl core::error::Error for AppError {}
+///
+/// #[exn::main]
+/// fn main() -> exn::Result<(), AppError> {
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn main(args: TokenStream, item: TokenStream) -> TokenStream {
+    let func = parse_macro_input!(item as ItemFn);
+    if let Err(err) = check_signature(&args, &func) {
+        return err.to_compile_error().into();
+    }
+    if func.sig.asyncness.is_some() && !func.attrs.iter().any(is_runtime_main) {
+        let msg = "async functions need the attribute of a runtime below `#[exn::main]`, e.g., `#[tokio::main]`";
+        return syn::Error::new(func.sig.span(), msg)
+            .to_compile_error()
+            .into();
+    }
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = func;
+    let asyncness = &sig.asyncness;
+    let output = &sig.output;
+    let name = &sig.ident;
+    quote! {
+        #vis fn #name() -> ::std::process::ExitCode {
+            #(#attrs)*
+            #asyncness fn __exn_main() #output #block
+            ::exn::__private::run_main(__exn_main())
+        }
+    }
+    .into()
+}
+
+/// Run a test, panicking with the exception tree it returns, if any.
+///
+/// The function must take no arguments and return `exn::Result<(), E>`. Unlike tests returning
+/// a `Result`, a failure is a panic, so that `#[should_panic]` applies to returned exceptions
+/// as well. This attribute requires the `std` feature of `exn`.
+///
+/// # Examples
+///
+/// ```
+/// #[exn::test]
+/// fn parse_port() -> exn::Result<(), std::num::ParseIntError> {
+///     let port = "8080".parse::<u16>()?;
+///     assert_eq!(port, 8080);
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn test(args: TokenStream, item: TokenStream) -> TokenStream {
+    let func = parse_macro_input!(item as ItemFn);
+    if let Err(err) = check_signature(&args, &func) {
+        return err.to_compile_error().into();
+    }
+    if func.sig.asyncness.is_some() {
+        let msg = "`#[exn::test]` does not support async functions";
+        return syn::Error::new(func.sig.span(), msg)
+            .to_compile_error()
+            .into();
+    }
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = func;
+    let output = &sig.output;
+    let name = &sig.ident;
+    quote! {
+        #[::core::prelude::v1::test]
+        #(#attrs)*
+        #vis fn #name() {
+            fn __exn_test() #output #block
+            ::exn::__private::run_test(__exn_test())
+        }
+    }
+    .into()
+}
+
+/// Derive `exn::Validate` for a struct, checking each field annotated with `#[validate]`.
+///
+/// Every annotated field is checked, rather than stopping at the first failure, and the failures
+/// are raised together under an `exn::ValidationError` naming the struct. Each failure is a child
+/// frame with an `exn::Field` attachment naming the field, located at its attribute. Fields
+/// without the attribute are not checked.
+///
+/// * `#[validate]` checks a field whose type implements `Validate`, e.g., a nested section of a
+///   configuration.
+/// * `#[validate(with = path)]` checks a field with a function taking a reference to it and
+///   returning a `Result` whose `Err` is an error or an exception.
+///
+/// A field may carry several attributes, which are checked in order.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Validate;
+///
+/// fn non_zero(port: &u16) -> Result<(), std::io::Error> {
+///     match port {
+///         0 => Err(std::io::Error::other("must not be 0")),
+///         _ => Ok(()),
+///     }
+/// }
+///
+/// fn non_empty(name: &str) -> Result<(), std::io::Error> {
+///     match name {
+///         "" => Err(std::io::Error::other("must not be empty")),
+///         _ => Ok(()),
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct Server {
+///     #[validate(with = non_empty)]
+///     host: String,
+///     #[validate(with = non_zero)]
+///     port: u16,
+/// }
+///
+/// #[derive(Validate)]
+/// struct Config {
+///     #[validate(with = non_empty)]
+///     name: String,
+///     #[validate]
+///     server: Server,
+/// }
+///
+/// let config = Config {
+///     name: String::new(),
+///     server: Server {
+///         host: "localhost".to_string(),
+///         port: 0,
+///     },
+/// };
+/// let exn = config.validate().unwrap_err();
+/// assert_eq!(exn.to_string(), "invalid `Config`");
+/// assert_eq!(exn.frame().children().len(), 2);
+/// ```
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_validate(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_validate(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        let msg = "`Validate` can only be derived for structs";
+        return Err(syn::Error::new(input.ident.span(), msg));
+    };
+
+    let mut checks = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(index.into()),
+        };
+        let name = match &field.ident {
+            Some(ident) => ident.to_string().trim_start_matches("r#").to_string(),
+            None => index.to_string(),
+        };
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("validate"))
+        {
+            let span = attr.span();
+            let check = match &attr.meta {
+                Meta::Path(_) => quote!(::exn::Validate::validate(&self.#member)),
+                _ => {
+                    let mut with = None::<Path>;
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("with") {
+                            with = Some(meta.value()?.parse()?);
+                            Ok(())
+                        } else {
+                            Err(meta.error("expected `with = path`"))
+                        }
+                    })?;
+                    let Some(with) = with else {
+                        return Err(syn::Error::new(span, "expected `with = path`"));
+                    };
+                    quote!(#with(&self.#member))
+                }
+            };
+            checks.push(quote_spanned!(span=> __exn_validator.check(#name, #check);));
+        }
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::exn::Validate for #ident #ty_generics #where_clause {
+            fn validate(&self) -> ::exn::Result<(), ::exn::ValidationError> {
+                let mut __exn_validator = ::exn::Validator::new();
+                #(#checks)*
+                __exn_validator.finish(::exn::ValidationError::new(::core::stringify!(#ident)))
+            }
+        }
+    })
+}
+
+fn check_signature(args: &TokenStream, func: &ItemFn) -> syn::Result<()> {
+    if !args.is_empty() {
+        let args = proc_macro2::TokenStream::from(args.clone());
+        return Err(syn::Error::new(args.span(), "unexpected arguments"));
+    }
+    if !func.sig.inputs.is_empty() {
+        let msg = "the function must take no arguments";
+        return Err(syn::Error::new(func.sig.inputs.span(), msg));
+    }
+    Ok(())
+}
+
+/// Check whether `attr` is the entry point attribute of an async runtime, e.g., `#[tokio::main]`.
+fn is_runtime_main(attr: &Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "main")
+}
@@ -0,0 +1,246 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Convert [`exn`](https://docs.rs/exn) exception trees into [miette](https://docs.rs/miette)
+//! diagnostics.
+//!
+//! [`ExnDiagnostic`] mirrors an exception tree as a tree of diagnostics, so that codebases
+//! reporting errors with miette can consume libraries returning [`Exn`]:
+//!
+//! * the message is the error message, or `«redacted»` for [sensitive](exn::Exn::mark_sensitive)
+//!   frames;
+//! * the [code](exn::Frame::code) and [severity](exn::Frame::severity) are carried over;
+//! * the [attachments](exn::Frame::attachments) and the [hint](exn::Frame::help) become the help
+//!   text, redacted for sensitive frames, and the [URL](exn::Frame::url) of the documentation is
+//!   carried over;
+//! * the children become the [related](Diagnostic::related) diagnostics;
+//! * the locations become labels, once the source files are loaded with
+//!   [`with_sources`](ExnDiagnostic::with_sources).
+//!
+//! # Examples
+//!
+//! ```
+//! use exn_miette::ExnDiagnostic;
+//!
+//! fn load() -> exn::Result<(), std::io::Error> {
+//!     exn::bail!(std::io::Error::other("failed to load config"))
+//! }
+//!
+//! fn run() -> miette::Result<()> {
+//!     load().map_err(|exn| ExnDiagnostic::from(exn).with_sources("."))?;
+//!     Ok(())
+//! }
+//!
+//! let report = run().unwrap_err();
+//! assert_eq!(report.to_string(), "failed to load config");
+//! ```
+
+#![deny(missing_docs)]
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use exn::Exn;
+use exn::Frame;
+use exn::FrameLocation;
+use exn::FrameOrigin;
+use miette::Diagnostic;
+use miette::LabeledSpan;
+use miette::NamedSource;
+use miette::Severity;
+use miette::SourceCode;
+
+/// A miette diagnostic mirroring an exception tree.
+///
+/// Every error is degenerated to its string representation, so the diagnostic owns no trait
+/// objects of the original tree and can be created from an [`Exn`] or a borrowed [`Frame`].
+pub struct ExnDiagnostic {
+    message: String,
+    code: Option<&'static str>,
+    severity: Severity,
+    help: Option<String>,
+    url: Option<String>,
+    location: Option<FrameLocation>,
+    source_code: Option<NamedSource<Arc<String>>>,
+    label: Option<LabeledSpan>,
+    related: Vec<ExnDiagnostic>,
+}
+
+impl ExnDiagnostic {
+    /// Create a diagnostic mirroring the exception tree rooted at `frame`.
+    pub fn new(frame: &Frame) -> Self {
+        let message = frame.redacted_message().to_string();
+        let severity = match frame.severity() {
+            exn::Severity::Info => Severity::Advice,
+            exn::Severity::Warning => Severity::Warning,
+            exn::Severity::Error => Severity::Error,
+        };
+        // the URL is carried over on its own, so only the hint to fix the error joins the help
+        let attachments = frame.attachments().iter().zip(frame.redacted_attachments());
+        let mut help = attachments
+            .filter(|(attachment, _)| !attachment.is_hint())
+            .map(|(_, redacted)| redacted.to_string())
+            .collect::<Vec<_>>();
+        if !frame.is_sensitive() {
+            help.extend(frame.help());
+        }
+        let help = (!help.is_empty()).then(|| help.join("\n"));
+        // frames walked from the source chain share the location of their parent
+        let location = (frame.origin() != FrameOrigin::SourceChain)
+            .then(|| frame.known_location().map(Cow::into_owned))
+            .flatten();
+
+        Self {
+            message,
+            code: frame.code(),
+            severity,
+            help,
+            url: frame.url(),
+            location,
+            source_code: None,
+            label: None,
+            related: frame.children().iter().map(ExnDiagnostic::new).collect(),
+        }
+    }
+
+    /// Load the source files of the locations in the tree, relative to `root`, and label each
+    /// diagnostic with its location.
+    ///
+    /// Locations recorded in the crates of a workspace are relative to the workspace root, so
+    /// `root` is typically the workspace root, or `.` when running from it. Diagnostics whose
+    /// source file cannot be read are left without a label.
+    pub fn with_sources(mut self, root: impl AsRef<Path>) -> Self {
+        let mut sources = HashMap::new();
+        self.load_sources(root.as_ref(), &mut sources);
+        self
+    }
+
+    fn load_sources(&mut self, root: &Path, sources: &mut HashMap<String, Option<Arc<String>>>) {
+        let location = self.location.as_ref();
+        if let Some((location, file)) = location.and_then(|l| Some((l, l.path()?.into_owned()))) {
+            let source = sources
+                .entry(file.clone())
+                .or_insert_with(|| std::fs::read_to_string(root.join(&file)).ok().map(Arc::new));
+            let span = source
+                .as_ref()
+                .and_then(|source| Some((source.clone(), span_of(source, location)?)));
+            if let Some((source, span)) = span {
+                let label = LabeledSpan::new_primary_with_span(Some("here".to_string()), span);
+                self.source_code = Some(NamedSource::new(&file, source));
+                self.label = Some(label);
+            }
+        }
+        for related in &mut self.related {
+            related.load_sources(root, sources);
+        }
+    }
+}
+
+/// Return the byte range of the expression starting at `location` in `source`, which is the
+/// identifier at `location`, or a single character.
+fn span_of(source: &str, location: &FrameLocation) -> Option<(usize, usize)> {
+    let line = usize::try_from(location.line()?).ok()?.checked_sub(1)?;
+    let column = usize::try_from(location.column()?).ok()?.checked_sub(1)?;
+    let line_start = source
+        .split_inclusive('\n')
+        .take(line)
+        .map(str::len)
+        .sum::<usize>();
+    let text = source[line_start..].lines().next()?;
+    let (offset, _) = text.char_indices().nth(column)?;
+    let len = text[offset..]
+        .char_indices()
+        .find(|(_, c)| !(c.is_alphanumeric() || *c == '_'))
+        .map_or(text.len() - offset, |(i, _)| i)
+        .max(1);
+    Some((line_start + offset, len))
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for ExnDiagnostic {
+    fn from(exn: Exn<E>) -> Self {
+        ExnDiagnostic::new(exn.frame())
+    }
+}
+
+impl fmt::Debug for ExnDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExnDiagnostic")
+            .field("message", &self.message)
+            .field("code", &self.code)
+            .field("severity", &self.severity)
+            .field("help", &self.help)
+            .field("url", &self.url)
+            .field("related", &self.related)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ExnDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// the children are reported as related diagnostics rather than as sources, so that reporters
+// show the whole tree instead of the first chain
+impl Error for ExnDiagnostic {}
+
+impl Diagnostic for ExnDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.code
+            .map(|code| Box::new(code) as Box<dyn fmt::Display + 'a>)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn fmt::Display + 'a>)
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.url
+            .as_ref()
+            .map(|url| Box::new(url) as Box<dyn fmt::Display + 'a>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.source_code
+            .as_ref()
+            .map(|source| source as &dyn SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = self.label.clone()?;
+        Some(Box::new(std::iter::once(label)))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.related.is_empty() {
+            return None;
+        }
+        Some(Box::new(
+            self.related
+                .iter()
+                .map(|related| related as &dyn Diagnostic),
+        ))
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use exn::Exn;
+use exn_miette::ExnDiagnostic;
+use miette::Diagnostic;
+use miette::NarratableReportHandler;
+use miette::Severity;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[test]
+fn tree() {
+    let exn = Exn::raise_all(
+        Error("failed to sync"),
+        [
+            Exn::new(Error("timeout"))
+                .attach("retried 3 times")
+                .with_help("raise the timeout")
+                .with_url("https://example.com/timeout"),
+            Exn::new(Error("secret")).mark_sensitive(),
+        ],
+    )
+    .with_severity(exn::Severity::Warning);
+
+    let diagnostic = ExnDiagnostic::from(exn);
+    assert_eq!(diagnostic.to_string(), "failed to sync");
+    assert_eq!(diagnostic.severity(), Some(Severity::Warning));
+    assert!(diagnostic.help().is_none());
+    assert!(diagnostic.labels().is_none());
+
+    let related = diagnostic.related().unwrap().collect::<Vec<_>>();
+    assert_eq!(related.len(), 2);
+    assert_eq!(related[0].to_string(), "timeout");
+    assert_eq!(related[0].severity(), Some(Severity::Error));
+    assert_eq!(
+        related[0].help().unwrap().to_string(),
+        "retried 3 times\nraise the timeout"
+    );
+    assert_eq!(
+        related[0].url().unwrap().to_string(),
+        "https://example.com/timeout"
+    );
+    assert!(related[0].related().is_none());
+    assert_eq!(related[1].to_string(), "«redacted»");
+}
+
+#[test]
+fn labels() {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+    let line = line!() + 1;
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+
+    let diagnostic = ExnDiagnostic::new(exn.frame()).with_sources(root);
+    let labels = diagnostic.labels().unwrap().collect::<Vec<_>>();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].label(), Some("here"));
+    assert_eq!(labels[0].len(), "raise".len());
+
+    let source = diagnostic.source_code().unwrap();
+    let contents = source.read_span(labels[0].inner(), 0, 0).unwrap();
+    assert_eq!(contents.data(), b"raise");
+    assert_eq!(contents.line() + 1, line as usize);
+
+    let mut output = String::new();
+    NarratableReportHandler::new()
+        .render_report(&mut output, &diagnostic)
+        .unwrap();
+    assert!(output.starts_with("outer\n"), "{output}");
+    assert!(output.contains("inner"), "{output}");
+
+    // sources that cannot be read are ignored
+    let diagnostic = ExnDiagnostic::new(exn.frame()).with_sources("/nonexistent");
+    assert!(diagnostic.labels().is_none());
+    assert!(diagnostic.source_code().is_none());
+}
@@ -0,0 +1,130 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Record [`exn`](https://docs.rs/exn) exception trees as
+//! [OpenTelemetry](https://docs.rs/opentelemetry) span events.
+//!
+//! Each frame of the tree becomes one `exception` event, so that distributed traces show the
+//! whole failure tree instead of one flattened string. Events carry the following attributes:
+//!
+//! * `exception.type`: the stable name of the [registered type](exn::register_error_type) of the
+//!   error, or its [type name](exn::Frame::type_name) if known.
+//! * `exception.message`: the error message, or `«redacted»` for
+//!   [sensitive](exn::Exn::mark_sensitive) frames.
+//! * `code.filepath`, `code.lineno` and `code.column`: the [location](exn::Frame::known_location)
+//!   of the frame, if known; an opaque location is written as is in `code.filepath`, without line
+//!   and column.
+//! * `exn.index`, `exn.depth` and `exn.parent`: the position of the frame in the tree, in
+//!   depth-first pre-order; the topmost frame has index 0 and no parent.
+//! * `exn.attachments`: the [attachments](exn::Frame::attachments) of the frame, if any, each
+//!   `«redacted»` for sensitive frames.
+//!
+//! # Examples
+//!
+//! ```
+//! let exn = exn::Exn::new(std::io::Error::other("connection reset"));
+//!
+//! // record the exception tree on the current span
+//! exn_otel::record_current(exn.frame());
+//! ```
+
+#![deny(missing_docs)]
+
+use exn::Frame;
+use opentelemetry::Array;
+use opentelemetry::KeyValue;
+use opentelemetry::StringValue;
+use opentelemetry::Value;
+use opentelemetry::trace::Span;
+use opentelemetry::trace::get_active_span;
+
+/// The name of the events recorded for each frame, as defined by the semantic conventions.
+pub const EVENT_NAME: &str = "exception";
+
+/// Record the exception tree rooted at `frame` as events on `span`.
+pub fn record<S: Span>(span: &mut S, frame: &Frame) {
+    for attributes in events(frame) {
+        span.add_event(EVENT_NAME, attributes);
+    }
+}
+
+/// Record the exception tree rooted at `frame` as events on the current span.
+///
+/// Nothing is recorded if there is no current span.
+pub fn record_current(frame: &Frame) {
+    get_active_span(|span| {
+        if !span.is_recording() {
+            return;
+        }
+        for attributes in events(frame) {
+            span.add_event(EVENT_NAME, attributes);
+        }
+    });
+}
+
+/// Return the attributes of the event of each frame in the exception tree rooted at `frame`, in
+/// depth-first pre-order.
+pub fn events(frame: &Frame) -> Vec<Vec<KeyValue>> {
+    fn walk(frame: &Frame, depth: usize, parent: Option<usize>, events: &mut Vec<Vec<KeyValue>>) {
+        let index = events.len();
+        events.push(attributes(frame, index, depth, parent));
+        for child in frame.children() {
+            walk(child, depth + 1, Some(index), events);
+        }
+    }
+
+    let mut events = Vec::new();
+    walk(frame, 0, None, &mut events);
+    events
+}
+
+fn attributes(frame: &Frame, index: usize, depth: usize, parent: Option<usize>) -> Vec<KeyValue> {
+    let message = frame.redacted_message().to_string();
+
+    let mut attributes = Vec::with_capacity(9);
+    let error_type = frame.error_type().map(|error_type| error_type.name());
+    if let Some(type_name) = error_type.or(frame.type_name()) {
+        attributes.push(KeyValue::new("exception.type", type_name));
+    }
+    attributes.push(KeyValue::new("exception.message", message));
+    if let Some(location) = frame.known_location() {
+        let filepath = match location.path() {
+            Some(path) => path.into_owned(),
+            None => location.to_string(),
+        };
+        attributes.push(KeyValue::new("code.filepath", filepath));
+        if let Some(line) = location.line() {
+            attributes.push(KeyValue::new("code.lineno", i64::from(line)));
+        }
+        if let Some(column) = location.column() {
+            attributes.push(KeyValue::new("code.column", i64::from(column)));
+        }
+    }
+    attributes.push(KeyValue::new("exn.index", index as i64));
+    attributes.push(KeyValue::new("exn.depth", depth as i64));
+    if let Some(parent) = parent {
+        attributes.push(KeyValue::new("exn.parent", parent as i64));
+    }
+    if !frame.attachments().is_empty() {
+        let attachments = frame
+            .redacted_attachments()
+            .map(|attachment| StringValue::from(attachment.to_string()))
+            .collect::<Vec<_>>();
+        attributes.push(KeyValue::new(
+            "exn.attachments",
+            Value::Array(Array::String(attachments)),
+        ));
+    }
+    attributes
+}
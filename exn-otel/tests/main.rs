@@ -0,0 +1,151 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use exn::Exn;
+use opentelemetry::Context;
+use opentelemetry::KeyValue;
+use opentelemetry::Value;
+use opentelemetry::trace::Span;
+use opentelemetry::trace::SpanContext;
+use opentelemetry::trace::Status;
+use opentelemetry::trace::TraceContextExt;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+type Events = Arc<Mutex<Vec<(String, Vec<KeyValue>)>>>;
+
+/// A span that keeps its events.
+#[derive(Debug)]
+struct MockSpan {
+    context: SpanContext,
+    events: Events,
+}
+
+impl MockSpan {
+    fn new() -> Self {
+        Self {
+            context: SpanContext::empty_context(),
+            events: Events::default(),
+        }
+    }
+}
+
+impl Span for MockSpan {
+    fn add_event_with_timestamp<T>(&mut self, name: T, _: SystemTime, attributes: Vec<KeyValue>)
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let name = name.into().into_owned();
+        self.events.lock().unwrap().push((name, attributes));
+    }
+
+    fn span_context(&self) -> &SpanContext {
+        &self.context
+    }
+
+    fn is_recording(&self) -> bool {
+        true
+    }
+
+    fn set_attribute(&mut self, _: KeyValue) {}
+
+    fn set_status(&mut self, _: Status) {}
+
+    fn update_name<T>(&mut self, _: T)
+    where
+        T: Into<Cow<'static, str>>,
+    {
+    }
+
+    fn add_link(&mut self, _: SpanContext, _: Vec<KeyValue>) {}
+
+    fn end_with_timestamp(&mut self, _: SystemTime) {}
+}
+
+fn get<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a Value> {
+    attributes
+        .iter()
+        .find(|kv| kv.key.as_str() == key)
+        .map(|kv| &kv.value)
+}
+
+#[test]
+fn record() {
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Error("first")).attach("while reading row 42"),
+            Exn::new(Error("second")).mark_sensitive(),
+        ],
+    );
+
+    let mut span = MockSpan::new();
+    exn_otel::record(&mut span, exn.frame());
+
+    let events = span.events.lock().unwrap();
+    assert_eq!(events.len(), 3);
+    assert!(events.iter().all(|(name, _)| name == exn_otel::EVENT_NAME));
+
+    let (_, outer) = &events[0];
+    assert_eq!(get(outer, "exception.message"), Some(&Value::from("outer")));
+    assert_eq!(
+        get(outer, "exception.type").map(Value::as_str).as_deref(),
+        Some(std::any::type_name::<Error>())
+    );
+    assert_eq!(
+        get(outer, "code.filepath"),
+        Some(&Value::from("exn-otel/tests/main.rs"))
+    );
+    assert_eq!(get(outer, "exn.parent"), None);
+
+    let (_, first) = &events[1];
+    assert_eq!(get(first, "exn.depth"), Some(&Value::I64(1)));
+    assert_eq!(get(first, "exn.parent"), Some(&Value::I64(0)));
+    assert_eq!(
+        get(first, "exn.attachments").map(Value::as_str).as_deref(),
+        Some("[\"while reading row 42\"]")
+    );
+
+    let (_, second) = &events[2];
+    assert_eq!(get(second, "exn.index"), Some(&Value::I64(2)));
+    assert_eq!(
+        get(second, "exception.message"),
+        Some(&Value::from("«redacted»"))
+    );
+}
+
+#[test]
+fn record_current() {
+    let span = MockSpan::new();
+    let events = span.events.clone();
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+
+    let _guard = Context::current_with_span(span).attach();
+    exn_otel::record_current(exn.frame());
+    assert_eq!(events.lock().unwrap().len(), 2);
+}
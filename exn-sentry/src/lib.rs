@@ -0,0 +1,169 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Convert [`exn`](https://docs.rs/exn) exception trees into [Sentry](https://docs.rs/sentry)
+//! events.
+//!
+//! Each frame of the [primary chain](exn::Frame::primary_chain) becomes one exception of the
+//! event, from the root cause up to the topmost frame, so that Sentry groups and links events by
+//! their chain of errors. Each exception carries the following data:
+//!
+//! * `type`: the stable name of the [registered type](exn::register_error_type) of the error, or
+//!   its [type name](exn::Frame::type_name) if known, or `Error`.
+//! * `value`: the error message, or `«redacted»` for [sensitive](exn::Exn::mark_sensitive) frames.
+//! * `stacktrace`: one stack frame at the location of the frame, with its
+//!   [function](exn::Frame::function) if known.
+//! * `mechanism`: of type `exn`, with the [attachments](exn::Frame::attachments) of the frame in
+//!   its `attachments` data, if any, each `«redacted»` for sensitive frames.
+//!
+//! The other children of the frames of the primary chain are rendered as with `{:?}`, with
+//! sensitive messages redacted, into the `exn.siblings` extra data of the event. The level of the
+//! event follows the [severity](exn::Frame::severity) of the topmost frame.
+//!
+//! # Examples
+//!
+//! ```
+//! let exn = exn::Exn::new(std::io::Error::other("connection reset"));
+//!
+//! let event = exn_sentry::to_event(exn.frame());
+//! assert_eq!(event.exception.len(), 1);
+//!
+//! // capture the exception tree with the current hub
+//! exn_sentry::capture(exn.frame());
+//! ```
+
+#![deny(missing_docs)]
+
+use std::borrow::Cow;
+
+use exn::Frame;
+use exn::FrameLocation;
+use exn::Severity;
+use exn::SourceLocation;
+use exn::report::Compact;
+use sentry_core::Level;
+use sentry_core::protocol::Event;
+use sentry_core::protocol::Exception;
+use sentry_core::protocol::Map;
+use sentry_core::protocol::Mechanism;
+use sentry_core::protocol::Stacktrace;
+use sentry_core::protocol::Uuid;
+use sentry_core::protocol::Value;
+
+/// The key of the extra data carrying the other children of the frames of the primary chain.
+pub const SIBLINGS_KEY: &str = "exn.siblings";
+
+/// Create an event of the exception tree rooted at `frame`.
+pub fn to_event(frame: &Frame) -> Event<'static> {
+    let chain = frame.primary_chain().collect::<Vec<_>>();
+    let exceptions = chain
+        .iter()
+        .rev()
+        .map(|frame| exception(frame))
+        .collect::<Vec<_>>();
+
+    let siblings = chain
+        .iter()
+        .flat_map(|frame| {
+            let primary = frame.primary_child();
+            frame
+                .children()
+                .iter()
+                .filter(move |child| !primary.is_some_and(|primary| std::ptr::eq(primary, *child)))
+        })
+        .map(|sibling| Value::from(Compact::new(sibling).with_redaction(true).to_string()))
+        .collect::<Vec<_>>();
+    let mut extra = Map::new();
+    if !siblings.is_empty() {
+        extra.insert(SIBLINGS_KEY.to_string(), Value::Array(siblings));
+    }
+
+    Event {
+        exception: exceptions.into(),
+        level: level(frame.severity()),
+        extra,
+        ..Default::default()
+    }
+}
+
+/// Capture the exception tree rooted at `frame` as an event with the current hub, and return the
+/// id of the event.
+///
+/// The id is nil if the event was not sent, e.g., because no client is bound to the hub.
+pub fn capture(frame: &Frame) -> Uuid {
+    sentry_core::capture_event(to_event(frame))
+}
+
+fn exception(frame: &Frame) -> Exception {
+    let value = frame.redacted_message().to_string();
+    let ty = match frame.error_type() {
+        Some(error_type) => error_type.name(),
+        None => frame.type_name().unwrap_or("Error"),
+    };
+
+    let mut data = Map::new();
+    if !frame.attachments().is_empty() {
+        let attachments = frame
+            .redacted_attachments()
+            .map(|attachment| Value::from(attachment.to_string()))
+            .collect::<Vec<_>>();
+        data.insert("attachments".to_string(), Value::Array(attachments));
+    }
+
+    Exception {
+        ty: ty.to_string(),
+        value: Some(value),
+        stacktrace: Stacktrace::from_frames_reversed(vec![stack_frame(frame)]),
+        mechanism: Some(Mechanism {
+            ty: "exn".to_string(),
+            data,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn stack_frame(frame: &Frame) -> sentry_core::protocol::Frame {
+    let location = frame.known_location().map(Cow::into_owned);
+    let (filename, lineno, colno) = match location {
+        Some(FrameLocation::Rust(location)) => {
+            let location = SourceLocation::from(location);
+            let filename = location.file().into_owned();
+            (
+                Some(filename),
+                Some(location.line()),
+                Some(location.column()),
+            )
+        }
+        Some(FrameLocation::File { path, line, column }) => (Some(path), Some(line), Some(column)),
+        Some(location) => (Some(location.to_string()), None, None),
+        None => (None, None, None),
+    };
+    sentry_core::protocol::Frame {
+        function: frame.function().map(str::to_string),
+        filename,
+        lineno: lineno.map(u64::from),
+        colno: colno.map(u64::from),
+        in_app: Some(true),
+        ..Default::default()
+    }
+}
+
+fn level(severity: Severity) -> Level {
+    match severity {
+        Severity::Info => Level::Info,
+        Severity::Warning => Level::Warning,
+        Severity::Error => Level::Error,
+    }
+}
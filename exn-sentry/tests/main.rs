@@ -0,0 +1,99 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use exn::Exn;
+use exn::Severity;
+use sentry_core::Level;
+use sentry_core::protocol::Value;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[test]
+fn to_event() {
+    let exn = Exn::raise_all(
+        Error("failed to sync"),
+        [
+            Exn::new(Error("disk full")).attach("while writing chunk 3"),
+            Exn::new(Error("token expired")).mark_sensitive(),
+        ],
+    );
+    let location = exn::SourceLocation::from(exn.frame().location());
+
+    let event = exn_sentry::to_event(exn.frame());
+    assert_eq!(event.level, Level::Error);
+    assert_eq!(event.exception.len(), 2);
+
+    let root = &event.exception[0];
+    assert_eq!(root.value.as_deref(), Some("disk full"));
+    assert_eq!(root.ty, std::any::type_name::<Error>());
+    let mechanism = root.mechanism.as_ref().unwrap();
+    assert_eq!(mechanism.ty, "exn");
+    assert_eq!(
+        mechanism.data.get("attachments"),
+        Some(&Value::from(vec!["while writing chunk 3"]))
+    );
+
+    let top = &event.exception[1];
+    assert_eq!(top.value.as_deref(), Some("failed to sync"));
+    let frames = &top.stacktrace.as_ref().unwrap().frames;
+    assert_eq!(frames.len(), 1);
+    assert_eq!(
+        frames[0].filename.as_deref(),
+        Some("exn-sentry/tests/main.rs")
+    );
+    assert_eq!(frames[0].lineno, Some(u64::from(location.line())));
+    assert_eq!(frames[0].colno, Some(u64::from(location.column())));
+
+    let siblings = event.extra[exn_sentry::SIBLINGS_KEY].as_array().unwrap();
+    assert_eq!(siblings.len(), 1);
+    assert!(siblings[0].as_str().unwrap().starts_with("«redacted», at "));
+}
+
+#[test]
+fn to_event_with_file_location() {
+    let exn = Exn::new(Error("undefined variable"))
+        .with_location(exn::FrameLocation::file("main.py", 7, 3))
+        .with_severity(Severity::Warning);
+
+    let event = exn_sentry::to_event(exn.frame());
+    assert_eq!(event.level, Level::Warning);
+    assert!(event.extra.is_empty());
+
+    let frame = &event.exception[0].stacktrace.as_ref().unwrap().frames[0];
+    assert_eq!(frame.filename.as_deref(), Some("main.py"));
+    assert_eq!(frame.lineno, Some(7));
+    assert_eq!(frame.colno, Some(3));
+}
+
+#[test]
+fn capture() {
+    let events = sentry_core::test::with_captured_events(|| {
+        let exn = Exn::new(Error("connection reset"));
+        exn_sentry::capture(exn.frame());
+    });
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].exception[0].value.as_deref(),
+        Some("connection reset")
+    );
+}
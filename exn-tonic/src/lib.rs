@@ -0,0 +1,323 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Convert [`exn`](https://docs.rs/exn) exception trees to and from [`tonic`](https://docs.rs/tonic)
+//! gRPC statuses, so that errors survive the RPC hop.
+//!
+//! On the server side, [`to_status`] creates a [`Status`] whose code is chosen by a
+//! [`CodePolicy`], typically matching on the [kind](exn::Kinded) of the flat boundary error, and
+//! whose details carry the exception tree encoded as JSON. On the client side, [`from_status`]
+//! decodes the tree back into an [`Exn<StatusError>`] whose children are the remote frames, each
+//! [located](exn::Exn::with_location) at its remote location.
+//!
+//! The details hold one JSON object per frame, with the following fields:
+//!
+//! * `message`: the error message, or `«redacted»` for [sensitive](exn::Exn::mark_sensitive)
+//!   frames.
+//! * `location`: the [location](exn::Frame::known_location) of the frame, if known.
+//! * `attachments`: the attachments of the frame, each with its `message`, `«redacted»` for
+//!   sensitive frames, and `location`.
+//! * `children`: the child frames, encoded in the same way.
+//!
+//! # Examples
+//!
+//! ```
+//! use exn::Exn;
+//! use exn::Kinded;
+//! use tonic::Code;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum ErrorKind {
+//!     NotFound,
+//!     Internal,
+//! }
+//!
+//! #[derive(Debug)]
+//! struct ApiError(ErrorKind);
+//!
+//! impl core::fmt::Display for ApiError {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "{:?}", self.0)
+//!     }
+//! }
+//!
+//! impl core::error::Error for ApiError {}
+//!
+//! impl Kinded for ApiError {
+//!     type Kind = ErrorKind;
+//!
+//!     fn kind(&self) -> ErrorKind {
+//!         self.0
+//!     }
+//! }
+//!
+//! let policy = exn_tonic::by_kind(|kind| match kind {
+//!     ErrorKind::NotFound => Code::NotFound,
+//!     ErrorKind::Internal => Code::Internal,
+//! });
+//!
+//! // on the server
+//! let exn = Exn::new(ApiError(ErrorKind::NotFound));
+//! let status = exn_tonic::to_status(&exn, policy);
+//! assert_eq!(status.code(), Code::NotFound);
+//!
+//! // on the client
+//! let exn = exn_tonic::from_status(&status);
+//! assert_eq!(exn.grpc_code(), Code::NotFound);
+//! assert_eq!(exn.to_string(), "NotFound");
+//! ```
+
+#![deny(missing_docs)]
+
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use bytes::Bytes;
+use exn::Exn;
+use exn::Frame;
+use exn::FrameLocation;
+use exn::Kinded;
+use exn::SourceLocation;
+use serde_json::Value;
+use serde_json::json;
+use tonic::Code;
+use tonic::Status;
+
+/// A policy choosing the gRPC code of the status of an exception.
+///
+/// Closures taking an exception and returning a code are policies.
+pub trait CodePolicy<E: Error + Send + Sync + 'static> {
+    /// Return the gRPC code of the status of `exn`.
+    fn code(&self, exn: &Exn<E>) -> Code;
+}
+
+impl<E, F> CodePolicy<E> for F
+where
+    E: Error + Send + Sync + 'static,
+    F: Fn(&Exn<E>) -> Code,
+{
+    fn code(&self, exn: &Exn<E>) -> Code {
+        self(exn)
+    }
+}
+
+/// The default [`CodePolicy`], choosing [`Code::Internal`] for every exception.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Internal;
+
+impl<E: Error + Send + Sync + 'static> CodePolicy<E> for Internal {
+    fn code(&self, _: &Exn<E>) -> Code {
+        Code::Internal
+    }
+}
+
+/// A [`CodePolicy`] mapping the [kind](Exn::kind) of the top-level error to a gRPC code.
+///
+/// This policy is created by [`by_kind`].
+pub struct ByKind<E, F> {
+    f: F,
+    error: PhantomData<fn(&E)>,
+}
+
+/// Create a [`CodePolicy`] mapping the [kind](Exn::kind) of the top-level error to a gRPC code
+/// with `f`.
+pub fn by_kind<E, F>(f: F) -> ByKind<E, F>
+where
+    E: Kinded,
+    F: Fn(E::Kind) -> Code,
+{
+    ByKind {
+        f,
+        error: PhantomData,
+    }
+}
+
+impl<E, F> CodePolicy<E> for ByKind<E, F>
+where
+    E: Kinded,
+    F: Fn(E::Kind) -> Code,
+{
+    fn code(&self, exn: &Exn<E>) -> Code {
+        (self.f)(exn.kind())
+    }
+}
+
+impl<E, F> fmt::Debug for ByKind<E, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByKind").finish_non_exhaustive()
+    }
+}
+
+/// Create a status for `exn`, with the code chosen by `policy` and the exception tree encoded in
+/// its details.
+///
+/// The message of the status is the message of the top-level error, or `«redacted»` if it is
+/// [sensitive](Exn::mark_sensitive). The details reveal the internals of the service, like
+/// messages and locations of all frames; strip them before responding to untrusted clients.
+pub fn to_status<E, P>(exn: &Exn<E>, policy: P) -> Status
+where
+    E: Error + Send + Sync + 'static,
+    P: CodePolicy<E>,
+{
+    let code = policy.code(exn);
+    let message = exn.frame().redacted_message().to_string();
+    let details = encode(exn.frame()).to_string();
+    Status::with_details(code, message, Bytes::from(details))
+}
+
+/// Create an exception from a status received from a remote service.
+///
+/// The top-level error carries the code and message of the status. If the details of the status
+/// carry an exception tree encoded by [`to_status`], the attachments of the remote top-level
+/// frame are restored, and its children become the children of the exception as
+/// [`RemoteError`]s. All frames are located at the caller; the remote locations are kept on the
+/// errors.
+#[track_caller]
+pub fn from_status(status: &Status) -> Exn<StatusError> {
+    let tree = serde_json::from_slice::<Value>(status.details()).ok();
+    let tree = tree.as_ref().filter(|tree| tree.is_object());
+    let error = StatusError {
+        code: status.code(),
+        message: status.message().to_string(),
+        location: tree.and_then(location),
+    };
+    match tree {
+        Some(tree) => decode_frame(error, tree),
+        None => Exn::new(error),
+    }
+}
+
+/// The error of an exception created from a gRPC status by [`from_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusError {
+    code: Code,
+    message: String,
+    location: Option<String>,
+}
+
+impl StatusError {
+    /// Return the gRPC code of the status.
+    pub fn grpc_code(&self) -> Code {
+        self.code
+    }
+
+    /// Return the message of the status.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Return the location of the remote frame, as `file:line:column`, if the status carried an
+    /// exception tree.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+impl fmt::Display for StatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for StatusError {}
+
+/// The error of a remote frame decoded from the details of a gRPC status by [`from_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteError {
+    message: String,
+    location: Option<String>,
+}
+
+impl RemoteError {
+    /// Return the message of the remote frame.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Return the location of the remote frame, as `file:line:column`.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+impl fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for RemoteError {}
+
+fn encode(frame: &Frame) -> Value {
+    fn location(location: &'static std::panic::Location<'static>) -> String {
+        SourceLocation::from(location).to_string()
+    }
+
+    let attachments = frame
+        .attachments()
+        .iter()
+        .zip(frame.redacted_attachments())
+        .map(|(attachment, redacted)| {
+            json!({
+                "message": redacted.to_string(),
+                "location": attachment.known_location().map(location),
+            })
+        })
+        .collect::<Vec<_>>();
+    let children = frame.children().iter().map(encode).collect::<Vec<_>>();
+    json!({
+        "message": frame.redacted_message().to_string(),
+        "location": frame.known_location().map(|location| location.to_string()),
+        "attachments": attachments,
+        "children": children,
+    })
+}
+
+#[track_caller]
+fn decode_frame<E: Error + Send + Sync + 'static>(error: E, tree: &Value) -> Exn<E> {
+    let children = array(tree, "children").map(|child| {
+        let error = RemoteError {
+            message: string(child, "message").unwrap_or_default().to_string(),
+            location: location(child),
+        };
+        decode_frame(error, child)
+    });
+    let mut exn = Exn::raise_all(error, children);
+    // reports print the location of the remote frame rather than the local one
+    if let Some(location) = location(tree) {
+        exn = exn.with_location(FrameLocation::opaque(location));
+    }
+    for attachment in array(tree, "attachments") {
+        if let Some(message) = string(attachment, "message") {
+            exn = exn.attach(message.to_string());
+        }
+    }
+    exn
+}
+
+fn location(tree: &Value) -> Option<String> {
+    string(tree, "location").map(str::to_string)
+}
+
+fn string<'a>(tree: &'a Value, key: &str) -> Option<&'a str> {
+    tree.get(key).and_then(Value::as_str)
+}
+
+fn array<'a>(tree: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    tree.get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
@@ -0,0 +1,95 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use exn::Exn;
+use exn_tonic::RemoteError;
+use tonic::Code;
+use tonic::Status;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[test]
+fn round_trip() {
+    let exn = Exn::raise_all(
+        Error("failed to sync"),
+        [
+            Exn::new(Error("disk full")).attach("while writing chunk 3"),
+            Exn::new(Error("timed out"))
+                .attach("token abc")
+                .mark_sensitive(),
+        ],
+    )
+    .attach("while handling /sync");
+    let remote_location = exn::SourceLocation::from(exn.frame().location()).to_string();
+
+    let status = exn_tonic::to_status(&exn, |_: &Exn<Error>| Code::Unavailable);
+    assert_eq!(status.code(), Code::Unavailable);
+    assert_eq!(status.message(), "failed to sync");
+
+    let exn = exn_tonic::from_status(&status);
+    assert_eq!(exn.grpc_code(), Code::Unavailable);
+    assert_eq!(exn.location(), Some(remote_location.as_str()));
+    assert_eq!(exn.frame().frame_location().to_string(), remote_location);
+    let expected = r#"failed to sync
+= while handling /sync
+|
+|-> disk full
+|   = while writing chunk 3
+|
+|-> «redacted»
+    = «redacted»"#;
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        expected
+    );
+
+    let child = exn.frame().children()[0].error();
+    let child = child.downcast_ref::<RemoteError>().unwrap();
+    assert_eq!(child.message(), "disk full");
+    assert!(
+        child
+            .location()
+            .unwrap()
+            .starts_with("exn-tonic/tests/main.rs:")
+    );
+}
+
+#[test]
+fn default_policy() {
+    let exn = Exn::new(Error("secret")).mark_sensitive();
+    let status = exn_tonic::to_status(&exn, exn_tonic::Internal);
+    assert_eq!(status.code(), Code::Internal);
+    assert_eq!(status.message(), "«redacted»");
+}
+
+#[test]
+fn plain_status() {
+    let status = Status::not_found("user not found");
+    let exn = exn_tonic::from_status(&status);
+    assert_eq!(exn.grpc_code(), Code::NotFound);
+    assert_eq!(exn.location(), None);
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        "user not found"
+    );
+}
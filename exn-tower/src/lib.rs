@@ -0,0 +1,319 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A [`tower`](https://docs.rs/tower) layer turning [`exn`](https://docs.rs/exn) exceptions into
+//! HTTP responses.
+//!
+//! [`ExnLayer`] wraps services whose error is an [`Exn`]. When the inner service fails, the
+//! layer attaches the request method, URI and request ID to the exception, reports it to the
+//! [`on_error`](ExnLayer::on_error) hook, and responds with an opaque message or a JSON
+//! rendering of the exception tree. The wrapped service never fails, so it can be mounted
+//! directly on routers such as `axum`.
+//!
+//! # Examples
+//!
+//! ```
+//! use exn_tower::ExnLayer;
+//!
+//! let layer = ExnLayer::new()
+//!     .with_json_tree(cfg!(debug_assertions))
+//!     .on_error(|frame| eprintln!("{frame:?}"));
+//! # let _ = layer;
+//! ```
+
+#![deny(missing_docs)]
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use exn::Exn;
+use exn::Frame;
+use exn::SourceLocation;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use http::header::CONTENT_TYPE;
+use serde_json::Value;
+use serde_json::json;
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// A layer that turns the exceptions of the inner service into HTTP responses.
+///
+/// See the [crate documentation](crate) for more information.
+#[derive(Clone)]
+pub struct ExnLayer {
+    config: Config,
+}
+
+#[derive(Clone)]
+struct Config {
+    status: StatusCode,
+    message: Cow<'static, str>,
+    json_tree: bool,
+    request_id_header: HeaderName,
+    on_error: Option<Arc<OnError>>,
+}
+
+type OnError = dyn Fn(&Frame) + Send + Sync;
+
+impl ExnLayer {
+    /// Create a layer responding with `500 Internal Server Error` and an opaque message.
+    pub fn new() -> Self {
+        Self {
+            config: Config {
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                message: Cow::Borrowed("internal server error"),
+                json_tree: false,
+                request_id_header: HeaderName::from_static("x-request-id"),
+                on_error: None,
+            },
+        }
+    }
+
+    /// Set the status code of the error responses. Defaults to `500 Internal Server Error`.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.config.status = status;
+        self
+    }
+
+    /// Set the opaque message of the error responses. Defaults to `internal server error`.
+    pub fn with_message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.config.message = message.into();
+        self
+    }
+
+    /// Set whether to respond with a JSON rendering of the exception tree instead of the opaque
+    /// message. Defaults to `false`.
+    ///
+    /// The rendering includes messages, locations, attachments and suppressed exceptions, except
+    /// that the messages and attachments of [sensitive](Exn::mark_sensitive) frames are replaced
+    /// with `«redacted»`. Still, it reveals internals of the service; enable it for development
+    /// builds or trusted clients only.
+    pub fn with_json_tree(mut self, json_tree: bool) -> Self {
+        self.config.json_tree = json_tree;
+        self
+    }
+
+    /// Set the header whose value is attached to the exceptions as the request ID. Defaults to
+    /// `x-request-id`.
+    pub fn with_request_id_header(mut self, header: HeaderName) -> Self {
+        self.config.request_id_header = header;
+        self
+    }
+
+    /// Set a hook called with each exception, after the request metadata is attached, e.g., to
+    /// log it since the response hides it.
+    pub fn on_error<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Frame) + Send + Sync + 'static,
+    {
+        self.config.on_error = Some(Arc::new(f));
+        self
+    }
+}
+
+impl Default for ExnLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ExnLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExnLayer")
+            .field("status", &self.config.status)
+            .field("message", &self.config.message)
+            .field("json_tree", &self.config.json_tree)
+            .field("request_id_header", &self.config.request_id_header)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Layer<S> for ExnLayer {
+    type Service = ExnService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ExnService {
+            inner,
+            config: Arc::new(self.config.clone()),
+            not_ready: None,
+        }
+    }
+}
+
+/// A service that turns the exceptions of the inner service into HTTP responses.
+///
+/// This service is created by [`ExnLayer`].
+pub struct ExnService<S> {
+    inner: S,
+    config: Arc<Config>,
+    /// The exception of the inner service failing to get ready, an `Exn<E>`, turned into the
+    /// response of the next call.
+    not_ready: Option<Box<dyn Any + Send + Sync>>,
+}
+
+impl<S: Clone> Clone for ExnService<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            config: self.config.clone(),
+            // a clone must be driven to readiness on its own
+            not_ready: None,
+        }
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for ExnService<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExnService")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, E, ReqBody, ResBody> Service<Request<ReqBody>> for ExnService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>, Error = Exn<E>>,
+    S::Future: Send + 'static,
+    E: Error + Send + Sync + 'static,
+    ResBody: From<String>,
+{
+    type Response = Response<ResBody>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // the failure of the inner service to get ready is turned into the next response, since
+        // this service never fails
+        if self.not_ready.is_none() {
+            match self.inner.poll_ready(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(exn)) => self.not_ready = Some(Box::new(exn)),
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let context = RequestContext::new(&request, &self.config.request_id_header);
+        let config = self.config.clone();
+        let future = match self.not_ready.take() {
+            None => Ok(self.inner.call(request)),
+            Some(exn) => Err(*exn
+                .downcast::<Exn<E>>()
+                .expect("only exceptions of the inner service are stored")),
+        };
+        Box::pin(async move {
+            let result = match future {
+                Ok(future) => future.await,
+                Err(exn) => Err(exn),
+            };
+            let exn = match result {
+                Ok(response) => return Ok(response),
+                Err(exn) => exn.attach(context),
+            };
+            if let Some(on_error) = &config.on_error {
+                on_error(exn.frame());
+            }
+            Ok(config.respond(exn.frame()))
+        })
+    }
+}
+
+impl Config {
+    fn respond<B: From<String>>(&self, frame: &Frame) -> Response<B> {
+        let (content_type, body) = if self.json_tree {
+            ("application/json", render_json(frame).to_string())
+        } else {
+            ("text/plain; charset=utf-8", self.message.to_string())
+        };
+        let mut response = Response::new(B::from(body));
+        *response.status_mut() = self.status;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        response
+    }
+}
+
+/// The metadata of a request, attached to the exception of a failed request.
+struct RequestContext {
+    method: http::Method,
+    uri: http::Uri,
+    request_id: Option<HeaderValue>,
+}
+
+impl RequestContext {
+    fn new<B>(request: &Request<B>, request_id_header: &HeaderName) -> Self {
+        Self {
+            method: request.method().clone(),
+            uri: request.uri().clone(),
+            request_id: request.headers().get(request_id_header).cloned(),
+        }
+    }
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "while handling {} {}", self.method, self.uri)?;
+        if let Some(request_id) = self.request_id.as_ref().and_then(|v| v.to_str().ok()) {
+            write!(f, " (request id: {request_id})")?;
+        }
+        Ok(())
+    }
+}
+
+fn render_json(frame: &Frame) -> Value {
+    fn location(location: &'static std::panic::Location<'static>) -> String {
+        SourceLocation::from(location).to_string()
+    }
+
+    let message = frame.redacted_message().to_string();
+    let attachments = frame
+        .attachments()
+        .iter()
+        .zip(frame.redacted_attachments())
+        .map(|(attachment, redacted)| {
+            json!({
+                "message": redacted.to_string(),
+                "location": attachment.known_location().map(location),
+            })
+        })
+        .collect::<Vec<_>>();
+    let children = frame.children().iter().map(render_json).collect::<Vec<_>>();
+    let suppressed = frame
+        .suppressed()
+        .iter()
+        .map(render_json)
+        .collect::<Vec<_>>();
+    json!({
+        "message": message,
+        "location": frame.known_location().map(|location| location.to_string()),
+        "attachments": attachments,
+        "children": children,
+        "suppressed": suppressed,
+    })
+}
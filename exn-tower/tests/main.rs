@@ -0,0 +1,248 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::Infallible;
+use std::future::Ready;
+use std::future::ready;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+
+use exn::Exn;
+use exn_tower::ExnLayer;
+use http::Request;
+use http::Response;
+use http::StatusCode;
+use tower_layer::Layer;
+use tower_service::Service;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A service that fails for every path but `/ok`.
+#[derive(Clone)]
+struct Handler;
+
+impl Service<Request<String>> for Handler {
+    type Response = Response<String>;
+    type Error = Exn<Error>;
+    type Future = Ready<Result<Response<String>, Exn<Error>>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<String>) -> Self::Future {
+        if request.uri().path() == "/ok" {
+            return ready(Ok(Response::new("ok".to_string())));
+        }
+        let exn = Exn::new(Error("connection refused")).raise(Error("failed to load user"));
+        ready(Err(exn))
+    }
+}
+
+/// A service that always fails to get ready.
+#[derive(Clone)]
+struct Overloaded;
+
+impl Service<Request<String>> for Overloaded {
+    type Response = Response<String>;
+    type Error = Exn<Error>;
+    type Future = Ready<Result<Response<String>, Exn<Error>>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Err(Exn::new(Error("service overloaded"))))
+    }
+
+    fn call(&mut self, _: Request<String>) -> Self::Future {
+        unreachable!("service is never ready")
+    }
+}
+
+fn call<S>(service: &mut S, request: Request<String>) -> Response<String>
+where
+    S: Service<Request<String>, Response = Response<String>, Error = Infallible>,
+{
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        std::future::poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .unwrap();
+        service.call(request).await.unwrap()
+    })
+}
+
+#[test]
+fn opaque_response() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let layer = ExnLayer::new().on_error({
+        let reports = reports.clone();
+        move |frame| reports.lock().unwrap().push(format!("{frame:#}"))
+    });
+    let mut service = layer.layer(Handler);
+
+    let response = call(&mut service, Request::new(String::new()));
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.body(), "internal server error");
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/plain; charset=utf-8"
+    );
+
+    let request = Request::get("/ok").body(String::new()).unwrap();
+    let response = call(&mut service, request);
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.body(), "ok");
+
+    assert_eq!(
+        *reports.lock().unwrap(),
+        ["failed to load user: connection refused"]
+    );
+}
+
+#[test]
+fn json_tree_response() {
+    let layer = ExnLayer::new()
+        .with_status(StatusCode::SERVICE_UNAVAILABLE)
+        .with_json_tree(true);
+    let mut service = layer.layer(Handler);
+
+    let request = Request::post("/users/42")
+        .header("x-request-id", "abc")
+        .body(String::new())
+        .unwrap();
+    let response = call(&mut service, request);
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.headers()["content-type"], "application/json");
+
+    let tree: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+    assert_eq!(tree["message"], "failed to load user");
+    assert_eq!(
+        tree["attachments"][0]["message"],
+        "while handling POST /users/42 (request id: abc)"
+    );
+    assert_eq!(tree["children"][0]["message"], "connection refused");
+    assert_eq!(tree["children"][0]["children"], serde_json::json!([]));
+}
+
+#[test]
+fn not_ready_response() {
+    let mut service = ExnLayer::new()
+        .with_message("try again later")
+        .layer(Overloaded);
+
+    let response = call(&mut service, Request::new(String::new()));
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.body(), "try again later");
+}
+
+/// A service that gets ready on the second poll.
+#[derive(Clone, Default)]
+struct Busy {
+    polls: usize,
+}
+
+impl Service<Request<String>> for Busy {
+    type Response = Response<String>;
+    type Error = Exn<Error>;
+    type Future = Ready<Result<Response<String>, Exn<Error>>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.polls += 1;
+        if self.polls == 1 {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _: Request<String>) -> Self::Future {
+        assert!(self.polls > 1, "service is not ready");
+        ready(Ok(Response::new("ok".to_string())))
+    }
+}
+
+#[test]
+fn forward_readiness() {
+    let mut service = ExnLayer::new().layer(Busy::default());
+    let waker = std::task::Waker::noop();
+    let mut cx = Context::from_waker(waker);
+    assert!(service.poll_ready(&mut cx).is_pending());
+
+    let response = call(&mut service, Request::new(String::new()));
+    assert_eq!(response.body(), "ok");
+}
+
+#[test]
+fn configure_cloned_layer() {
+    let layer = ExnLayer::new();
+    let _service = layer.clone().layer(Handler);
+    let mut service = layer
+        .with_message("try again later")
+        .with_status(StatusCode::SERVICE_UNAVAILABLE)
+        .layer(Handler);
+
+    let response = call(&mut service, Request::new(String::new()));
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.body(), "try again later");
+}
+
+#[test]
+fn json_tree_redaction() {
+    /// A service failing with a sensitive exception and a suppressed one.
+    #[derive(Clone)]
+    struct Sensitive;
+
+    impl Service<Request<String>> for Sensitive {
+        type Response = Response<String>;
+        type Error = Exn<Error>;
+        type Future = Ready<Result<Response<String>, Exn<Error>>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: Request<String>) -> Self::Future {
+            let mut exn = Exn::new(Error("token abc is invalid"))
+                .attach("token: abc")
+                .mark_sensitive()
+                .raise(Error("failed to authenticate"));
+            exn.suppress(Exn::new(Error("failed to close session")));
+            ready(Err(exn))
+        }
+    }
+
+    let mut service = ExnLayer::new().with_json_tree(true).layer(Sensitive);
+    let response = call(&mut service, Request::new(String::new()));
+    assert!(!response.body().contains("abc"), "{}", response.body());
+
+    let tree: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+    assert_eq!(tree["children"][0]["message"], "«redacted»");
+    assert_eq!(
+        tree["children"][0]["attachments"][0]["message"],
+        "«redacted»"
+    );
+    assert_eq!(tree["suppressed"][0]["message"], "failed to close session");
+}
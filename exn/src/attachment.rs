@@ -0,0 +1,192 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use core::any::Any;
+use core::error::Error;
+use core::fmt;
+use core::panic::Location;
+
+use crate::Exn;
+use crate::Frame;
+use crate::help::Hint;
+use crate::location::StoredLocation;
+
+/// A message attached to a frame as lightweight context, without raising a new error.
+///
+/// Attachments are created with [`Exn::attach`] and rendered as annotations under their frame.
+/// Their message keeps its type, so that structured context such as a request ID can be found
+/// again with [`downcast_ref`](Attachment::downcast_ref) or [`Exn::contexts`].
+pub struct Attachment {
+    message: Box<dyn Message>,
+    location: StoredLocation,
+}
+
+/// The message of an attachment.
+trait Message: fmt::Display + Send + Sync + 'static {
+    fn as_display(&self) -> &(dyn fmt::Display + Send + Sync + 'static);
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<M: fmt::Display + Send + Sync + 'static> Message for M {
+    fn as_display(&self) -> &(dyn fmt::Display + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl Attachment {
+    /// Create an attachment with the given message, located at `location`.
+    pub(crate) fn new_at<M>(message: M, location: &'static Location<'static>) -> Self
+    where
+        M: fmt::Display + Send + Sync + 'static,
+    {
+        Self {
+            message: Box::new(message),
+            location: StoredLocation::new(location),
+        }
+    }
+
+    /// Return the message of this attachment.
+    pub fn message(&self) -> &(dyn fmt::Display + Send + Sync + 'static) {
+        (*self.message).as_display()
+    }
+
+    /// Return `true` if the message of this attachment is of type `T`.
+    pub fn is<T: 'static>(&self) -> bool {
+        (*self.message).as_any().is::<T>()
+    }
+
+    /// Return `true` if this attachment is a hint attached with [`Exn::with_help`] or
+    /// [`Exn::with_url`], which reports write apart from the other attachments.
+    pub fn is_hint(&self) -> bool {
+        self.is::<Hint>()
+    }
+
+    /// Return the message of this attachment if it is of type `T`.
+    ///
+    /// Note that attachments of [cloned](crate::Exn::try_clone) exceptions are degenerated to
+    /// their string representation.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        (*self.message).as_any().downcast_ref()
+    }
+
+    /// Return the source code location where this attachment was added.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location.get()
+    }
+
+    /// Return the source code location where this attachment was added, or `None` if locations
+    /// are compiled out with `--cfg exn_no_location`.
+    pub fn known_location(&self) -> Option<&'static Location<'static>> {
+        self.location.known()
+    }
+
+    /// Clone this attachment, degenerating its message to its string representation.
+    pub(crate) fn to_owned(&self) -> Self {
+        Self::new_at(self.message.to_string(), self.location.get())
+    }
+}
+
+impl fmt::Debug for Attachment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Attachment")
+            .field("message", &self.message.to_string())
+            .field("location", &self.location)
+            .finish()
+    }
+}
+
+impl fmt::Display for Attachment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.message, f)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Attach a message to the topmost frame, e.g., "while processing row 42".
+    ///
+    /// Unlike [`Exn::raise`], this adds no new error layer, so there is no need to define an error
+    /// type for each piece of context. The attachment records its own location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// let io = std::io::Error::other("disk full");
+    /// let exn = Exn::new(io).attach("while writing chunk 3");
+    ///
+    /// let attachment = &exn.frame().attachments()[0];
+    /// assert_eq!(attachment.to_string(), "while writing chunk 3");
+    /// ```
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn attach<M>(self, message: M) -> Self
+    where
+        M: fmt::Display + Send + Sync + 'static,
+    {
+        self.attach_at(message, crate::location::caller())
+    }
+
+    /// Return an iterator over the attachments of type `T` along the primary chain.
+    ///
+    /// See [`Frame::contexts`] for more information.
+    pub fn contexts<T: 'static>(&self) -> impl Iterator<Item = &T> {
+        self.frame().contexts()
+    }
+}
+
+impl Frame {
+    /// Return an iterator over the attachments of type `T` along the
+    /// [primary chain](Frame::primary_chain), from this frame to the root cause.
+    ///
+    /// This collects context attached at different layers, e.g., every request ID attached by
+    /// nested middleware.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// struct RequestId(u64);
+    ///
+    /// impl core::fmt::Display for RequestId {
+    ///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    ///         write!(f, "request id: {}", self.0)
+    ///     }
+    /// }
+    ///
+    /// let exn = Exn::new(std::fmt::Error)
+    ///     .attach(RequestId(2))
+    ///     .attach("while rendering")
+    ///     .raise(std::io::Error::other("failed to respond"))
+    ///     .attach(RequestId(1));
+    ///
+    /// let ids = exn
+    ///     .contexts::<RequestId>()
+    ///     .map(|id| id.0)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(ids, [1, 2]);
+    /// ```
+    pub fn contexts<T: 'static>(&self) -> impl Iterator<Item = &T> {
+        self.primary_chain()
+            .flat_map(|frame| frame.attachments())
+            .filter_map(|attachment| attachment.downcast_ref())
+    }
+}
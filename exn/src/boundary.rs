@@ -0,0 +1,104 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+use crate::Frame;
+
+type Rule<E> = Box<dyn Fn(&Frame) -> Option<E> + Send + Sync>;
+
+/// A table of rules mapping the errors of exception trees into the error type `E` of a library
+/// boundary.
+///
+/// Each rule maps errors of one type `T` into an `E`. [`apply`](BoundaryMap::apply) tries the
+/// rules in the order they were added; each rule searches the whole tree for an error of its type,
+/// visiting frames depth-first. The first match is raised as the new top-level error, keeping the
+/// original exception as its child. If no rule matches, the fallback error is raised instead.
+///
+/// # Examples
+///
+/// ```
+/// use exn::BoundaryMap;
+/// use exn::Exn;
+/// use exn::Message;
+///
+/// let map = BoundaryMap::new(|| Message::Static("internal error"))
+///     .rule(|err: &std::num::ParseIntError| Message::from(format!("invalid number: {err}")))
+///     .rule(|err: &std::io::Error| Message::from(format!("I/O error: {}", err.kind())));
+///
+/// let exn = Exn::new(std::io::Error::other("disk full")).raise(std::fmt::Error);
+/// assert_eq!(map.apply(exn).as_str(), "I/O error: other error");
+///
+/// let exn = Exn::new(std::fmt::Error);
+/// assert_eq!(map.apply(exn).as_str(), "internal error");
+/// ```
+pub struct BoundaryMap<E: Error + Send + Sync + 'static> {
+    rules: Vec<Rule<E>>,
+    fallback: Box<dyn Fn() -> E + Send + Sync>,
+}
+
+impl<E: Error + Send + Sync + 'static> BoundaryMap<E> {
+    /// Create a map without rules, raising the error returned by `fallback` when no rule matches.
+    pub fn new<F>(fallback: F) -> Self
+    where
+        F: Fn() -> E + Send + Sync + 'static,
+    {
+        Self {
+            rules: Vec::new(),
+            fallback: Box::new(fallback),
+        }
+    }
+
+    /// Add a rule mapping errors of type `T` into `E` with `map`.
+    pub fn rule<T, F>(mut self, map: F) -> Self
+    where
+        T: Error + 'static,
+        F: Fn(&T) -> E + Send + Sync + 'static,
+    {
+        self.rules.push(Box::new(move |frame| {
+            frame
+                .frames()
+                .find_map(|frame| frame.error().downcast_ref::<T>())
+                .map(&map)
+        }));
+        self
+    }
+
+    /// Return the error that `exn` maps into.
+    pub fn map<T: Error + Send + Sync + 'static>(&self, exn: &Exn<T>) -> E {
+        self.rules
+            .iter()
+            .find_map(|rule| rule(exn.frame()))
+            .unwrap_or_else(|| (self.fallback)())
+    }
+
+    /// Raise the error that `exn` maps into, keeping `exn` as its child.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn apply<T: Error + Send + Sync + 'static>(&self, exn: Exn<T>) -> Exn<E> {
+        let error = self.map(&exn);
+        exn.raise_at(error, crate::location::caller())
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> fmt::Debug for BoundaryMap<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundaryMap")
+            .field("rules", &self.rules.len())
+            .finish_non_exhaustive()
+    }
+}
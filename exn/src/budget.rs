@@ -0,0 +1,98 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+/// A limit on the work done when formatting an exception tree.
+///
+/// Once the budget is exhausted, the output is cut short and ends with a truncation notice. This
+/// bounds the cost of formatting pathological trees, e.g., in a hot logging path.
+///
+/// The `Debug` output of [`Exn`](crate::Exn) and [`Frame`](crate::Frame) honors the budget set
+/// with [`set_debug_budget`], which is unlimited by default.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Budget;
+///
+/// exn::set_debug_budget(
+///     Budget::unlimited()
+///         .with_max_frames(64)
+///         .with_max_bytes(16 * 1024),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Budget {
+    max_frames: usize,
+    max_bytes: usize,
+}
+
+impl Budget {
+    /// Create a budget without any limit.
+    pub const fn unlimited() -> Self {
+        Self {
+            max_frames: usize::MAX,
+            max_bytes: usize::MAX,
+        }
+    }
+
+    /// Limit the number of frames written.
+    pub const fn with_max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = max_frames;
+        self
+    }
+
+    /// Limit the number of bytes written, excluding the truncation notice.
+    pub const fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Return the maximum number of frames written.
+    pub const fn max_frames(&self) -> usize {
+        self.max_frames
+    }
+
+    /// Return the maximum number of bytes written, excluding the truncation notice.
+    pub const fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+static DEBUG_MAX_FRAMES: AtomicUsize = AtomicUsize::new(usize::MAX);
+static DEBUG_MAX_BYTES: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Set the budget honored by the `Debug` output of [`Exn`](crate::Exn) and
+/// [`Frame`](crate::Frame).
+pub fn set_debug_budget(budget: Budget) {
+    DEBUG_MAX_FRAMES.store(budget.max_frames, Ordering::Relaxed);
+    DEBUG_MAX_BYTES.store(budget.max_bytes, Ordering::Relaxed);
+}
+
+/// Return the budget honored by the `Debug` output of [`Exn`](crate::Exn) and
+/// [`Frame`](crate::Frame).
+pub fn debug_budget() -> Budget {
+    Budget {
+        max_frames: DEBUG_MAX_FRAMES.load(Ordering::Relaxed),
+        max_bytes: DEBUG_MAX_BYTES.load(Ordering::Relaxed),
+    }
+}
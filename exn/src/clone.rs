@@ -0,0 +1,104 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use core::any::TypeId;
+use core::error::Error;
+
+use crate::Exn;
+use crate::Frame;
+use crate::registry::Registry;
+
+/// An error that can be cloned into a new trait object.
+///
+/// This trait is implemented for every error type implementing [`Clone`]. Error types must be
+/// [registered](crate#registering-error-types) with [`register_cloneable_error`] before the
+/// exception trees containing them can be cloned with [`Exn::try_clone`] or [`Frame::try_clone`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+///
+/// #[derive(Debug, Clone)]
+/// struct Timeout;
+///
+/// impl core::fmt::Display for Timeout {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "operation timed out")
+///     }
+/// }
+///
+/// impl core::error::Error for Timeout {}
+///
+/// let exn = Exn::new(Timeout);
+/// assert!(exn.try_clone().is_none());
+///
+/// exn::register_cloneable_error::<Timeout>();
+/// let last_error = exn.try_clone().unwrap();
+/// assert_eq!(last_error.to_string(), "operation timed out");
+/// ```
+pub trait CloneableError: Error + Send + Sync + 'static {
+    /// Clone this error into a new trait object.
+    fn clone_error(&self) -> Box<dyn Error + Send + Sync + 'static>;
+}
+
+impl<T: Error + Clone + Send + Sync + 'static> CloneableError for T {
+    fn clone_error(&self) -> Box<dyn Error + Send + Sync + 'static> {
+        Box::new(self.clone())
+    }
+}
+
+/// Register the error type `T` so that frames holding it can be cloned by [`Exn::try_clone`] and
+/// [`Frame::try_clone`].
+///
+/// Registering the same type more than once has no further effect.
+pub fn register_cloneable_error<T: CloneableError>() {
+    fn clone_of<T: CloneableError>(
+        error: &(dyn Error + 'static),
+    ) -> Option<Box<dyn Error + Send + Sync + 'static>> {
+        error.downcast_ref::<T>().map(T::clone_error)
+    }
+
+    REGISTRY.register(TypeId::of::<T>(), clone_of::<T>);
+}
+
+type CloneFn = fn(&(dyn Error + 'static)) -> Option<Box<dyn Error + Send + Sync + 'static>>;
+
+static REGISTRY: Registry<CloneFn> = Registry::new();
+
+fn clone_error(error: &(dyn Error + 'static)) -> Option<Box<dyn Error + Send + Sync + 'static>> {
+    REGISTRY.entries().find_map(|entry| (entry.value)(error))
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Deep-clone the exception tree, if the error of every frame can be cloned.
+    ///
+    /// See [`Frame::try_clone`] for more information.
+    pub fn try_clone(&self) -> Option<Self> {
+        let frame = self.frame().try_clone()?;
+        Some(Exn::from_boxed_frame(Box::new(frame)))
+    }
+}
+
+impl Frame {
+    /// Deep-clone this frame and all its descendants, if the error of every frame can be cloned.
+    ///
+    /// Returns `None` if any error type does not implement [`CloneableError`] or has not been
+    /// registered with [`register_cloneable_error`]. Frames walked from the source chain of an
+    /// error are always cloned, since they only hold a string.
+    pub fn try_clone(&self) -> Option<Frame> {
+        self.try_clone_with(&clone_error)
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::any::TypeId;
+use core::error::Error;
+
+use crate::Exn;
+use crate::Frame;
+use crate::registry::Registry;
+
+/// A machine-readable code identifying an error, such as `"E1234"`.
+///
+/// Error types implementing this trait must be [registered](crate#registering-error-types) with
+/// [`register_error_code`] before their codes can be found in an exception tree.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ErrorCode;
+///
+/// #[derive(Debug)]
+/// struct NotFound;
+///
+/// impl core::fmt::Display for NotFound {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "not found")
+///     }
+/// }
+///
+/// impl core::error::Error for NotFound {}
+///
+/// impl ErrorCode for NotFound {
+///     fn code(&self) -> &'static str {
+///         "E0404"
+///     }
+/// }
+///
+/// exn::register_error_code::<NotFound>();
+///
+/// let exn = exn::Exn::new(NotFound);
+/// assert_eq!(exn.code(), Some("E0404"));
+/// ```
+pub trait ErrorCode: Error + Send + Sync + 'static {
+    /// Return the code of this error.
+    fn code(&self) -> &'static str;
+}
+
+/// Register the error type `T` so that its codes are reported by [`Exn::code`] and [`Frame::code`].
+///
+/// Registering the same type more than once has no further effect.
+pub fn register_error_code<T: ErrorCode>() {
+    fn code_of<T: ErrorCode>(error: &(dyn Error + 'static)) -> Option<&'static str> {
+        error.downcast_ref::<T>().map(T::code)
+    }
+
+    REGISTRY.register(TypeId::of::<T>(), code_of::<T>);
+}
+
+static REGISTRY: Registry<fn(&(dyn Error + 'static)) -> Option<&'static str>> = Registry::new();
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return the code of the first frame in the exception tree that has one.
+    ///
+    /// Frames are visited depth-first, starting with the topmost frame. See [`Frame::code`] for
+    /// more information.
+    pub fn code(&self) -> Option<&'static str> {
+        fn walk(frame: &Frame) -> Option<&'static str> {
+            frame
+                .code()
+                .or_else(|| frame.children().iter().find_map(walk))
+        }
+        walk(self.frame())
+    }
+}
+
+impl Frame {
+    /// Return the code of the error that occurred at this frame.
+    ///
+    /// Returns `None` if the error type does not implement [`ErrorCode`] or has not been
+    /// registered with [`register_error_code`].
+    pub fn code(&self) -> Option<&'static str> {
+        let error = self.error() as &(dyn Error + 'static);
+        REGISTRY.entries().find_map(|entry| (entry.value)(error))
+    }
+}
@@ -12,53 +12,515 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use alloc::boxed::Box;
 use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::error::Error;
 use core::fmt;
+use core::fmt::Write;
+use core::panic::Location;
 
+use crate::Budget;
 use crate::Exn;
 use crate::Frame;
+use crate::FrameLocation;
+use crate::FrameOrigin;
+use crate::ReportFormat;
+use crate::Severity;
+use crate::SourceLocation;
+use crate::debug_budget;
+use crate::debug_format;
+use crate::help::Hint;
+use crate::report::write_json_frame;
+use crate::testing::pinned_file;
 
+/// The output follows the [debug format](crate::debug_format), the whole exception tree by
+/// default.
 impl<E: Error + Send + Sync + 'static> fmt::Debug for Exn<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_exn(f, self.frame(), 0, "")
+        write_debug(f, self.frame())
     }
 }
 
+/// The output follows the [debug format](crate::debug_format), the whole exception tree by
+/// default.
 impl fmt::Debug for Frame {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_exn(f, self, 0, "")
+        write_debug(f, self)
     }
 }
 
-fn write_exn(f: &mut fmt::Formatter<'_>, frame: &Frame, level: usize, prefix: &str) -> fmt::Result {
-    write!(f, "{}", frame.error())?;
+fn write_debug(f: &mut fmt::Formatter<'_>, frame: &Frame) -> fmt::Result {
+    match debug_format() {
+        ReportFormat::Full => write_tree(f, frame, &Options::default()),
+        ReportFormat::Compact => write!(f, "{frame:#}"),
+        ReportFormat::Json => write_json_frame(f, frame, false),
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Render the exception tree as a string, the same as the `Debug` output.
+    ///
+    /// See [`Frame::render_tree`] for more information.
+    pub fn render_tree(&self) -> String {
+        self.frame().render_tree()
+    }
+
+    /// Render the exception tree as a string, without locations.
+    ///
+    /// See [`Frame::render_tree_without_locations`] for more information.
+    pub fn render_tree_without_locations(&self) -> String {
+        self.frame().render_tree_without_locations()
+    }
+}
+
+impl Frame {
+    /// Render the exception tree rooted at this frame as a string, the same as the `Debug`
+    /// output, e.g., to embed it in API responses or log fields.
+    ///
+    /// Use [`report::Compact`](crate::report::Compact) for more rendering options, and
+    /// [`Frame::display_tree`] to stream the rendering without building a string.
+    pub fn render_tree(&self) -> String {
+        format!("{self:?}")
+    }
+
+    /// Render the exception tree rooted at this frame as a string, without locations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::ErrorExt;
+    ///
+    /// let exn = std::fmt::Error
+    ///     .raise()
+    ///     .raise(std::io::Error::other("failed to render"));
+    /// assert_eq!(
+    ///     exn.render_tree_without_locations(),
+    ///     "failed to render\n|\n|-> an error occurred when formatting an argument"
+    /// );
+    /// ```
+    pub fn render_tree_without_locations(&self) -> String {
+        let options = Options {
+            locations: false,
+            ..Options::default()
+        };
+        format!("{:?}", Render(self, &options))
+    }
+}
+
+struct Render<'a>(&'a Frame, &'a Options);
+
+impl fmt::Debug for Render<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.0, self.1)
+    }
+}
+
+/// Options of the tree layout written by [`write_tree`].
+#[derive(Debug, Clone)]
+pub(crate) struct Options {
+    /// Whether to write the location of each frame.
+    pub(crate) locations: bool,
+    /// Whether to replace the line and column of locations with placeholders.
+    pub(crate) placeholders: bool,
+    /// Whether to keep the file of locations written with placeholders.
+    pub(crate) placeholder_files: bool,
+    /// Whether to write the function where each frame was created.
+    pub(crate) functions: bool,
+    /// Whether to write the type name of the error of each frame.
+    pub(crate) type_names: bool,
+    /// Whether to write transparent frames.
+    pub(crate) transparent_frames: bool,
+    /// The filter of frames by their tags, if any; boxed to keep reports small.
+    pub(crate) tags: Option<Box<TagFilter>>,
+    /// Whether to replace the error message of sensitive frames.
+    pub(crate) redact: bool,
+    /// The number of lines of context of source snippets, if any are written.
+    #[cfg(feature = "diagnostics")]
+    pub(crate) snippets: Option<usize>,
+    /// The width to wrap messages at, if any.
+    pub(crate) width: Option<usize>,
+    /// The depth below which frames are elided.
+    pub(crate) max_depth: usize,
+    /// The number of children of each frame after which the rest are elided.
+    pub(crate) max_children: usize,
+    /// The limit on the frames and bytes written.
+    pub(crate) budget: Budget,
+}
+
+/// The filter of frames by their tags.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TagFilter {
+    /// The tags of the frames to write, if not empty; frames without any of them are hidden.
+    pub(crate) included: Vec<&'static str>,
+    /// The tags of the frames to hide.
+    pub(crate) excluded: Vec<&'static str>,
+}
 
-    let location = frame.location();
-    write!(
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            locations: true,
+            placeholders: false,
+            placeholder_files: false,
+            functions: false,
+            type_names: false,
+            transparent_frames: true,
+            tags: None,
+            redact: false,
+            #[cfg(feature = "diagnostics")]
+            snippets: None,
+            width: None,
+            max_depth: usize::MAX,
+            max_children: usize::MAX,
+            budget: debug_budget(),
+        }
+    }
+}
+
+/// Write the exception tree rooted at `frame`, ending with a truncation notice if the budget of
+/// `options` is exhausted.
+pub(crate) fn write_tree(
+    f: &mut fmt::Formatter<'_>,
+    frame: &Frame,
+    options: &Options,
+) -> fmt::Result {
+    let mut w = Writer {
         f,
-        ", at {}:{}:{}",
-        location.file(),
-        location.line(),
-        location.column()
-    )?;
+        options,
+        frames: 0,
+        bytes: 0,
+        column: 0,
+        depth: 0,
+        exhausted: false,
+    };
+    let result = w
+        .start_frame()
+        .and_then(|_| write_exn(&mut w, frame, 0, Prefix::ROOT, Prefix::ROOT))
+        .and_then(|_| write_hints(&mut w, frame));
+    match result {
+        Err(_) if w.exhausted => write!(w.f, "\n… output truncated: formatting budget exhausted"),
+        result => result,
+    }
+}
 
-    let children = frame.children();
+/// A writer that keeps track of the budget, failing once it is exhausted.
+struct Writer<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    options: &'a Options,
+    frames: usize,
+    bytes: usize,
+    /// The number of characters written since the last line break.
+    column: usize,
+    /// The depth of the frame being written, the topmost frame having depth 0.
+    depth: usize,
+    exhausted: bool,
+}
+
+impl Writer<'_, '_> {
+    fn start_frame(&mut self) -> fmt::Result {
+        if self.frames >= self.options.budget.max_frames() {
+            self.exhausted = true;
+            return Err(fmt::Error);
+        }
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+impl Write for Writer<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let remaining = self.options.budget.max_bytes() - self.bytes;
+        if s.len() <= remaining {
+            self.bytes += s.len();
+            self.column = match s.rfind('\n') {
+                Some(i) => s[i + 1..].chars().count(),
+                None => self.column + s.chars().count(),
+            };
+            return self.f.write_str(s);
+        }
+
+        let mut end = remaining;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.bytes += end;
+        self.f.write_str(&s[..end])?;
+        self.exhausted = true;
+        Err(fmt::Error)
+    }
+}
+
+/// The prefix of the lines of a frame, built from the segments of its ancestors on the stack
+/// rather than in an allocated string, so that deep trees are streamed without allocations.
+struct Prefix<'a> {
+    parent: Option<&'a Prefix<'a>>,
+    segment: &'static str,
+}
+
+impl<'a> Prefix<'a> {
+    const ROOT: &'static Prefix<'static> = &Prefix {
+        parent: None,
+        segment: "",
+    };
+
+    fn push(&'a self, segment: &'static str) -> Prefix<'a> {
+        Prefix {
+            parent: Some(self),
+            segment,
+        }
+    }
+
+    /// Return the number of characters of the prefix.
+    fn width(&self) -> usize {
+        self.parent.map_or(0, Prefix::width) + self.segment.chars().count()
+    }
+}
+
+impl fmt::Display for Prefix<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{parent}")?;
+        }
+        f.write_str(self.segment)
+    }
+}
+
+/// Write `frame` and its descendants.
+///
+/// Children are written under `prefix`, while continuation lines of wrapped messages are
+/// written under `indent`, aligned with the start of the frame's own line.
+fn write_exn(
+    w: &mut Writer<'_, '_>,
+    frame: &Frame,
+    level: usize,
+    prefix: &Prefix<'_>,
+    indent: &Prefix<'_>,
+) -> fmt::Result {
+    match frame.severity() {
+        Severity::Error => {}
+        severity => write!(w, "{severity}: ")?,
+    }
+    // frames walked from the source chain share the location of their parent
+    let is_source = frame.origin() == FrameOrigin::SourceChain;
+    if is_source {
+        write!(w, "source: ")?;
+    }
+    if let Some(code) = frame.code() {
+        write!(w, "[{code}] ")?;
+    }
+    if w.options.redact {
+        write_message(w, &frame.redacted_message(), indent)?;
+    } else {
+        write_message(w, frame.error(), indent)?;
+    }
+    if let Some(type_name) = frame.type_name().filter(|_| w.options.type_names) {
+        write!(w, " ({type_name})")?;
+    }
+
+    if let Some(location) = frame
+        .known_location()
+        .filter(|_| w.options.locations && !is_source)
+    {
+        write_frame_location(w, &location)?;
+    }
+    if let Some(function) = frame.function().filter(|_| w.options.functions) {
+        write!(w, ", in {function}")?;
+    }
+
+    #[cfg(feature = "diagnostics")]
+    {
+        // snippets are only available for locations in Rust source code
+        let location = match frame.known_location().as_deref() {
+            Some(FrameLocation::Rust(location)) => Some(*location),
+            _ => None,
+        };
+        let snippet = (w.options.snippets)
+            .filter(|_| !is_source)
+            .zip(location)
+            .and_then(|(context, location)| crate::snippet::snippet(location, context));
+        for line in snippet.iter().flat_map(|snippet| snippet.lines()) {
+            write!(w, "\n{}  {}", prefix, line)?;
+        }
+    }
+
+    // hints are written in the trailer of the tree
+    let attachments = frame.attachments().iter().zip(frame.redacted_attachments());
+    for (attachment, redacted) in attachments.filter(|(attachment, _)| !attachment.is::<Hint>()) {
+        write!(w, "\n{}= ", prefix)?;
+        if w.options.redact {
+            write_message(w, &redacted, &prefix.push("  "))?;
+        } else {
+            write_message(w, attachment, &prefix.push("  "))?;
+        }
+        if let Some(location) = attachment.known_location().filter(|_| w.options.locations) {
+            write_location(w, location)?;
+        }
+    }
+
+    let children = visible_children(frame, w.options);
     let children_len = children.len();
 
-    for (i, child) in children.iter().enumerate() {
-        write!(f, "\n{}|", prefix)?;
-        write!(f, "\n{}|-> ", prefix)?;
+    let suppressed = frame.suppressed();
+
+    if (children_len > 0 || !suppressed.is_empty()) && w.depth >= w.options.max_depth {
+        let elided = frame.frames().count() - 1
+            + suppressed.iter().map(|s| s.frames().count()).sum::<usize>();
+        return write!(w, "\n{}|\n{}|-> … and {} more", prefix, prefix, elided);
+    }
+
+    let shown = children_len.min(w.options.max_children);
+    for (i, child) in children[..shown].iter().enumerate() {
+        w.start_frame()?;
+        write!(w, "\n{}|", prefix)?;
+        write!(w, "\n{}|-> ", prefix)?;
 
-        let child_child_len = child.children().len();
-        if level == 0 && children_len == 1 && child_child_len == 1 {
-            write_exn(f, child, 0, prefix)?;
-        } else if i < children_len - 1 {
-            write_exn(f, child, level + 1, &format!("{}|   ", prefix))?;
+        w.depth += 1;
+        let is_linear = level == 0
+            && children_len == 1
+            && visible_children(child, w.options).len() == 1
+            && suppressed.is_empty()
+            && child.suppressed().is_empty();
+        if is_linear {
+            write_exn(w, child, 0, prefix, &prefix.push("|   "))?;
+        } else if i < children_len - 1 || !suppressed.is_empty() {
+            let prefix = prefix.push("|   ");
+            write_exn(w, child, level + 1, &prefix, &prefix)?;
         } else {
-            write_exn(f, child, level + 1, &format!("{}    ", prefix))?;
+            let prefix = prefix.push("    ");
+            write_exn(w, child, level + 1, &prefix, &prefix)?;
+        }
+        w.depth -= 1;
+    }
+    if shown < children_len {
+        let elided = children_len - shown;
+        write!(w, "\n{}|\n{}|-> … and {} more", prefix, prefix, elided)?;
+    }
+
+    for (i, frame) in suppressed.iter().enumerate() {
+        w.start_frame()?;
+        write!(w, "\n{}|", prefix)?;
+        write!(w, "\n{}|-> suppressed: ", prefix)?;
+
+        w.depth += 1;
+        if i < suppressed.len() - 1 {
+            let prefix = prefix.push("|   ");
+            write_exn(w, frame, level + 1, &prefix, &prefix)?;
+        } else {
+            let prefix = prefix.push("    ");
+            write_exn(w, frame, level + 1, &prefix, &prefix)?;
+        }
+        w.depth -= 1;
+    }
+
+    Ok(())
+}
+
+/// Return the children of `frame` to write, replacing frames hidden as transparent or by their
+/// tags with their own visible children.
+fn visible_children<'a>(frame: &'a Frame, options: &Options) -> Vec<&'a Frame> {
+    fn is_hidden(frame: &Frame, options: &Options) -> bool {
+        let hidden_by_tags = options.tags.as_ref().is_some_and(|filter| {
+            let tags = frame.tags();
+            (!filter.included.is_empty() && !tags.iter().any(|tag| filter.included.contains(tag)))
+                || tags.iter().any(|tag| filter.excluded.contains(tag))
+        });
+        (frame.is_transparent() && !options.transparent_frames) || hidden_by_tags
+    }
+
+    fn walk<'a>(frame: &'a Frame, options: &Options, children: &mut Vec<&'a Frame>) {
+        for child in frame.children() {
+            if is_hidden(child, options) {
+                walk(child, options, children);
+            } else {
+                children.push(child);
+            }
         }
     }
 
+    let mut children = Vec::new();
+    walk(frame, options, &mut children);
+    children
+}
+
+/// Write the [help](Frame::help) and [URL](Frame::url) of each frame after the tree.
+fn write_hints(w: &mut Writer<'_, '_>, frame: &Frame) -> fmt::Result {
+    let mut first = true;
+    for frame in frame.frames() {
+        let (help, url) = (frame.help(), frame.url());
+        if first && (help.is_some() || url.is_some()) {
+            w.write_str("\n")?;
+            first = false;
+        }
+        if let Some(help) = help {
+            write!(w, "\nhelp: {help}")?;
+        }
+        if let Some(url) = url {
+            write!(w, "\nsee: {url}")?;
+        }
+    }
     Ok(())
 }
+
+/// Write `message`, wrapping it at word boundaries if a width is set, with continuation lines
+/// written under `indent`.
+fn write_message(
+    w: &mut Writer<'_, '_>,
+    message: &dyn fmt::Display,
+    indent: &Prefix<'_>,
+) -> fmt::Result {
+    let Some(width) = w.options.width else {
+        return write!(w, "{message}");
+    };
+
+    let message = format!("{message}");
+    let indent_width = indent.width();
+    for (i, line) in message.split('\n').enumerate() {
+        if i > 0 {
+            write!(w, "\n{indent}")?;
+        }
+        for (j, word) in line.split(' ').enumerate() {
+            if j > 0 {
+                let overflows = w.column + 1 + word.chars().count() > width;
+                if overflows && w.column > indent_width {
+                    write!(w, "\n{indent}")?;
+                } else {
+                    w.write_str(" ")?;
+                }
+            }
+            w.write_str(word)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_frame_location(w: &mut Writer<'_, '_>, location: &FrameLocation) -> fmt::Result {
+    match location {
+        FrameLocation::Rust(location) => write_location(w, location),
+        FrameLocation::File { path, .. } if w.options.placeholders => {
+            if w.options.placeholder_files {
+                write!(w, ", at {}:<L>:<C>", path.replace('\\', "/"))
+            } else {
+                write!(w, ", at <file>:<L>:<C>")
+            }
+        }
+        location => write!(w, ", at {location}"),
+    }
+}
+
+fn write_location(w: &mut Writer<'_, '_>, location: &'static Location<'static>) -> fmt::Result {
+    let mut location = SourceLocation::from(location);
+    if let Some(file) = pinned_file() {
+        location = SourceLocation::new(file, location.line(), location.column());
+    }
+    if !w.options.placeholders {
+        return write!(w, ", at {location}");
+    }
+    if w.options.placeholder_files {
+        let file = location.strip_workspace_prefix().file();
+        write!(w, ", at {file}:<L>:<C>")
+    } else {
+        write!(w, ", at <file>:<L>:<C>")
+    }
+}
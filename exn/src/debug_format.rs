@@ -0,0 +1,140 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::str::FromStr;
+use core::sync::atomic::AtomicU8;
+use core::sync::atomic::Ordering;
+
+/// The format of the `Debug` output of [`Exn`](crate::Exn) and [`Frame`](crate::Frame).
+///
+/// With the `std` feature, the format is read from the `EXN_REPORT` environment variable the
+/// first time it is needed, so that operators can switch the verbosity of a deployed binary
+/// without code changes. The variable holds `full`, `compact` or `json`; a missing or invalid
+/// value selects [`Full`](ReportFormat::Full). [`set_debug_format`] overrides the variable.
+///
+/// Explicit reports, like [`Compact`](crate::report::Compact), are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ReportFormat;
+///
+/// exn::set_debug_format(ReportFormat::Compact);
+///
+/// let exn = exn::Exn::new(std::io::Error::other("connection reset"));
+/// let exn = exn.raise(std::io::Error::other("failed to fetch"));
+/// assert_eq!(format!("{exn:?}"), "failed to fetch: connection reset");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportFormat {
+    /// The whole exception tree, with locations and attachments. This is the default format.
+    #[default]
+    Full,
+    /// The errors of the [primary chain](crate::Frame::primary_chain) on a single line, as with
+    /// the alternate `Display` format (`{:#}`).
+    Compact,
+    /// The exception tree as a JSON object, as in the `exception` member of
+    /// [`ProblemDetails`](crate::report::ProblemDetails), without redaction. The
+    /// [debug budget](crate::debug_budget) is not honored, so that the output stays valid JSON.
+    Json,
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportFormat::Full => write!(f, "full"),
+            ReportFormat::Compact => write!(f, "compact"),
+            ReportFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = ParseReportFormatError;
+
+    /// Parse `full`, `compact` or `json`, ignoring ASCII case and surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("full") {
+            Ok(ReportFormat::Full)
+        } else if s.eq_ignore_ascii_case("compact") {
+            Ok(ReportFormat::Compact)
+        } else if s.eq_ignore_ascii_case("json") {
+            Ok(ReportFormat::Json)
+        } else {
+            Err(ParseReportFormatError(()))
+        }
+    }
+}
+
+/// The error returned when parsing a [`ReportFormat`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseReportFormatError(());
+
+impl fmt::Display for ParseReportFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected one of `full`, `compact` or `json`")
+    }
+}
+
+impl core::error::Error for ParseReportFormatError {}
+
+const UNSET: u8 = u8::MAX;
+
+static DEBUG_FORMAT: AtomicU8 = AtomicU8::new(UNSET);
+
+/// Set the format of the `Debug` output of [`Exn`](crate::Exn) and [`Frame`](crate::Frame),
+/// overriding the `EXN_REPORT` environment variable.
+pub fn set_debug_format(format: ReportFormat) {
+    DEBUG_FORMAT.store(format as u8, Ordering::Relaxed);
+}
+
+/// Return the format of the `Debug` output of [`Exn`](crate::Exn) and [`Frame`](crate::Frame).
+///
+/// See [`ReportFormat`] for how the format is chosen.
+pub fn debug_format() -> ReportFormat {
+    match DEBUG_FORMAT.load(Ordering::Relaxed) {
+        UNSET => {
+            let format = env_format().unwrap_or_default();
+            // a concurrent call of `set_debug_format` wins over the environment
+            let _ = DEBUG_FORMAT.compare_exchange(
+                UNSET,
+                format as u8,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+            from_u8(DEBUG_FORMAT.load(Ordering::Relaxed))
+        }
+        format => from_u8(format),
+    }
+}
+
+fn from_u8(format: u8) -> ReportFormat {
+    match format {
+        1 => ReportFormat::Compact,
+        2 => ReportFormat::Json,
+        _ => ReportFormat::Full,
+    }
+}
+
+#[cfg(feature = "std")]
+fn env_format() -> Option<ReportFormat> {
+    std::env::var("EXN_REPORT").ok()?.parse().ok()
+}
+
+#[cfg(not(feature = "std"))]
+fn env_format() -> Option<ReportFormat> {
+    None
+}
@@ -0,0 +1,141 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::time::Duration;
+use std::eprintln;
+use std::time::Instant;
+
+use crate::Fingerprinter;
+use crate::Frame;
+
+/// Create a [`DedupLogger`] that summarizes identical exception trees reported within `window`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// # fn run_service() -> exn::Result<(), std::io::Error> { Ok(()) }
+/// let mut logger = exn::dedup_logger(Duration::from_secs(60));
+/// loop {
+///     if let Err(exn) = run_service() {
+///         logger.log(exn.frame());
+///     }
+///     std::thread::sleep(Duration::from_secs(1));
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn dedup_logger(window: Duration) -> DedupLogger {
+    DedupLogger { window, last: None }
+}
+
+/// A logger for the main loop of a daemon that keeps crash loops readable.
+///
+/// The first exception tree is rendered in full. Identical trees reported within the window that
+/// follows are summarized on a single line with a repeat count instead. Once the window has
+/// elapsed, or when a different tree is reported, the next tree is rendered in full again and a new
+/// window starts.
+///
+/// Two trees are identical if they have the same shape, and the same error messages and
+/// locations at every frame.
+///
+/// This struct is created by [`dedup_logger`].
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct DedupLogger {
+    window: Duration,
+    last: Option<Last>,
+}
+
+#[derive(Debug)]
+struct Last {
+    fingerprint: u64,
+    rendered_at: Instant,
+    repeats: usize,
+}
+
+impl DedupLogger {
+    /// Render `frame`, either in full or summarized if it repeats the previous exception tree.
+    pub fn render<'a>(&mut self, frame: &'a Frame) -> Deduped<'a> {
+        let fingerprint = Fingerprinter::new()
+            .with_messages(true)
+            .with_locations(true)
+            .fingerprint(frame);
+        let now = Instant::now();
+        match &mut self.last {
+            Some(last)
+                if last.fingerprint == fingerprint
+                    && now.duration_since(last.rendered_at) < self.window =>
+            {
+                last.repeats += 1;
+                Deduped {
+                    frame,
+                    repeats: last.repeats,
+                    window: self.window,
+                }
+            }
+            _ => {
+                self.last = Some(Last {
+                    fingerprint,
+                    rendered_at: now,
+                    repeats: 0,
+                });
+                Deduped {
+                    frame,
+                    repeats: 0,
+                    window: self.window,
+                }
+            }
+        }
+    }
+
+    /// Render `frame` like [`DedupLogger::render`] and write it to the standard error.
+    pub fn log(&mut self, frame: &Frame) {
+        eprintln!("{}", self.render(frame));
+    }
+}
+
+/// An exception tree rendered by a [`DedupLogger`].
+///
+/// This struct is created by [`DedupLogger::render`].
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct Deduped<'a> {
+    frame: &'a Frame,
+    repeats: usize,
+    window: Duration,
+}
+
+impl Deduped<'_> {
+    /// Return how many times the exception tree has been repeated since it was last rendered in
+    /// full, or `0` if it is rendered in full.
+    pub fn repeats(&self) -> usize {
+        self.repeats
+    }
+}
+
+impl fmt::Display for Deduped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.repeats == 0 {
+            write!(f, "{:?}", self.frame)
+        } else {
+            write!(
+                f,
+                "{:#} (repeated {} times within {:?})",
+                self.frame, self.repeats, self.window
+            )
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::cell::OnceCell;
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+
+/// An exception whose kind is classified lazily, the first time it is inspected.
+///
+/// Classifying an exception at a library boundary usually means walking the tree and downcasting
+/// frames. A `Deferred` postpones that work until the caller asks for the [`kind`], and memoizes
+/// the result, so errors that are simply logged and dropped never pay for it.
+///
+/// This struct is created by [`Exn::defer_kind`].
+///
+/// Note that `Deferred` is not `Sync`, since the memoized kind is stored in a [`OnceCell`].
+///
+/// [`kind`]: Deferred::kind
+///
+/// # Examples
+///
+/// ```
+/// use exn::Deferred;
+/// use exn::Exn;
+///
+/// #[derive(Debug)]
+/// struct LibError;
+///
+/// impl core::fmt::Display for LibError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "library error")
+///     }
+/// }
+///
+/// impl core::error::Error for LibError {}
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum LibErrorKind {
+///     NotFound,
+///     Internal,
+/// }
+///
+/// fn classify(exn: &Exn<LibError>) -> LibErrorKind {
+///     let not_found = exn
+///         .frames()
+///         .any(|frame| frame.error().downcast_ref::<std::io::Error>().is_some());
+///     if not_found {
+///         LibErrorKind::NotFound
+///     } else {
+///         LibErrorKind::Internal
+///     }
+/// }
+///
+/// fn fetch() -> Result<(), Deferred<LibError, LibErrorKind>> {
+///     let err = std::io::Error::from(std::io::ErrorKind::NotFound);
+///     Err(Exn::new(err).raise(LibError).defer_kind(classify))
+/// }
+///
+/// let err = fetch().unwrap_err();
+/// assert_eq!(*err.kind(), LibErrorKind::NotFound);
+/// ```
+pub struct Deferred<E: Error + Send + Sync + 'static, K> {
+    exn: Exn<E>,
+    classify: fn(&Exn<E>) -> K,
+    kind: OnceCell<K>,
+}
+
+impl<E: Error + Send + Sync + 'static, K> Deferred<E, K> {
+    /// Return the kind of the exception, classifying it on the first call.
+    pub fn kind(&self) -> &K {
+        self.kind.get_or_init(|| (self.classify)(&self.exn))
+    }
+
+    /// Return `true` if the exception has already been classified.
+    pub fn is_classified(&self) -> bool {
+        self.kind.get().is_some()
+    }
+
+    /// Return the underlying exception.
+    pub fn exn(&self) -> &Exn<E> {
+        &self.exn
+    }
+
+    /// Extract the underlying exception, dropping the kind.
+    pub fn into_exn(self) -> Exn<E> {
+        self.exn
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Defer the classification of this exception with `classify` until its kind is inspected.
+    ///
+    /// See [`Deferred`] for more information.
+    pub fn defer_kind<K>(self, classify: fn(&Exn<E>) -> K) -> Deferred<E, K> {
+        Deferred {
+            exn: self,
+            classify,
+            kind: OnceCell::new(),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static, K> From<Deferred<E, K>> for Exn<E> {
+    fn from(deferred: Deferred<E, K>) -> Self {
+        deferred.exn
+    }
+}
+
+impl<E: Error + Send + Sync + 'static, K> fmt::Debug for Deferred<E, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.exn, f)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static, K> fmt::Display for Deferred<E, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.exn, f)
+    }
+}
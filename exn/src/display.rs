@@ -19,14 +19,36 @@ use core::ops::Deref;
 use crate::Exn;
 use crate::Frame;
 
+/// With the alternate flag (`{:#}`), the errors of the [primary chain](Exn::primary_chain) are
+/// written on a single line, joined by colons, e.g., `root: cause1: cause2`.
 impl<E: Error + Send + Sync + 'static> fmt::Display for Exn<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.deref())
+        if f.alternate() {
+            write_summary(f, self.frame())
+        } else {
+            write!(f, "{}", self.deref())
+        }
     }
 }
 
+/// With the alternate flag (`{:#}`), the errors of the [primary chain](Frame::primary_chain) are
+/// written on a single line, joined by colons, e.g., `root: cause1: cause2`.
 impl fmt::Display for Frame {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.error())
+        if f.alternate() {
+            write_summary(f, self)
+        } else {
+            write!(f, "{}", self.error())
+        }
     }
 }
+
+fn write_summary(f: &mut fmt::Formatter<'_>, frame: &Frame) -> fmt::Result {
+    for (i, frame) in frame.primary_chain().enumerate() {
+        if i > 0 {
+            write!(f, ": ")?;
+        }
+        write!(f, "{}", frame.error())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,285 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Results with two distinct failure modes.
+//!
+//! Most functions are best served by one flat error type at their boundary. Some genuinely fail
+//! in two ways that callers handle differently, e.g., a retryable transport failure and a
+//! permanent validation failure. [`Result<T, A, B>`] keeps such failures apart as an
+//! [`Either<Exn<A>, Exn<B>>`], without merging them prematurely into one error type.
+//!
+//! [`EitherResultExt`] raises the exception of a result into either side, and
+//! [`Either::raise`] merges both sides once the distinction no longer matters.
+//!
+//! # Examples
+//!
+//! ```
+//! use exn::either::Either;
+//! use exn::either::EitherResultExt;
+//!
+//! #[derive(Debug)]
+//! struct TransportError;
+//!
+//! impl core::fmt::Display for TransportError {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "transport failed")
+//!     }
+//! }
+//!
+//! impl core::error::Error for TransportError {}
+//!
+//! #[derive(Debug)]
+//! struct InvalidRequest;
+//!
+//! impl core::fmt::Display for InvalidRequest {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "invalid request")
+//!     }
+//! }
+//!
+//! impl core::error::Error for InvalidRequest {}
+//!
+//! fn transmit(len: u32) -> std::io::Result<u32> {
+//!     Ok(len)
+//! }
+//!
+//! fn validate(body: &str) -> exn::either::Result<u32, TransportError, InvalidRequest> {
+//!     body.parse::<u32>().or_raise_right(|| InvalidRequest)
+//! }
+//!
+//! fn send(body: &str) -> exn::either::Result<u32, TransportError, InvalidRequest> {
+//!     let len = validate(body)?;
+//!     transmit(len).or_raise_left(|| TransportError)
+//! }
+//!
+//! match send("NaN") {
+//!     Err(Either::Left(exn)) => panic!("retry on {exn}"),
+//!     Err(Either::Right(exn)) => assert_eq!(exn.to_string(), "invalid request"),
+//!     Ok(_) => unreachable!(),
+//! }
+//! ```
+
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+use crate::Frame;
+use crate::IntoExn;
+
+/// A result whose exception is either an [`Exn<A>`] or an [`Exn<B>`].
+pub type Result<T, A, B> = core::result::Result<T, Either<Exn<A>, Exn<B>>>;
+
+/// A value of one of two types.
+///
+/// See the [module documentation](self) for more information.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// A value of the left type.
+    Left(L),
+    /// A value of the right type.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Return `true` if this is a [`Left`](Either::Left) value.
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Return `true` if this is a [`Right`](Either::Right) value.
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Return the left value, if any.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(left) => Some(left),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Return the right value, if any.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(right) => Some(right),
+        }
+    }
+
+    /// Map the left value with `f`, leaving a right value unchanged.
+    pub fn map_left<T, F: FnOnce(L) -> T>(self, f: F) -> Either<T, R> {
+        match self {
+            Either::Left(left) => Either::Left(f(left)),
+            Either::Right(right) => Either::Right(right),
+        }
+    }
+
+    /// Map the right value with `f`, leaving a left value unchanged.
+    pub fn map_right<T, F: FnOnce(R) -> T>(self, f: F) -> Either<L, T> {
+        match self {
+            Either::Left(left) => Either::Left(left),
+            Either::Right(right) => Either::Right(f(right)),
+        }
+    }
+}
+
+impl<A, B> Either<Exn<A>, Exn<B>>
+where
+    A: Error + Send + Sync + 'static,
+    B: Error + Send + Sync + 'static,
+{
+    /// Return the topmost frame of the exception on either side.
+    pub fn frame(&self) -> &Frame {
+        match self {
+            Either::Left(exn) => exn.frame(),
+            Either::Right(exn) => exn.frame(),
+        }
+    }
+
+    /// Raise a new exception on the exception on either side, merging both failure modes into
+    /// one.
+    ///
+    /// See [`Exn::raise`] for more information.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise<T: Error + Send + Sync + 'static>(self, err: T) -> Exn<T> {
+        match self {
+            Either::Left(exn) => exn.raise(err),
+            Either::Right(exn) => exn.raise(err),
+        }
+    }
+}
+
+impl<A, B> AsRef<Frame> for Either<Exn<A>, Exn<B>>
+where
+    A: Error + Send + Sync + 'static,
+    B: Error + Send + Sync + 'static,
+{
+    fn as_ref(&self) -> &Frame {
+        self.frame()
+    }
+}
+
+impl<L: fmt::Debug, R: fmt::Debug> fmt::Debug for Either<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Either::Left(left) => fmt::Debug::fmt(left, f),
+            Either::Right(right) => fmt::Debug::fmt(right, f),
+        }
+    }
+}
+
+impl<L: fmt::Display, R: fmt::Display> fmt::Display for Either<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Either::Left(left) => fmt::Display::fmt(left, f),
+            Either::Right(right) => fmt::Display::fmt(right, f),
+        }
+    }
+}
+
+/// An extension trait for [`Result`](core::result::Result) to raise its exception into either
+/// side of an [`Either`].
+pub trait EitherResultExt {
+    /// The `Ok` type.
+    type Success;
+
+    /// The `Err` type that would be wrapped in an [`Exn`].
+    type Error: Error + Send + Sync + 'static;
+
+    /// Raise a new exception on the `Err` variant like [`ResultExt::or_raise`], on the left side.
+    ///
+    /// [`ResultExt::or_raise`]: crate::ResultExt::or_raise
+    fn or_raise_left<A, B, F>(self, err: F) -> Result<Self::Success, A, B>
+    where
+        A: Error + Send + Sync + 'static,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A;
+
+    /// Raise a new exception on the `Err` variant like [`ResultExt::or_raise`], on the right
+    /// side.
+    ///
+    /// [`ResultExt::or_raise`]: crate::ResultExt::or_raise
+    fn or_raise_right<A, B, F>(self, err: F) -> Result<Self::Success, A, B>
+    where
+        A: Error + Send + Sync + 'static,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> B;
+
+    /// Put the exception of the `Err` variant on the left side, without raising a new one.
+    fn into_left<B>(self) -> Result<Self::Success, Self::Error, B>
+    where
+        B: Error + Send + Sync + 'static;
+
+    /// Put the exception of the `Err` variant on the right side, without raising a new one.
+    fn into_right<A>(self) -> Result<Self::Success, A, Self::Error>
+    where
+        A: Error + Send + Sync + 'static;
+}
+
+impl<T, E> EitherResultExt for core::result::Result<T, E>
+where
+    E: IntoExn,
+{
+    type Success = T;
+    type Error = E::Error;
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn or_raise_left<A, B, F>(self, err: F) -> Result<T, A, B>
+    where
+        A: Error + Send + Sync + 'static,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(Either::Left(e.into_exn().raise(err()))),
+        }
+    }
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn or_raise_right<A, B, F>(self, err: F) -> Result<T, A, B>
+    where
+        A: Error + Send + Sync + 'static,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> B,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(Either::Right(e.into_exn().raise(err()))),
+        }
+    }
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn into_left<B>(self) -> Result<T, E::Error, B>
+    where
+        B: Error + Send + Sync + 'static,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(Either::Left(e.into_exn())),
+        }
+    }
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn into_right<A>(self) -> Result<T, A, E::Error>
+    where
+        A: Error + Send + Sync + 'static,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(Either::Right(e.into_exn())),
+        }
+    }
+}
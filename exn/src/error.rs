@@ -0,0 +1,126 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+use core::fmt;
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+use crate::Exn;
+
+/// An exception that implements [`Error`].
+///
+/// `Exn` itself does not implement `Error`, since that would conflict with the conversion of
+/// any error into an `Exn` with `?`. Wrap it in an `ExnError` to pass it to APIs requiring an
+/// owned error type, e.g., as the source of another error, while keeping access to the tree
+/// through [`Deref`]. The [source](Error::source) of an `ExnError` is the
+/// [primary child](crate::Frame::primary_child) frame.
+///
+/// To pass an exception to APIs taking a `&dyn Error` without consuming it, use
+/// [`Exn::as_error`] instead.
+///
+/// This struct is created by [`Exn::into_std_error`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::ExnError;
+///
+/// fn log_error(error: &(dyn std::error::Error + 'static)) {
+///     eprintln!(
+///         "{error}, caused by {:?}",
+///         error.source().map(|e| e.to_string())
+///     );
+/// }
+///
+/// let exn = Exn::new(std::fmt::Error).raise(std::io::Error::other("failed to render"));
+/// log_error(exn.as_error());
+///
+/// let error: ExnError<std::io::Error> = exn.into_std_error();
+/// assert_eq!(error.kind(), std::io::ErrorKind::Other);
+/// assert!(std::error::Error::source(&error).is_some());
+/// ```
+pub struct ExnError<E: Error + Send + Sync + 'static>(Exn<E>);
+
+impl<E: Error + Send + Sync + 'static> ExnError<E> {
+    /// Return the wrapped exception.
+    pub fn exn(&self) -> &Exn<E> {
+        &self.0
+    }
+
+    /// Extract the wrapped exception.
+    pub fn into_exn(self) -> Exn<E> {
+        self.0
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return the exception as an error, e.g., to pass it to APIs taking a `&dyn Error`.
+    ///
+    /// The returned error is the topmost frame; its [source](Error::source) is the
+    /// [primary child](crate::Frame::primary_child) frame.
+    pub fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.frame()
+    }
+
+    /// Wrap this exception in an [`ExnError`] that implements [`Error`].
+    pub fn into_std_error(self) -> ExnError<E> {
+        ExnError(self)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for ExnError<E> {
+    fn from(exn: Exn<E>) -> Self {
+        ExnError(exn)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Deref for ExnError<E> {
+    type Target = Exn<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> DerefMut for ExnError<E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> fmt::Debug for ExnError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// See the `Display` implementation of [`Exn`] for the alternate format.
+impl<E: Error + Send + Sync + 'static> fmt::Display for ExnError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Error for ExnError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.frame().source()
+    }
+
+    #[cfg(exn_nightly)]
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        self.0.frame().provide(request);
+    }
+}
@@ -41,7 +41,7 @@ pub fn Ok<T, E: Error + Send + Sync + 'static>(value: T) -> Result<T, E> {
 /// An extension trait for error types to raise them as exceptions.
 pub trait ErrorExt: Error + Send + Sync + 'static {
     /// Raise this error as a new exception.
-    #[track_caller]
+    #[cfg_attr(not(exn_no_location), track_caller)]
     fn raise(self) -> Exn<Self>
     where
         Self: Sized,
@@ -0,0 +1,128 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities for returning exceptions from FFI callbacks that can only return an integer.
+//!
+//! The common C pattern is to return a status code and let the caller retrieve the rich error
+//! afterwards. [`set_last_error`] maps an exception to the integer registered for its
+//! [error code](crate::ErrorCode) and stores the whole exception tree in a thread-local slot, from
+//! where [`last_error`] retrieves it.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::ffi::c_int;
+//!
+//! use exn::ErrorCode;
+//! use exn::Exn;
+//!
+//! #[derive(Debug)]
+//! struct NotFound;
+//!
+//! impl core::fmt::Display for NotFound {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "not found")
+//!     }
+//! }
+//!
+//! impl core::error::Error for NotFound {}
+//!
+//! impl ErrorCode for NotFound {
+//!     fn code(&self) -> &'static str {
+//!         "E0404"
+//!     }
+//! }
+//!
+//! exn::register_error_code::<NotFound>();
+//! exn::ffi::register_int_code("E0404", 404);
+//!
+//! extern "C" fn callback() -> c_int {
+//!     exn::ffi::into_status(Err::<(), _>(Exn::new(NotFound)))
+//! }
+//!
+//! assert_eq!(callback(), 404);
+//! let frame = exn::ffi::last_error().unwrap();
+//! assert_eq!(frame.to_string(), "not found");
+//! ```
+
+use core::cell::RefCell;
+use core::error::Error;
+use core::ffi::c_int;
+use std::sync::RwLock;
+use std::vec::Vec;
+
+use crate::Exn;
+use crate::Frame;
+use crate::Result;
+
+/// The status returned by [`into_status`] on success.
+pub const STATUS_OK: c_int = 0;
+
+/// The status returned for exceptions without an error code registered with
+/// [`register_int_code`].
+pub const STATUS_UNKNOWN: c_int = -1;
+
+static INT_CODES: RwLock<Vec<(&'static str, c_int)>> = RwLock::new(Vec::new());
+
+std::thread_local! {
+    static LAST_ERROR: RefCell<Option<Frame>> = const { RefCell::new(None) };
+}
+
+/// Register the integer returned by [`set_last_error`] for exceptions with the given error code.
+///
+/// Registering the same error code again replaces the previous integer.
+pub fn register_int_code(code: &'static str, value: c_int) {
+    let mut int_codes = INT_CODES.write().unwrap_or_else(|err| err.into_inner());
+    match int_codes.iter_mut().find(|(c, _)| *c == code) {
+        Some(entry) => entry.1 = value,
+        None => int_codes.push((code, value)),
+    }
+}
+
+/// Return the integer registered for the error code of `exn`.
+///
+/// The error code is looked up with [`Exn::code`]. Returns `None` if the exception has no error
+/// code or no integer has been registered for it.
+pub fn int_code<E: Error + Send + Sync + 'static>(exn: &Exn<E>) -> Option<c_int> {
+    let code = exn.code()?;
+    let int_codes = INT_CODES.read().unwrap_or_else(|err| err.into_inner());
+    int_codes.iter().find(|(c, _)| *c == code).map(|(_, v)| *v)
+}
+
+/// Store `exn` as the last error of the current thread, and return the integer registered for its
+/// error code, or [`STATUS_UNKNOWN`].
+pub fn set_last_error<E: Error + Send + Sync + 'static>(exn: Exn<E>) -> c_int {
+    let status = int_code(&exn).unwrap_or(STATUS_UNKNOWN);
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(*exn.into_boxed_frame()));
+    status
+}
+
+/// Take the last error of the current thread, leaving the slot empty.
+pub fn last_error() -> Option<Frame> {
+    LAST_ERROR.with(|last_error| last_error.borrow_mut().take())
+}
+
+/// Clear the last error of the current thread.
+pub fn clear_last_error() {
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = None);
+}
+
+/// Convert `result` into a status: [`STATUS_OK`] on the `Ok` variant, otherwise the result of
+/// [`set_last_error`].
+pub fn into_status<E: Error + Send + Sync + 'static>(result: Result<(), E>) -> c_int {
+    match result {
+        Ok(()) => STATUS_OK,
+        Err(exn) => set_last_error(exn),
+    }
+}
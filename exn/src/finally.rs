@@ -0,0 +1,175 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+#[cfg(feature = "std")]
+use core::fmt;
+#[cfg(feature = "std")]
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::Exn;
+use crate::RaiseFrom;
+use crate::Result;
+
+/// Run `body`, then `cleanup`, folding a cleanup failure into the failure of `body`.
+///
+/// * If only `body` fails, its exception is returned.
+/// * If only `cleanup` fails, its exception is returned. If the cleanup has an error type of its
+///   own, the error `E` creates with [`RaiseFrom`] is raised with the cleanup exception as its
+///   child. The error type of a cleanup that cannot fail may need an annotation then, e.g.,
+///   [`exn::Ok::<_, E>(())`](crate::Ok).
+/// * If both fail, the cleanup failure is recorded as [suppressed](crate::Exn::suppress) by the
+///   exception of `body`, which is returned, so that neither failure is lost.
+///
+/// `cleanup` also runs if `body` panics, in which case its result is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+///
+/// fn write() -> exn::Result<(), std::io::Error> {
+///     Err(Exn::new(std::io::Error::other("failed to write the file")))
+/// }
+///
+/// fn close() -> exn::Result<(), std::io::Error> {
+///     Err(Exn::new(std::io::Error::other("failed to close the file")))
+/// }
+///
+/// let exn = exn::try_finally(write, close).unwrap_err();
+/// assert_eq!(exn.to_string(), "failed to write the file");
+/// assert_eq!(
+///     exn.frame().suppressed()[0].to_string(),
+///     "failed to close the file"
+/// );
+/// ```
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn try_finally<T, E, F, B, C>(body: B, cleanup: C) -> Result<T, E>
+where
+    E: RaiseFrom<F>,
+    F: Error + Send + Sync + 'static,
+    B: FnOnce() -> Result<T, E>,
+    C: FnOnce() -> Result<(), F>,
+{
+    let mut guard = Guard(Some(cleanup));
+    let result = body();
+    let cleanup = guard.0.take().expect("cleanup must run once");
+    match (result, cleanup()) {
+        (result, Ok(())) => result,
+        (Ok(_), Err(exn)) => Err(Exn::raise_from_exn(exn)),
+        (Err(mut exn), Err(cleanup_exn)) => {
+            exn.suppress(cleanup_exn);
+            Err(exn)
+        }
+    }
+}
+
+/// Run `body`, attaching the location where the scope was entered to any exception escaping it.
+///
+/// This is a cheap marker of which operation failed, like a `try` block, without defining an
+/// error type per scope: the attachment reads `in scope` and is located at the call of
+/// `try_scope`.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ResultExt;
+///
+/// let result = exn::try_scope(|| {
+///     let port = "http"
+///         .parse::<u16>()
+///         .or_raise(|| std::io::Error::other("invalid port"))?;
+///     Ok(port)
+/// });
+///
+/// let exn = result.unwrap_err();
+/// assert_eq!(exn.frame().attachments()[0].to_string(), "in scope");
+/// ```
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn try_scope<T, E, F>(body: F) -> Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+    F: FnOnce() -> Result<T, E>,
+{
+    let location = crate::location::caller();
+    match body() {
+        Ok(v) => Ok(v),
+        Err(exn) => Err(exn.attach_at("in scope", location)),
+    }
+}
+
+/// Run `body`, attaching the time elapsed since the scope was entered to any exception escaping
+/// it.
+///
+/// The attachment reads like `after 3.2s` and is located at the call of `timed_scope`, which
+/// helps diagnosing timeouts and slow paths. Nothing is recorded if `body` succeeds.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ResultExt;
+///
+/// let result = exn::timed_scope(|| {
+///     std::thread::sleep(std::time::Duration::from_millis(10));
+///     "http"
+///         .parse::<u16>()
+///         .or_raise(|| std::io::Error::other("invalid port"))
+/// });
+///
+/// let exn = result.unwrap_err();
+/// assert!(
+///     exn.frame().attachments()[0]
+///         .to_string()
+///         .starts_with("after ")
+/// );
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn timed_scope<T, E, F>(body: F) -> Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+    F: FnOnce() -> Result<T, E>,
+{
+    let location = crate::location::caller();
+    let start = Instant::now();
+    match body() {
+        Ok(v) => Ok(v),
+        Err(exn) => Err(exn.attach_at(Elapsed(start.elapsed()), location)),
+    }
+}
+
+/// The time elapsed in a [`timed_scope`], rendered with one decimal, e.g., `after 3.2s`.
+#[cfg(feature = "std")]
+struct Elapsed(Duration);
+
+#[cfg(feature = "std")]
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "after {:.1?}", self.0)
+    }
+}
+
+/// A guard running the cleanup if the body panics.
+struct Guard<C: FnOnce() -> Result<(), E>, E: Error + Send + Sync + 'static>(Option<C>);
+
+impl<C: FnOnce() -> Result<(), E>, E: Error + Send + Sync + 'static> Drop for Guard<C, E> {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.0.take() {
+            let _ = cleanup();
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+use core::fmt;
+use core::fmt::Write;
+
+use crate::Exn;
+use crate::Frame;
+
+/// A hasher of exception trees into fingerprints, to group recurring failures.
+///
+/// By default, the fingerprint covers the shape of the tree and the type of the error of each
+/// frame, identified by the stable name of its [registered type](crate::register_error_type) or
+/// else by its [type name](Frame::type_name). Messages and locations are ignored, since they
+/// often embed identifiers or change with unrelated edits; enable them with
+/// [`with_messages`](Fingerprinter::with_messages) and
+/// [`with_locations`](Fingerprinter::with_locations).
+///
+/// Fingerprints are computed with 64-bit FNV-1a, so they do not depend on the process or the
+/// platform. They are stable across builds only if every error type in the tree is registered:
+/// an unregistered type is identified by [`core::any::type_name`], whose output may change with
+/// any release of Rust, and changes when the type is renamed or moved.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Fingerprinter;
+///
+/// let fetch = |id: u32| exn::Exn::new(std::io::Error::other(format!("user {id} not found")));
+///
+/// assert_eq!(fetch(1).fingerprint(), fetch(2).fingerprint());
+///
+/// let fingerprinter = Fingerprinter::new().with_messages(true);
+/// assert_ne!(
+///     fingerprinter.fingerprint(fetch(1).frame()),
+///     fingerprinter.fingerprint(fetch(2).frame()),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fingerprinter {
+    messages: bool,
+    locations: bool,
+}
+
+impl Fingerprinter {
+    /// Create a fingerprinter covering the shape of the tree and the types of the errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to cover the message of the error of each frame. Defaults to `false`.
+    pub fn with_messages(mut self, messages: bool) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Set whether to cover the location of each frame. Defaults to `false`.
+    pub fn with_locations(mut self, locations: bool) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Return the fingerprint of the exception tree rooted at `frame`.
+    pub fn fingerprint(&self, frame: &Frame) -> u64 {
+        let mut hasher = Fnv1a::new();
+        self.walk(frame, &mut hasher);
+        hasher.0
+    }
+
+    fn walk(&self, frame: &Frame, hasher: &mut Fnv1a) {
+        let error_type = frame.error_type().map(|error_type| error_type.name());
+        hasher.write_field(error_type.or(frame.type_name()).unwrap_or_default());
+        if self.messages {
+            // writing to the hasher never fails
+            let _ = write!(hasher, "{}", frame.error());
+            hasher.write_separator();
+        }
+        if self.locations {
+            let _ = write!(hasher, "{}", frame.frame_location());
+            hasher.write_separator();
+        }
+        hasher.write_bytes(&(frame.children().len() as u64).to_le_bytes());
+        for child in frame.children() {
+            self.walk(child, hasher);
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash function.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_field(&mut self, field: &str) {
+        self.write_bytes(field.as_bytes());
+        self.write_separator();
+    }
+
+    // 0xff never occurs in UTF-8, so fields cannot run into each other
+    fn write_separator(&mut self) {
+        self.write_bytes(&[0xff]);
+    }
+}
+
+impl Write for Fnv1a {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_bytes(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return the fingerprint of the exception tree, covering its shape and the types of its
+    /// errors.
+    ///
+    /// See [`Fingerprinter`] for more information and for other options.
+    pub fn fingerprint(&self) -> u64 {
+        self.frame().fingerprint()
+    }
+}
+
+impl Frame {
+    /// Return the fingerprint of the exception tree rooted at this frame, covering its shape and
+    /// the types of its errors.
+    ///
+    /// See [`Fingerprinter`] for more information and for other options.
+    pub fn fingerprint(&self) -> u64 {
+        Fingerprinter::new().fingerprint(self)
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+use core::fmt;
+
+use crate::IntoExn;
+use crate::Result;
+
+/// An extension trait for futures resolving to a [`Result`] to provide context information on
+/// [`Exn`](crate::Exn)s.
+///
+/// The combinators record the location where they are called, not where the future is awaited,
+/// so async call chains need no intermediate bindings to keep accurate locations.
+///
+/// # Examples
+///
+/// ```
+/// use exn::FutureExt;
+///
+/// # #[derive(Debug)]
+/// # struct ConfigError;
+/// # impl core::fmt::Display for ConfigError {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// #         write!(f, "failed to load config")
+/// #     }
+/// # }
+/// # impl core::error::Error for ConfigError {}
+/// async fn read_config() -> Result<String, std::io::Error> {
+///     Err(std::io::Error::other("permission denied"))
+/// }
+///
+/// async fn load() -> exn::Result<String, ConfigError> {
+///     read_config()
+///         .or_attach(|| "reading /etc/app.toml")
+///         .or_raise(|| ConfigError)
+///         .await
+/// }
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let exn = runtime.block_on(load()).unwrap_err();
+/// assert_eq!(
+///     format!("{exn:#}"),
+///     "failed to load config: permission denied"
+/// );
+/// ```
+pub trait FutureExt: Future<Output = core::result::Result<Self::Success, Self::Failure>> {
+    /// The `Ok` type.
+    type Success;
+
+    /// The `Err` type, either an error or an [`Exn`](crate::Exn).
+    type Failure: IntoExn;
+
+    /// Raise a new exception on the [`Exn`](crate::Exn) the future resolves to.
+    ///
+    /// Like [`ResultExt::or_raise`](crate::ResultExt::or_raise), located at the caller.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn or_raise<A, F>(self, err: F) -> impl Future<Output = Result<Self::Success, A>>
+    where
+        Self: Sized,
+        A: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        let location = crate::location::caller();
+        async move {
+            match self.await {
+                Ok(v) => Ok(v),
+                Err(e) => Err(e.__into_exn_at(location).raise_at(err(), location)),
+            }
+        }
+    }
+
+    /// Attach a message to the [`Exn`](crate::Exn) the future resolves to.
+    ///
+    /// Like [`Exn::attach`](crate::Exn::attach), located at the caller.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn or_attach<M, F>(
+        self,
+        message: F,
+    ) -> impl Future<Output = Result<Self::Success, <Self::Failure as IntoExn>::Error>>
+    where
+        Self: Sized,
+        M: fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> M,
+    {
+        let location = crate::location::caller();
+        async move {
+            match self.await {
+                Ok(v) => Ok(v),
+                Err(e) => Err(e.__into_exn_at(location).attach_at(message(), location)),
+            }
+        }
+    }
+}
+
+impl<Fut, T, E> FutureExt for Fut
+where
+    Fut: Future<Output = core::result::Result<T, E>>,
+    E: IntoExn,
+{
+    type Success = T;
+    type Failure = E;
+}
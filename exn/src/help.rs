@@ -0,0 +1,168 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::String;
+use core::any::TypeId;
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+use crate::Frame;
+use crate::registry::Registry;
+
+/// Help for users who run into an error: a hint to fix it, and a link to its documentation.
+///
+/// Reports write them in a `help:` and `see:` trailer after the exception tree, so that CLI tools
+/// can point users to docs for specific failures without encoding URLs in error messages.
+///
+/// Error types implementing this trait must be [registered](crate#registering-error-types) with
+/// [`register_help`] before their help can be found in an exception tree. Help can also be
+/// attached to individual frames with [`Exn::with_help`] and [`Exn::with_url`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Help;
+///
+/// #[derive(Debug)]
+/// struct MissingConfig;
+///
+/// impl core::fmt::Display for MissingConfig {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "config file not found")
+///     }
+/// }
+///
+/// impl core::error::Error for MissingConfig {}
+///
+/// impl Help for MissingConfig {
+///     fn help(&self) -> Option<String> {
+///         Some("run `tool init` to create one".to_string())
+///     }
+///
+///     fn url(&self) -> Option<String> {
+///         Some("https://example.com/docs/config".to_string())
+///     }
+/// }
+///
+/// exn::register_help::<MissingConfig>();
+///
+/// let exn = exn::Exn::new(MissingConfig);
+/// assert_eq!(
+///     format!("{:?}", exn::testing::without_locations(exn.frame())),
+///     "config file not found\n\nhelp: run `tool init` to create one\nsee: https://example.com/docs/config"
+/// );
+/// ```
+pub trait Help: Error + Send + Sync + 'static {
+    /// Return a hint to fix this error, if any.
+    fn help(&self) -> Option<String> {
+        None
+    }
+
+    /// Return the URL of the documentation of this error, if any.
+    fn url(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Register the error type `T` so that its help is reported by [`Frame::help`] and
+/// [`Frame::url`].
+///
+/// Registering the same type more than once has no further effect.
+pub fn register_help<T: Help>() {
+    fn help_of<'a, T: Help>(error: &'a (dyn Error + 'static)) -> Option<&'a dyn Help> {
+        error.downcast_ref::<T>().map(|error| error as &dyn Help)
+    }
+
+    REGISTRY.register(TypeId::of::<T>(), help_of::<T>);
+}
+
+type HelpOfFn = for<'a> fn(&'a (dyn Error + 'static)) -> Option<&'a dyn Help>;
+
+static REGISTRY: Registry<HelpOfFn> = Registry::new();
+
+/// An attachment carrying help for a single frame.
+pub(crate) enum Hint {
+    Help(String),
+    Url(String),
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::Help(help) => write!(f, "help: {help}"),
+            Hint::Url(url) => write!(f, "see: {url}"),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Attach a hint to fix the error to the topmost frame, overriding the one of its error type.
+    ///
+    /// See [`Help`] for more information.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn with_help(self, help: impl Into<String>) -> Self {
+        self.attach(Hint::Help(help.into()))
+    }
+
+    /// Attach the URL of the documentation of the error to the topmost frame, overriding the one
+    /// of its error type.
+    ///
+    /// See [`Help`] for more information.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn with_url(self, url: impl Into<String>) -> Self {
+        self.attach(Hint::Url(url.into()))
+    }
+}
+
+impl Frame {
+    /// Return the hint to fix the error that occurred at this frame.
+    ///
+    /// This is the last hint attached with [`Exn::with_help`], if any, or else the one of the
+    /// error, if its type implements [`Help`] and has been registered with [`register_help`].
+    pub fn help(&self) -> Option<String> {
+        self.hints()
+            .filter_map(|hint| match hint {
+                Hint::Help(help) => Some(help.clone()),
+                Hint::Url(_) => None,
+            })
+            .last()
+            .or_else(|| self.help_impl().and_then(Help::help))
+    }
+
+    /// Return the URL of the documentation of the error that occurred at this frame.
+    ///
+    /// This is the last URL attached with [`Exn::with_url`], if any, or else the one of the
+    /// error, if its type implements [`Help`] and has been registered with [`register_help`].
+    pub fn url(&self) -> Option<String> {
+        self.hints()
+            .filter_map(|hint| match hint {
+                Hint::Url(url) => Some(url.clone()),
+                Hint::Help(_) => None,
+            })
+            .last()
+            .or_else(|| self.help_impl().and_then(Help::url))
+    }
+
+    fn hints(&self) -> impl Iterator<Item = &Hint> {
+        self.attachments()
+            .iter()
+            .filter_map(|attachment| attachment.downcast_ref())
+    }
+
+    fn help_impl(&self) -> Option<&dyn Help> {
+        let error = self.error() as &(dyn Error + 'static);
+        REGISTRY.entries().find_map(|entry| (entry.value)(error))
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::string::ToString;
@@ -21,7 +22,18 @@ use core::error::Error;
 use core::fmt;
 use core::marker::PhantomData;
 use core::ops::Deref;
+use core::ops::DerefMut;
 use core::panic::Location;
+use core::slice;
+
+use crate::Attachment;
+use crate::FrameLocation;
+use crate::FramesDropped;
+use crate::RaiseFrom;
+use crate::Severity;
+use crate::frame_limit;
+use crate::location::StoredLocation;
+use crate::observer;
 
 /// An exception type that can hold an error tree and additional context.
 pub struct Exn<E: Error + Send + Sync + 'static> {
@@ -30,10 +42,19 @@ pub struct Exn<E: Error + Send + Sync + 'static> {
     phantom: PhantomData<E>,
 }
 
-impl<E: Error + Send + Sync + 'static> From<E> for Exn<E> {
-    #[track_caller]
-    fn from(error: E) -> Self {
-        Exn::new(error)
+/// Raising an error `E2` as an `Exn<E1>` is supported when `E1` implements [`RaiseFrom<E2>`].
+///
+/// This is always the case for `E1 == E2`, which creates a new exception with the error.
+///
+/// [`RaiseFrom<E2>`]: crate::RaiseFrom
+impl<E1, E2> From<E2> for Exn<E1>
+where
+    E1: RaiseFrom<E2>,
+    E2: Error + Send + Sync + 'static,
+{
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn from(error: E2) -> Self {
+        E1::raise_from(error)
     }
 }
 
@@ -50,42 +71,88 @@ impl<E: Error + Send + Sync + 'static> Exn<E> {
     ///
     /// [source chain of the error]: Error::source
     /// [`ErrorExt::raise`](crate::ErrorExt)
-    #[track_caller]
+    #[cfg_attr(not(exn_no_location), track_caller)]
     pub fn new(error: E) -> Self {
-        struct SourceError(String);
-
-        impl fmt::Debug for SourceError {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt::Debug::fmt(&self.0, f)
-            }
-        }
-
-        impl fmt::Display for SourceError {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt::Display::fmt(&self.0, f)
-            }
-        }
+        Self::new_at(error, crate::location::caller())
+    }
 
-        impl Error for SourceError {}
+    /// Create a new exception with the given error, located at `location` rather than at the
+    /// caller.
+    ///
+    /// This lets code generators report the position of the code they were generated from, e.g.,
+    /// a procedural macro capturing [`Location::caller`] in a function spanned to the input of
+    /// the user, instead of the generated code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::panic::Location;
+    ///
+    /// use exn::Exn;
+    ///
+    /// #[track_caller]
+    /// fn user_location() -> &'static Location<'static> {
+    ///     Location::caller()
+    /// }
+    ///
+    /// let location = user_location();
+    /// let exn = Exn::new_at(std::fmt::Error, location);
+    /// assert_eq!(exn.frame().location(), location);
+    /// ```
+    pub fn new_at(error: E, location: &'static Location<'static>) -> Self {
+        #[cfg_attr(not(feature = "std"), allow(unused_mut))]
+        let mut new_exn = Self::unobserved(error, location);
+        #[cfg(feature = "std")]
+        crate::scope::attach_scopes(&mut new_exn.frame);
+        observer::notify_new(&new_exn.frame);
+        new_exn
+    }
 
-        fn walk(error: &dyn Error, location: &'static Location<'static>) -> Vec<Frame> {
+    /// Create a new exception like [`Exn::new_at`], without notifying the observer.
+    ///
+    /// Exceptions raised on others are completed first and then notified as raised.
+    pub(crate) fn unobserved(error: E, location: &'static Location<'static>) -> Self {
+        fn walk(error: &dyn Error, location: &'static Location<'static>) -> Children {
             if let Some(source) = error.source() {
-                let children = vec![Frame {
+                let children = walk(source, location);
+                Children::One(Box::new(Frame {
                     error: Box::new(SourceError(source.to_string())),
-                    location,
-                    children: walk(source, location),
-                }];
-                children
+                    location: StoredLocation::new(location),
+                    frame_location: None,
+                    function: None,
+                    type_name: None,
+                    severity: Severity::Error,
+                    origin: FrameOrigin::SourceChain,
+                    sensitive: false,
+                    transparent: false,
+                    primary: None,
+                    tags: Vec::new(),
+                    attachments: Vec::new(),
+                    suppressed: Vec::new(),
+                    frame_count: 1 + children.frame_count(),
+                    children,
+                }))
             } else {
-                vec![]
+                Children::None
             }
         }
 
-        let location = Location::caller();
         let children = walk(&error, location);
         let frame = Frame {
             error: Box::new(error),
-            location,
+            location: StoredLocation::new(location),
+            frame_location: None,
+            function: None,
+            type_name: Some(core::any::type_name::<E>()),
+            severity: Severity::Error,
+            origin: FrameOrigin::Raised,
+            sensitive: false,
+            transparent: false,
+            primary: None,
+            tags: Vec::new(),
+            attachments: Vec::new(),
+            suppressed: Vec::new(),
+            frame_count: 1 + children.frame_count(),
             children,
         };
 
@@ -96,38 +163,647 @@ impl<E: Error + Send + Sync + 'static> Exn<E> {
     }
 
     /// Create a new exception with the given error and its children.
-    #[track_caller]
+    #[cfg_attr(not(exn_no_location), track_caller)]
     pub fn raise_all<T, I>(error: E, children: I) -> Self
     where
         T: Error + Send + Sync + 'static,
         I: IntoIterator,
         I::Item: Into<Exn<T>>,
     {
-        let mut new_exn = Exn::new(error);
-        for exn in children {
-            let exn = exn.into();
-            new_exn.frame.children.push(*exn.frame);
-        }
+        Self::raise_all_at(error, children, crate::location::caller())
+    }
+
+    /// Create a new exception with the given error and its children, marking the child at index
+    /// `primary` as the primary cause.
+    ///
+    /// The [source](Error::source), [root cause](Exn::root_cause) and
+    /// [primary chain](Exn::primary_chain) of the exception follow the primary child instead of
+    /// the first one, while reports keep the children in order. If there is no child at index
+    /// `primary`, or if it is dropped over the [frame limit](crate::set_frame_limit), the first
+    /// child is the primary one as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// let exn = Exn::raise_all_with_primary(
+    ///     std::io::Error::other("failed to replicate"),
+    ///     [
+    ///         Exn::new(std::io::Error::other("replica 1 is lagging")),
+    ///         Exn::new(std::io::Error::other("disk full on the primary")),
+    ///     ],
+    ///     1,
+    /// );
+    /// assert_eq!(exn.root_cause().to_string(), "disk full on the primary");
+    /// assert_eq!(
+    ///     exn.frame().children()[0].to_string(),
+    ///     "replica 1 is lagging"
+    /// );
+    /// ```
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise_all_with_primary<T, I>(error: E, children: I, primary: usize) -> Self
+    where
+        T: Error + Send + Sync + 'static,
+        I: IntoIterator,
+        I::Item: Into<Exn<T>>,
+    {
+        let children = children.into_iter().map(|exn| exn.into().frame);
+        let location = crate::location::caller();
+        Self::raise_frames_with_primary_at(error, children, Some(primary), location)
+    }
+
+    /// Create a new exception with the given error and its children, located at `location`.
+    pub(crate) fn raise_all_at<T, I>(
+        error: E,
+        children: I,
+        location: &'static Location<'static>,
+    ) -> Self
+    where
+        T: Error + Send + Sync + 'static,
+        I: IntoIterator,
+        I::Item: Into<Exn<T>>,
+    {
+        let children = children.into_iter().map(|exn| exn.into().frame);
+        Self::raise_frames_at(error, children, location)
+    }
+
+    /// Create a new exception with the given error and the trees rooted at `children` as its
+    /// children, located at `location`.
+    pub(crate) fn raise_frames_at(
+        error: E,
+        children: impl Iterator<Item = Box<Frame>>,
+        location: &'static Location<'static>,
+    ) -> Self {
+        Self::raise_frames_with_primary_at(error, children, None, location)
+    }
+
+    /// Create a new exception like [`Exn::raise_frames_at`], whose primary child is the one at
+    /// index `primary` in `children`, if any.
+    fn raise_frames_with_primary_at(
+        error: E,
+        children: impl Iterator<Item = Box<Frame>>,
+        mut primary: Option<usize>,
+        location: &'static Location<'static>,
+    ) -> Self {
+        let mut new_exn = Exn::unobserved(error, location);
+        let mut dropped = 0;
+        let children = if frame_limit() == usize::MAX {
+            Children::from_boxed(children)
+        } else {
+            let children = children.collect::<Vec<_>>();
+            let mut size = new_exn.frame.frame_count;
+            let total: usize = children.iter().map(|child| child.frame_count).sum();
+            // keep room for the frames standing in for exceptions dropped now or suppressed later
+            let limit = frame_limit().saturating_sub(1);
+            let limit = if size.saturating_add(total) <= limit {
+                limit
+            } else {
+                limit.saturating_sub(1)
+            };
+            let mut kept = Vec::with_capacity(children.len());
+            let mut kept_primary = None;
+            for (i, child) in children.into_iter().enumerate() {
+                if size.saturating_add(child.frame_count) <= limit {
+                    size += child.frame_count;
+                    if primary == Some(i) {
+                        kept_primary = Some(kept.len());
+                    }
+                    kept.push(child);
+                } else {
+                    dropped += child.frame_count;
+                }
+            }
+            primary = kept_primary;
+            Children::from_boxed(kept.into_iter())
+        };
+        let count = children.len();
+        new_exn.frame.extend_children(children);
+        // the children come after the frames kept from the source chain of the error
+        let offset = new_exn.frame.children.len() - count;
+        new_exn.frame.primary = primary
+            .filter(|&primary| primary < count)
+            .map(|primary| offset + primary);
+        let added = record_dropped(&mut new_exn.frame.children, dropped, location);
+        new_exn.frame.frame_count += added;
+        observer::notify_raise(&new_exn.frame);
         new_exn
     }
 
     /// Raise a new exception; this will make the current exception a child of the new one.
-    #[track_caller]
+    #[cfg_attr(not(exn_no_location), track_caller)]
     pub fn raise<T: Error + Send + Sync + 'static>(self, err: T) -> Exn<T> {
-        let mut new_exn = Exn::new(err);
-        new_exn.frame.children.push(*self.frame);
+        self.raise_at(err, crate::location::caller())
+    }
+
+    /// Raise a new exception like [`Exn::raise`], located at `location` rather than at the
+    /// caller.
+    ///
+    /// See [`Exn::new_at`] for more information.
+    pub fn raise_at<T: Error + Send + Sync + 'static>(
+        self,
+        err: T,
+        location: &'static Location<'static>,
+    ) -> Exn<T> {
+        Exn::raise_frame_at(err, self.frame, location)
+    }
+
+    /// Attach a message to the topmost frame like [`Exn::attach`], located at `location` rather
+    /// than at the caller.
+    ///
+    /// See [`Exn::new_at`] for more information.
+    pub fn attach_at<M>(mut self, message: M, location: &'static Location<'static>) -> Self
+    where
+        M: fmt::Display + Send + Sync + 'static,
+    {
+        let attachment = Attachment::new_at(message, location);
+        self.frame.attachments.push(attachment);
+        self
+    }
+
+    /// Raise a new exception like [`Exn::raise`], marking the new frame as transparent.
+    ///
+    /// Use this for pass-through layers, e.g., generic adapters or middleware, whose error adds
+    /// no meaningful message. Reports created with
+    /// [`with_transparent_frames(false)`](crate::report::Compact::with_transparent_frames) hide
+    /// transparent frames, while they can still be found by downcasting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::ErrorExt;
+    /// use exn::report::Compact;
+    ///
+    /// let exn = std::fmt::Error
+    ///     .raise()
+    ///     .raise_transparent(std::io::Error::other("middleware failed"))
+    ///     .raise(std::io::Error::other("failed to respond"));
+    ///
+    /// let report = Compact::new(exn.frame())
+    ///     .with_locations(false)
+    ///     .with_transparent_frames(false);
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "failed to respond\n|\n|-> an error occurred when formatting an argument"
+    /// );
+    /// ```
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise_transparent<T: Error + Send + Sync + 'static>(self, err: T) -> Exn<T> {
+        let mut new_exn = self.raise_at(err, crate::location::caller());
+        new_exn.frame.transparent = true;
+        new_exn
+    }
+
+    /// Raise a new exception like [`Exn::raise`], tagging the new frame with `tag`.
+    ///
+    /// See [`Exn::with_tag`] for more information.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise_tagged<T: Error + Send + Sync + 'static>(
+        self,
+        err: T,
+        tag: &'static str,
+    ) -> Exn<T> {
+        self.raise_at(err, crate::location::caller()).with_tag(tag)
+    }
+
+    /// Raise a new exception like [`Exn::raise`], unless the new error is displayed the same as
+    /// the current topmost error.
+    ///
+    /// In that case, no new layer is added: the new error replaces the current topmost frame, whose
+    /// children become the children of the new frame. This defuses error layers that merely echo
+    /// the message of their child.
+    ///
+    /// Note that the replaced error is dropped, so it can no longer be found by downcasting.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise_dedup<T: Error + Send + Sync + 'static>(self, err: T) -> Exn<T> {
+        if err.to_string() != self.frame.error.to_string() {
+            return self.raise(err);
+        }
+
+        let mut new_exn = Exn::unobserved(err, crate::location::caller());
+        new_exn.frame.extend_children(self.frame.children);
+        observer::notify_raise(&new_exn.frame);
         new_exn
     }
 
+    /// Raise a new exception of the same type like [`Exn::raise`], only if `predicate` returns
+    /// `true` for the current topmost frame; otherwise, return the exception unchanged.
+    ///
+    /// Use this to add boundary context only in specific situations, e.g., to avoid wrapping an
+    /// error that already says everything. The new error is created by `err` only when raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum ApiError {
+    ///     Unauthorized,
+    ///     Internal,
+    /// }
+    ///
+    /// impl core::fmt::Display for ApiError {
+    ///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    ///         write!(f, "{self:?}")
+    ///     }
+    /// }
+    ///
+    /// impl core::error::Error for ApiError {}
+    ///
+    /// fn is_unauthorized(frame: &exn::Frame) -> bool {
+    ///     frame.error().downcast_ref() == Some(&ApiError::Unauthorized)
+    /// }
+    ///
+    /// let exn = Exn::new(ApiError::Unauthorized);
+    /// let exn = exn.raise_when(|frame| !is_unauthorized(frame), || ApiError::Internal);
+    /// assert_eq!(*exn, ApiError::Unauthorized);
+    /// assert!(exn.frame().children().is_empty());
+    /// ```
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise_when<P, F>(self, predicate: P, err: F) -> Self
+    where
+        P: FnOnce(&Frame) -> bool,
+        F: FnOnce() -> E,
+    {
+        if predicate(&self.frame) {
+            self.raise_at(err(), crate::location::caller())
+        } else {
+            self
+        }
+    }
+
+    /// Append `other` to the children of the topmost frame, as a sibling of the existing ones.
+    ///
+    /// Use this to attach independent failures discovered after the exception was created, e.g.,
+    /// cleanup failures in a `finally` path, without raising a new exception.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// let mut exn = Exn::new(std::io::Error::other("failed to write the file"));
+    /// // ... then cleaning up fails as well
+    /// exn.adopt(Exn::new(std::io::Error::other(
+    ///     "failed to remove the temporary file",
+    /// )));
+    /// assert_eq!(exn.frame().children().len(), 1);
+    /// ```
+    ///
+    /// The exception is dropped if it would bring the tree over the
+    /// [frame limit](crate::set_frame_limit).
+    pub fn adopt<T: Error + Send + Sync + 'static>(&mut self, other: Exn<T>) {
+        match self.frame.accepts(&other.frame) {
+            Ok(()) => {
+                self.frame.frame_count += other.frame.frame_count;
+                self.frame.children.push(other.frame);
+            }
+            Err(dropped) => {
+                let location = other.frame.location();
+                self.frame.frame_count +=
+                    record_dropped(&mut self.frame.children, dropped, location);
+            }
+        }
+    }
+
+    /// Record `other` as suppressed by this exception, like suppressed exceptions in Java.
+    ///
+    /// Suppressed exceptions are kept apart from the children of the topmost frame, so that
+    /// failures during cleanup do not masquerade as causes: they are not visited by
+    /// [`Exn::frames`] and are rendered with a `suppressed: ` label after the children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// let mut exn = Exn::new(std::io::Error::other("failed to write the file"));
+    /// exn.suppress(Exn::new(std::io::Error::other("failed to close the file")));
+    /// assert!(exn.frame().children().is_empty());
+    /// assert_eq!(exn.frame().suppressed().len(), 1);
+    /// ```
+    ///
+    /// The exception is dropped if it would bring the tree over the
+    /// [frame limit](crate::set_frame_limit).
+    pub fn suppress<T: Error + Send + Sync + 'static>(&mut self, other: Exn<T>) {
+        match self.frame.accepts(&other.frame) {
+            Ok(()) => {
+                self.frame.frame_count += other.frame.frame_count;
+                self.frame.suppressed.push(*other.frame);
+            }
+            Err(dropped) => {
+                let location = other.frame.location();
+                self.frame.frame_count +=
+                    record_dropped(&mut self.frame.suppressed, dropped, location);
+            }
+        }
+    }
+
     /// Return the underlying exception frame.
     pub fn frame(&self) -> &Frame {
         &self.frame
     }
 
+    /// Return the deepest error found by following the [primary child](Frame::primary_child) of
+    /// each frame.
+    ///
+    /// See [`Frame::root_cause`] for more information.
+    pub fn root_cause(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.frame.root_cause()
+    }
+
+    /// Return an iterator over the frames reached by following the
+    /// [primary child](Frame::primary_child) of each frame, starting with the topmost frame.
+    ///
+    /// See [`Frame::primary_chain`] for more information.
+    pub fn primary_chain(&self) -> PrimaryChain<'_> {
+        self.frame.primary_chain()
+    }
+
+    /// Return an iterator over all frames in the exception tree, in depth-first pre-order.
+    ///
+    /// See [`Frame::frames`] for more information.
+    pub fn frames(&self) -> Frames<'_> {
+        self.frame.frames()
+    }
+
+    /// Return an iterator over all frames in the exception tree whose location's file path
+    /// contains `file`.
+    ///
+    /// See [`Frame::frames_at`] for more information.
+    pub fn frames_at<'a>(&'a self, file: &'a str) -> impl Iterator<Item = &'a Frame> {
+        self.frame.frames_at(file)
+    }
+
     /// Extract the top-level error using move semantics
     pub fn into_error(self) -> E {
         *self.frame.error.downcast().expect("error type must match")
     }
+
+    /// Split the exception into its top-level error and the tree of its remaining child frames.
+    ///
+    /// Use this at boundaries that convert the top-level error into another type: reattaching
+    /// the tree with [`Exn::from_parts`] keeps the children without raising an extra frame.
+    ///
+    /// The frames walked from the [source chain](Error::source) of the top-level error are
+    /// dropped, since they are derived from the returned error. The location, severity,
+    /// attachments and suppressed exceptions of the topmost frame are dropped as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// let exn = Exn::new(std::io::Error::other("connection reset")).raise(std::fmt::Error);
+    ///
+    /// let (error, tree) = exn.into_parts();
+    /// let exn = Exn::from_parts(std::io::Error::other(error), tree);
+    /// assert_eq!(exn.frame().children().len(), 1);
+    /// ```
+    pub fn into_parts(self) -> (E, FrameTree) {
+        let frame = *self.frame;
+        let error = *frame.error.downcast().expect("error type must match");
+        let children = match frame.children {
+            Children::One(child) if child.origin == FrameOrigin::SourceChain => Children::None,
+            Children::Many(children) => Children::from_frames(
+                children
+                    .into_iter()
+                    .filter(|child| child.origin != FrameOrigin::SourceChain)
+                    .collect(),
+            ),
+            children => children,
+        };
+        (error, FrameTree { children })
+    }
+
+    /// Split the child frames of the exception into those whose subtree contains an error of
+    /// type `T`, and the others.
+    ///
+    /// Use this to handle subsets of an aggregated failure differently, e.g., to retry only the
+    /// failed items of a batch that timed out. The top-level error is dropped, along with the
+    /// frames walked from its source chain; split the exception with [`Exn::into_parts`] and
+    /// [`FrameTree::partition`] to keep it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    ///
+    /// let failures = [
+    ///     Exn::new(std::io::Error::other("item 1 timed out")),
+    ///     Exn::new(std::fmt::Error).raise(std::io::Error::other("item 2 is malformed")),
+    ///     Exn::new(std::io::Error::other("item 3 timed out")),
+    /// ];
+    /// let exn = Exn::raise_all(std::io::Error::other("batch failed"), failures);
+    ///
+    /// let (malformed, others) = exn.partition::<std::fmt::Error>();
+    /// assert_eq!(malformed.children()[0].to_string(), "item 2 is malformed");
+    /// assert_eq!(others.children().len(), 2);
+    /// ```
+    pub fn partition<T: Error + 'static>(self) -> (FrameTree, FrameTree) {
+        let (_, tree) = self.into_parts();
+        tree.partition::<T>()
+    }
+
+    /// Create a new exception with the given error, reattaching the child frames split by
+    /// [`Exn::into_parts`].
+    ///
+    /// Like [`Exn::new`], this walks the source chain of `error`; its frames come before the
+    /// reattached ones.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn from_parts(error: E, tree: FrameTree) -> Self {
+        let mut new_exn = Self::unobserved(error, crate::location::caller());
+        new_exn.frame.extend_children(tree.children);
+        observer::notify_raise(&new_exn.frame);
+        new_exn
+    }
+
+    /// Set the path of the function where the topmost frame was created, typically captured with
+    /// [`here!`](crate::here).
+    ///
+    /// [`bail!`](crate::bail) and [`ensure!`](crate::ensure) set it on the exceptions they
+    /// create. Reports created with
+    /// [`with_functions(true)`](crate::report::Compact::with_functions) write it after the
+    /// location, which helps to interpret locations in generated or macro-heavy code.
+    pub fn with_function(mut self, function: &'static str) -> Self {
+        self.frame.function = Some(function);
+        self
+    }
+
+    /// Tag the topmost frame with `tag`, e.g., `"user"` for frames actionable by end users.
+    ///
+    /// Reports created with [`with_included_tags`](crate::report::Compact::with_included_tags)
+    /// or [`with_excluded_tags`](crate::report::Compact::with_excluded_tags) write only the
+    /// frames with the tags of interest, e.g., to show user-actionable frames to end users while
+    /// keeping all frames in debug logs. Tags of an enum can be set with a method returning the
+    /// name of each variant as a `&'static str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    /// use exn::report::Compact;
+    ///
+    /// let exn = Exn::new(std::io::Error::other("connection reset"))
+    ///     .with_tag("internal")
+    ///     .raise(std::io::Error::other("failed to fetch the page"));
+    /// assert!(exn.frame().children()[0].has_tag("internal"));
+    ///
+    /// let report = Compact::new(exn.frame())
+    ///     .with_locations(false)
+    ///     .with_excluded_tags(["internal"]);
+    /// assert_eq!(report.to_string(), "failed to fetch the page");
+    /// ```
+    pub fn with_tag(mut self, tag: &'static str) -> Self {
+        if !self.frame.tags.contains(&tag) {
+            self.frame.tags.push(tag);
+        }
+        self
+    }
+
+    /// Set the location of the topmost frame, e.g., a line of the configuration file an
+    /// interpreter failed to load.
+    ///
+    /// Reports print this location in place of the Rust source code location where the frame was
+    /// created, which [`Frame::location`] keeps returning. See [`FrameLocation`] for more
+    /// information.
+    pub fn with_location(mut self, location: impl Into<FrameLocation>) -> Self {
+        self.frame.set_location(location);
+        self
+    }
+
+    /// Set the severity of the topmost frame.
+    ///
+    /// Frames are created with [`Severity::Error`]; use this to fold non-fatal failures into the
+    /// same tree as the fatal error.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.frame.severity = severity;
+        self
+    }
+
+    /// Mark the error message of the topmost frame as sensitive.
+    ///
+    /// Reports created with [`with_redaction(true)`](crate::report::Compact::with_redaction)
+    /// replace sensitive messages, and the attachments of sensitive frames, with `«redacted»`, as
+    /// do the integrations, through [`Frame::redacted_message`]. The frames walked from the
+    /// [source chain](Error::source) of the topmost error are marked as well, since they are
+    /// derived from the same error.
+    pub fn mark_sensitive(mut self) -> Self {
+        fn mark(frame: &mut Frame) {
+            frame.sensitive = true;
+            for child in frame.children.iter_mut() {
+                if child.origin == FrameOrigin::SourceChain {
+                    mark(child);
+                }
+            }
+        }
+        mark(&mut self.frame);
+        self
+    }
+
+    /// Return the highest severity among all frames in the exception tree.
+    pub fn max_severity(&self) -> Severity {
+        fn walk(frame: &Frame) -> Severity {
+            frame
+                .children
+                .iter()
+                .map(walk)
+                .fold(frame.severity, Severity::max)
+        }
+        walk(&self.frame)
+    }
+
+    /// Create an exception from a frame constructed with [`Frame::new`], whose error must be of
+    /// type `E`.
+    ///
+    /// Returns the frame back if its error is of another type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    /// use exn::Frame;
+    ///
+    /// let location = std::panic::Location::caller();
+    /// let mut frame = Frame::new(std::fmt::Error, location);
+    /// frame.push_child(Frame::new(
+    ///     std::io::Error::other("connection reset"),
+    ///     location,
+    /// ));
+    ///
+    /// let exn = Exn::<std::fmt::Error>::from_frame(frame).unwrap();
+    /// assert_eq!(exn.frame().children().len(), 1);
+    /// ```
+    pub fn from_frame(frame: Frame) -> Result<Self, Box<Frame>> {
+        let frame = Box::new(frame);
+        if !frame.error.is::<E>() {
+            return Err(frame);
+        }
+        let new_exn = Self::from_boxed_frame(frame);
+        observer::notify_new(&new_exn.frame);
+        Ok(new_exn)
+    }
+
+    /// Return the topmost frame, dropping the type information of the top-level error.
+    pub(crate) fn into_boxed_frame(self) -> Box<Frame> {
+        self.frame
+    }
+
+    /// Create an exception from its topmost frame, whose error must be of type `E`.
+    pub(crate) fn from_boxed_frame(frame: Box<Frame>) -> Self {
+        debug_assert!(frame.error.is::<E>());
+        Self {
+            frame,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Convert `exn` into an exception of type `E`, as is if its error is of type `E` already, or
+    /// else by raising the error `E` creates with [`RaiseFrom`] from the top-level error of `exn`,
+    /// with `exn` as its only child.
+    ///
+    /// The top-level error of `exn` is taken back from the child of the raised exception holding
+    /// it; if the [`RaiseFrom`] implementation keeps no such child, the raised exception is
+    /// returned as is.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub(crate) fn raise_from_exn<F>(exn: Exn<F>) -> Self
+    where
+        E: RaiseFrom<F>,
+        F: Error + Send + Sync + 'static,
+    {
+        if exn.frame.error.is::<E>() {
+            return Self::from_boxed_frame(exn.frame);
+        }
+        let location = crate::location::caller();
+        let mut frame = exn.frame;
+        let error = core::mem::replace(&mut frame.error, Box::new(fmt::Error));
+        let error = *error.downcast::<F>().expect("error type must match");
+        let mut raised = E::raise_from(error);
+        let Some(child) = raised
+            .frame
+            .children
+            .iter_mut()
+            .find(|child| child.error.is::<F>())
+        else {
+            return raised;
+        };
+        core::mem::swap(&mut frame.error, &mut child.error);
+        Exn::raise_frame_at(raised.into_error(), frame, location)
+    }
+
+    /// Create a new exception with the given error and `child` as its only child, located at
+    /// `location`.
+    pub(crate) fn raise_frame_at(
+        error: E,
+        child: Box<Frame>,
+        location: &'static Location<'static>,
+    ) -> Self {
+        let mut new_exn = Exn::unobserved(error, location);
+        new_exn.frame.extend_children(Children::One(child));
+        observer::notify_raise(&new_exn.frame);
+        new_exn
+    }
 }
 
 impl<E> Deref for Exn<E>
@@ -144,39 +820,615 @@ where
     }
 }
 
+/// An error walked from the source chain of a raised error, degenerated to its string
+/// representation.
+#[derive(Clone)]
+struct SourceError(String);
+
+impl fmt::Debug for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for SourceError {}
+
 /// A frame in the exception tree.
 pub struct Frame {
     /// The error that occurred at this frame.
     error: Box<dyn Error + Send + Sync + 'static>,
     /// The source code location where this exception frame was created.
-    location: &'static Location<'static>,
+    location: StoredLocation,
+    /// The location of this exception frame in a non-Rust source, if set.
+    frame_location: Option<Box<FrameLocation>>,
+    /// The path of the function where this exception frame was created, if known.
+    function: Option<&'static str>,
+    /// The type name of the error that occurred at this frame, if known.
+    type_name: Option<&'static str>,
+    /// The severity of the error that occurred at this frame.
+    severity: Severity,
+    /// How this exception frame was created.
+    origin: FrameOrigin,
+    /// Whether the error message at this frame must be redacted in reports for end users.
+    sensitive: bool,
+    /// Whether this frame may be hidden by reports, as it adds no meaningful message.
+    transparent: bool,
+    /// The index of the child that is the primary cause of the error at this frame, if not the
+    /// first one. Children are only ever appended to a frame, so the index stays valid.
+    primary: Option<usize>,
+    /// The tags of this frame, used to filter frames in reports.
+    tags: Vec<&'static str>,
+    /// Messages attached to this frame as lightweight context.
+    attachments: Vec<Attachment>,
+    /// Exceptions suppressed by the error at this frame, e.g., failures during cleanup.
+    suppressed: Vec<Frame>,
+    /// The number of frames in the tree rooted at this frame, including suppressed exceptions,
+    /// kept up to date so that the frame limit is checked in constant time.
+    frame_count: usize,
     /// Child exception frames that provide additional context or source errors.
-    children: Vec<Frame>,
+    children: Children,
+}
+
+/// The child frames of an exception, split from its top-level error by [`Exn::into_parts`].
+pub struct FrameTree {
+    children: Children,
+}
+
+impl FrameTree {
+    /// Return the child frames.
+    pub fn children(&self) -> &[Frame] {
+        &self.children
+    }
+
+    /// Return `true` if there are no child frames.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Split the child frames into those whose subtree contains an error of type `T`, and the
+    /// others.
+    ///
+    /// See [`Exn::partition`] for more information.
+    pub fn partition<T: Error + 'static>(self) -> (FrameTree, FrameTree) {
+        let (matching, others) = self
+            .children
+            .into_frames()
+            .into_iter()
+            .partition::<Vec<_>, _>(|child| child.frames().any(|frame| frame.error().is::<T>()));
+        let matching = FrameTree {
+            children: Children::from_frames(matching),
+        };
+        let others = FrameTree {
+            children: Children::from_frames(others),
+        };
+        (matching, others)
+    }
+}
+
+impl fmt::Debug for FrameTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.children.iter()).finish()
+    }
 }
 
 impl Frame {
+    /// Check whether `other` can be added to the tree rooted at this frame without exceeding the
+    /// frame limit, returning the number of frames to drop otherwise.
+    ///
+    /// Room is kept for the frames standing in for exceptions dropped by later additions, so that
+    /// they fit within the limit too.
+    fn accepts(&self, other: &Frame) -> Result<(), usize> {
+        let limit = frame_limit();
+        if limit == usize::MAX {
+            return Ok(());
+        }
+        let reserved = usize::from(!ends_with_dropped(&self.children))
+            + usize::from(!ends_with_dropped(&self.suppressed));
+        if self.frame_count.saturating_add(other.frame_count) + reserved <= limit {
+            Ok(())
+        } else {
+            Err(other.frame_count)
+        }
+    }
+
+    /// Append `children` to the children of this newly created frame.
+    ///
+    /// If one of `children` displays the same message as the source of the error at this frame,
+    /// the source error has been raised as an exception already, so the frames walked from the
+    /// source chain are dropped rather than reported twice.
+    fn extend_children(&mut self, children: Children) {
+        let source = self
+            .children
+            .first()
+            .filter(|child| child.origin == FrameOrigin::SourceChain);
+        if let Some(source) = source {
+            let message = source.error.to_string();
+            let is_duplicate = children.iter().any(|child| {
+                child.origin != FrameOrigin::SourceChain && child.error.to_string() == message
+            });
+            if is_duplicate {
+                self.children = Children::None;
+                self.primary = None;
+            }
+        }
+        self.children.extend(children);
+        self.frame_count = 1 + self.children.frame_count() + self.suppressed.frame_count();
+    }
+
+    /// Return the number of frames in the tree rooted at this frame, including suppressed
+    /// exceptions, in constant time.
+    pub(crate) fn cached_frame_count(&self) -> usize {
+        self.frame_count
+    }
+}
+
+/// Account for `count` frames dropped over the frame limit in the last frame of `frames`, if it
+/// stands in for dropped frames already, or in a new frame located at `location`.
+///
+/// Returns the number of frames added to `frames`.
+fn record_dropped<F>(frames: &mut F, count: usize, location: &'static Location<'static>) -> usize
+where
+    F: DerefMut<Target = [Frame]> + Extend<Frame>,
+{
+    if count == 0 {
+        return 0;
+    }
+    let last = frames
+        .last_mut()
+        .and_then(|frame| frame.error.downcast_mut::<FramesDropped>());
+    match last {
+        Some(dropped) => {
+            dropped.add(count);
+            0
+        }
+        None => {
+            let exn = Exn::unobserved(FramesDropped::new(count), location);
+            frames.extend([*exn.frame]);
+            1
+        }
+    }
+}
+
+/// Check whether the last frame of `frames` stands in for dropped frames.
+fn ends_with_dropped(frames: &[Frame]) -> bool {
+    frames
+        .last()
+        .is_some_and(|frame| frame.error.is::<FramesDropped>())
+}
+
+/// The number of frames in a list of sibling trees.
+trait FrameCount {
+    fn frame_count(&self) -> usize;
+}
+
+impl FrameCount for [Frame] {
+    fn frame_count(&self) -> usize {
+        self.iter().map(|frame| frame.frame_count).sum()
+    }
+}
+
+/// The children of a frame.
+///
+/// Most exception trees are linear chains, so a single child is kept in its own box instead of a
+/// vector. This way, raising a new exception moves the boxed frame of the current one as is.
+enum Children {
+    None,
+    One(Box<Frame>),
+    Many(Vec<Frame>),
+}
+
+impl Children {
+    fn from_boxed(mut children: impl Iterator<Item = Box<Frame>>) -> Self {
+        let Some(first) = children.next() else {
+            return Children::None;
+        };
+        let Some(second) = children.next() else {
+            return Children::One(first);
+        };
+        // match the growth of a vector, which would have allocated 4 frames at once
+        let capacity = children.size_hint().0.saturating_add(2).max(4);
+        let mut all = Vec::with_capacity(capacity);
+        all.push(*first);
+        all.push(*second);
+        all.extend(children.map(|child| *child));
+        Children::Many(all)
+    }
+
+    fn from_frames(mut children: Vec<Frame>) -> Self {
+        match children.len() {
+            0 => Children::None,
+            1 => Children::One(Box::new(children.pop().unwrap())),
+            _ => Children::Many(children),
+        }
+    }
+
+    fn into_frames(self) -> Vec<Frame> {
+        match self {
+            Children::None => Vec::new(),
+            Children::One(child) => vec![*child],
+            Children::Many(children) => children,
+        }
+    }
+
+    fn push(&mut self, child: Box<Frame>) {
+        *self = match core::mem::replace(self, Children::None) {
+            Children::None => Children::One(child),
+            Children::One(first) => Children::from_boxed([first, child].into_iter()),
+            Children::Many(mut children) => {
+                children.push(*child);
+                Children::Many(children)
+            }
+        };
+    }
+
+    fn extend(&mut self, other: Children) {
+        match (core::mem::replace(self, Children::None), other) {
+            (children, Children::None) | (Children::None, children) => *self = children,
+            (mut children, Children::One(child)) => {
+                children.push(child);
+                *self = children;
+            }
+            (Children::One(first), Children::Many(mut rest)) => {
+                rest.insert(0, *first);
+                *self = Children::Many(rest);
+            }
+            (Children::Many(mut all), Children::Many(rest)) => {
+                all.extend(rest);
+                *self = Children::Many(all);
+            }
+        }
+    }
+}
+
+impl Extend<Frame> for Children {
+    fn extend<I: IntoIterator<Item = Frame>>(&mut self, frames: I) {
+        for frame in frames {
+            self.push(Box::new(frame));
+        }
+    }
+}
+
+impl Deref for Children {
+    type Target = [Frame];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Children::None => &[],
+            Children::One(child) => slice::from_ref(child),
+            Children::Many(children) => children,
+        }
+    }
+}
+
+impl DerefMut for Children {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Children::None => &mut [],
+            Children::One(child) => slice::from_mut(child),
+            Children::Many(children) => children,
+        }
+    }
+}
+
+/// How an exception frame was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FrameOrigin {
+    /// The frame was raised explicitly, e.g., with [`Exn::new`] or [`Exn::raise`].
+    Raised,
+    /// The frame was created by walking the [source chain][Error::source] of a raised error.
+    ///
+    /// Such a frame shares the location of the frame whose error it was walked from.
+    SourceChain,
+}
+
+impl Frame {
+    /// Create a frame with the given error, located at `location`.
+    ///
+    /// This is for code that builds exception trees from other representations, e.g.,
+    /// deserializers, foreign function interfaces and test fixtures, where the location of the
+    /// caller is not the location of the error. Link frames with [`Frame::push_child`] and turn the
+    /// topmost one into an exception with [`Exn::from_frame`]. Prefer [`Exn::new`] and
+    /// [`Exn::raise`] otherwise.
+    ///
+    /// Like [`Exn::new`], this walks the source chain of `error`.
+    pub fn new<E: Error + Send + Sync + 'static>(
+        error: E,
+        location: &'static Location<'static>,
+    ) -> Self {
+        *Exn::unobserved(error, location).frame
+    }
+
+    /// Append `child` to the children of this frame.
+    ///
+    /// Like [`Exn::adopt`], the child is dropped if it would bring the tree over the
+    /// [frame limit](crate::set_frame_limit).
+    pub fn push_child(&mut self, child: Frame) {
+        match self.accepts(&child) {
+            Ok(()) => {
+                self.frame_count += child.frame_count;
+                self.children.push(Box::new(child));
+            }
+            Err(dropped) => {
+                self.frame_count += record_dropped(&mut self.children, dropped, child.location());
+            }
+        }
+    }
+
+    /// Set the location of this frame, as with [`Exn::with_location`].
+    pub fn set_location(&mut self, location: impl Into<FrameLocation>) {
+        self.frame_location = Some(Box::new(location.into()));
+    }
+
+    /// Attach a message to this frame, located at `location`.
+    pub fn push_attachment<M>(&mut self, message: M, location: &'static Location<'static>)
+    where
+        M: fmt::Display + Send + Sync + 'static,
+    {
+        self.attachments.push(Attachment::new_at(message, location));
+    }
+
     /// Return the error that occurred at this frame.
     pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
         &*self.error
     }
 
     /// Return the source code location where this exception frame was created.
+    ///
+    /// If locations are compiled out with `--cfg exn_no_location`, all frames report the same
+    /// fixed location inside this crate.
     pub fn location(&self) -> &'static Location<'static> {
-        self.location
+        self.location.get()
+    }
+
+    /// Return the location of this exception frame as printed by reports: the location set with
+    /// [`Exn::with_location`] if any, or the Rust source code location where it was created.
+    pub fn frame_location(&self) -> Cow<'_, FrameLocation> {
+        match &self.frame_location {
+            Some(location) => Cow::Borrowed(location),
+            None => Cow::Owned(FrameLocation::Rust(self.location())),
+        }
+    }
+
+    /// Return the location of this exception frame as printed by reports, or `None` if it was
+    /// not set with [`Exn::with_location`] and locations are compiled out with
+    /// `--cfg exn_no_location`.
+    ///
+    /// Renderers should use this rather than [`Frame::frame_location`] to decide whether to
+    /// write a location at all.
+    pub fn known_location(&self) -> Option<Cow<'_, FrameLocation>> {
+        match &self.frame_location {
+            Some(location) => Some(Cow::Borrowed(location)),
+            None => self
+                .location
+                .known()
+                .map(|location| Cow::Owned(FrameLocation::Rust(location))),
+        }
+    }
+
+    /// Return the path of the function where this exception frame was created, if known.
+    ///
+    /// See [`Exn::with_function`] for more information.
+    pub fn function(&self) -> Option<&'static str> {
+        self.function
+    }
+
+    /// Return the messages attached to this frame, in the order they were attached.
+    pub fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+
+    /// Return the type name of the error that occurred at this frame, as returned by
+    /// [`core::any::type_name`].
+    ///
+    /// Returns `None` for frames created by walking the [source chain][Error::source] of an error,
+    /// whose concrete types are erased.
+    pub fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    /// Return the severity of the error that occurred at this frame.
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Return how this exception frame was created.
+    pub fn origin(&self) -> FrameOrigin {
+        self.origin
+    }
+
+    /// Return whether the error message at this frame is sensitive.
+    ///
+    /// See [`Exn::mark_sensitive`] for more information.
+    pub fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    /// Return the tags of this frame, in the order they were added.
+    ///
+    /// See [`Exn::with_tag`] for more information.
+    pub fn tags(&self) -> &[&'static str] {
+        &self.tags
+    }
+
+    /// Return `true` if this frame is tagged with `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Return whether this frame is transparent.
+    ///
+    /// See [`Exn::raise_transparent`] for more information.
+    pub fn is_transparent(&self) -> bool {
+        self.transparent
+    }
+
+    /// Return a slice of the exceptions suppressed by the error at this frame.
+    ///
+    /// See [`Exn::suppress`] for more information.
+    pub fn suppressed(&self) -> &[Frame] {
+        &self.suppressed
     }
 
     /// Return a slice of the children of the exception.
     pub fn children(&self) -> &[Frame] {
         &self.children
     }
+
+    /// Return the child frame that is the primary cause of the error at this frame: the one marked
+    /// with [`Exn::raise_all_with_primary`], or else the first one.
+    pub fn primary_child(&self) -> Option<&Frame> {
+        let primary = self.primary.and_then(|primary| self.children.get(primary));
+        primary.or_else(|| self.children.first())
+    }
+
+    /// Return the deepest error found by following the [primary child](Frame::primary_child) of
+    /// each frame.
+    ///
+    /// This is the error of the last frame yielded by [`Frame::primary_chain`]. If this frame has
+    /// no children, its own error is returned.
+    pub fn root_cause(&self) -> &(dyn Error + Send + Sync + 'static) {
+        let mut frame = self;
+        while let Some(child) = frame.primary_child() {
+            frame = child;
+        }
+        frame.error()
+    }
+
+    /// Return an iterator over the frames reached by following the
+    /// [primary child](Frame::primary_child) of each frame, starting with this frame.
+    ///
+    /// This mirrors the [source chain][Error::source] of the frame: sibling children other than
+    /// the primary one are not visited.
+    pub fn primary_chain(&self) -> PrimaryChain<'_> {
+        PrimaryChain { next: Some(self) }
+    }
+
+    /// Return an iterator over this frame and all its descendants, in depth-first pre-order.
+    ///
+    /// The frame itself is yielded first, then the subtree of each child in order.
+    pub fn frames(&self) -> Frames<'_> {
+        Frames { stack: vec![self] }
+    }
+
+    /// Clone this frame and all its descendants, cloning each error with `clone_error`.
+    ///
+    /// Returns `None` if any error, except those walked from a source chain, cannot be cloned.
+    pub(crate) fn try_clone_with(
+        &self,
+        clone_error: &impl Fn(&(dyn Error + 'static)) -> Option<Box<dyn Error + Send + Sync>>,
+    ) -> Option<Frame> {
+        let error = match self.error.downcast_ref::<SourceError>() {
+            Some(source) => Box::new(source.clone()),
+            None => clone_error(&*self.error)?,
+        };
+        let suppressed = self
+            .suppressed
+            .iter()
+            .map(|frame| frame.try_clone_with(clone_error))
+            .collect::<Option<Vec<_>>>()?;
+        let children = self
+            .children
+            .iter()
+            .map(|child| child.try_clone_with(clone_error))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Frame {
+            error,
+            location: self.location,
+            frame_location: self.frame_location.clone(),
+            function: self.function,
+            type_name: self.type_name,
+            severity: self.severity,
+            origin: self.origin,
+            sensitive: self.sensitive,
+            transparent: self.transparent,
+            primary: self.primary,
+            tags: self.tags.clone(),
+            attachments: self.attachments.iter().map(Attachment::to_owned).collect(),
+            suppressed,
+            frame_count: self.frame_count,
+            children: Children::from_frames(children),
+        })
+    }
+
+    /// Return an iterator over this frame and all its descendants whose location's file path
+    /// contains `file`, in depth-first pre-order.
+    ///
+    /// This is useful to attribute failures to a module or crate, e.g., `frames_at("crates/db/")`.
+    pub fn frames_at<'a>(&'a self, file: &'a str) -> impl Iterator<Item = &'a Frame> {
+        self.frames()
+            .filter(move |frame| frame.location().file().contains(file))
+    }
+}
+
+/// An iterator over the primary chain of an exception tree.
+///
+/// This struct is created by [`Exn::primary_chain`] and [`Frame::primary_chain`].
+#[derive(Debug, Clone)]
+pub struct PrimaryChain<'a> {
+    next: Option<&'a Frame>,
+}
+
+impl<'a> Iterator for PrimaryChain<'a> {
+    type Item = &'a Frame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.next?;
+        self.next = frame.primary_child();
+        Some(frame)
+    }
+}
+
+impl core::iter::FusedIterator for PrimaryChain<'_> {}
+
+/// An iterator over all frames of an exception tree, in depth-first pre-order.
+///
+/// This struct is created by [`Exn::frames`] and [`Frame::frames`].
+#[derive(Debug, Clone)]
+pub struct Frames<'a> {
+    stack: Vec<&'a Frame>,
+}
+
+impl<'a> Iterator for Frames<'a> {
+    type Item = &'a Frame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.stack.pop()?;
+        self.stack.extend(frame.children.iter().rev());
+        Some(frame)
+    }
+}
+
+impl core::iter::FusedIterator for Frames<'_> {}
+
+impl<E: Error + Send + Sync + 'static> AsRef<Frame> for Exn<E> {
+    fn as_ref(&self) -> &Frame {
+        self.frame()
+    }
+}
+
+impl AsRef<Frame> for Frame {
+    fn as_ref(&self) -> &Frame {
+        self
+    }
 }
 
 impl Error for Frame {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        self.children
-            .first()
+        self.primary_child()
             .map(|child| child as &(dyn Error + 'static))
     }
+
+    #[cfg(exn_nightly)]
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        self.error.provide(request);
+    }
 }
 
 impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Box<dyn Error + 'static> {
@@ -0,0 +1,121 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+use crate::Frame;
+
+/// An error marking that an operation did not complete in time.
+///
+/// Raise it at the layer that enforces the deadline; [`Exn::is_timeout`] then finds it wherever it
+/// is in the exception tree.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeoutError;
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation timed out")
+    }
+}
+
+impl Error for TimeoutError {}
+
+/// An error marking that an operation was cancelled before it completed.
+///
+/// Raise it at the layer that observes the cancellation; [`Exn::is_cancelled`] then finds it
+/// wherever it is in the exception tree.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CancelledError;
+
+impl fmt::Display for CancelledError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation was cancelled")
+    }
+}
+
+impl Error for CancelledError {}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return `true` if any frame in the exception tree is a timeout.
+    ///
+    /// See [`Frame::is_timeout`] for more information.
+    pub fn is_timeout(&self) -> bool {
+        self.frame().is_timeout()
+    }
+
+    /// Return `true` if any frame in the exception tree is a cancellation.
+    ///
+    /// See [`Frame::is_cancelled`] for more information.
+    pub fn is_cancelled(&self) -> bool {
+        self.frame().is_cancelled()
+    }
+}
+
+impl Frame {
+    /// Return `true` if this frame or any of its descendants is a timeout.
+    ///
+    /// A frame is a timeout if its error is a [`TimeoutError`], or, with the `std` feature, an
+    /// [`std::io::Error`] of kind [`TimedOut`](std::io::ErrorKind::TimedOut).
+    ///
+    /// Frames walked from the [source chain](Error::source) of an error are degenerated to their
+    /// string representation, so they are never recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::Exn;
+    /// use exn::TimeoutError;
+    ///
+    /// let io = std::io::Error::other("connection reset");
+    /// let exn = Exn::raise_all(io, [Exn::new(TimeoutError)]);
+    /// assert!(exn.is_timeout());
+    /// assert!(!exn.is_cancelled());
+    /// ```
+    pub fn is_timeout(&self) -> bool {
+        self.frames().any(|frame| {
+            let error = frame.error();
+            if error.is::<TimeoutError>() {
+                return true;
+            }
+            #[cfg(feature = "std")]
+            if let Some(io) = error.downcast_ref::<std::io::Error>() {
+                return io.kind() == std::io::ErrorKind::TimedOut;
+            }
+            false
+        })
+    }
+
+    /// Return `true` if this frame or any of its descendants is a cancellation.
+    ///
+    /// A frame is a cancellation if its error is a [`CancelledError`], or, with the `tokio`
+    /// feature, a [`TaskError::Cancelled`](crate::task::TaskError::Cancelled).
+    ///
+    /// Frames walked from the [source chain](Error::source) of an error are degenerated to their
+    /// string representation, so they are never recognized.
+    pub fn is_cancelled(&self) -> bool {
+        self.frames().any(|frame| {
+            let error = frame.error();
+            if error.is::<CancelledError>() {
+                return true;
+            }
+            #[cfg(feature = "tokio")]
+            if let Some(task) = error.downcast_ref::<crate::task::TaskError>() {
+                return *task == crate::task::TaskError::Cancelled;
+            }
+            false
+        })
+    }
+}
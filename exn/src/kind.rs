@@ -0,0 +1,82 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+
+use crate::Exn;
+
+/// An error classified by a kind, typically a flat enum that callers match on.
+///
+/// Libraries returning `Exn<E>` at their boundary usually map internal errors into an error `E`
+/// carrying such a kind. Implementing `Kinded` for `E` provides [`Exn::kind`] and
+/// [`Exn::has_kind`] on exceptions of that type.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::Kinded;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum LibErrorKind {
+///     NotFound,
+///     Internal,
+/// }
+///
+/// #[derive(Debug)]
+/// struct LibError(LibErrorKind);
+///
+/// impl core::fmt::Display for LibError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "{:?}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for LibError {}
+///
+/// impl Kinded for LibError {
+///     type Kind = LibErrorKind;
+///
+///     fn kind(&self) -> LibErrorKind {
+///         self.0
+///     }
+/// }
+///
+/// let exn = Exn::new(LibError(LibErrorKind::NotFound)).raise(LibError(LibErrorKind::Internal));
+/// assert_eq!(exn.kind(), LibErrorKind::Internal);
+/// assert!(exn.has_kind(LibErrorKind::NotFound));
+/// ```
+pub trait Kinded: Error + Send + Sync + 'static {
+    /// The kind of the errors of this type.
+    type Kind: Copy + Eq;
+
+    /// Return the kind of this error.
+    fn kind(&self) -> Self::Kind;
+}
+
+impl<E: Kinded> Exn<E> {
+    /// Return the kind of the top-level error.
+    pub fn kind(&self) -> E::Kind {
+        Kinded::kind(&**self)
+    }
+
+    /// Return `true` if any error of type `E` in the exception tree has the given kind.
+    ///
+    /// Frames are visited depth-first, starting with the topmost frame.
+    pub fn has_kind(&self, kind: E::Kind) -> bool {
+        self.frames()
+            .filter_map(|frame| frame.error().downcast_ref::<E>())
+            .any(|error| error.kind() == kind)
+    }
+}
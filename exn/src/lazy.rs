@@ -0,0 +1,100 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+use core::fmt;
+
+/// An error whose message is written by a closure, only when the error is formatted.
+///
+/// Building a message with `format!` allocates even if the error is handled and discarded without
+/// ever being printed. A `LazyMessage` captures the arguments instead, and formats them on demand.
+///
+/// This struct is usually created with the [`lazy_context!`] macro or
+/// [`ResultExt::or_raise_lazy`].
+///
+/// [`lazy_context!`]: crate::lazy_context!
+/// [`ResultExt::or_raise_lazy`]: crate::ResultExt::or_raise_lazy
+///
+/// # Examples
+///
+/// ```
+/// use exn::LazyMessage;
+///
+/// let key = 42;
+/// let message = LazyMessage::new(move |f| write!(f, "cache miss for key {key}"));
+/// assert_eq!(message.to_string(), "cache miss for key 42");
+/// ```
+pub struct LazyMessage<F> {
+    write: F,
+}
+
+impl<F> LazyMessage<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync + 'static,
+{
+    /// Create a new error whose message is written by `write`.
+    pub fn new(write: F) -> Self {
+        Self { write }
+    }
+}
+
+impl<F> fmt::Display for LazyMessage<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.write)(f)
+    }
+}
+
+impl<F> fmt::Debug for LazyMessage<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyMessage")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl<F> Error for LazyMessage<F> where F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result {}
+
+/// Creates a [`LazyMessage`] error from a format string, formatted only when the error is.
+///
+/// The arguments are captured by value, so clone them first if they are still needed afterwards.
+///
+/// [`LazyMessage`]: crate::LazyMessage
+///
+/// # Examples
+///
+/// ```
+/// use exn::ResultExt;
+/// use exn::lazy_context;
+///
+/// let path = "/path/to/file";
+/// let result = std::fs::read_to_string(path).or_raise(|| lazy_context!("failed to read {path}"));
+/// assert_eq!(
+///     result.unwrap_err().to_string(),
+///     "failed to read /path/to/file"
+/// );
+/// ```
+#[macro_export]
+macro_rules! lazy_context {
+    ($($arg:tt)+) => {
+        $crate::LazyMessage::new(move |f: &mut ::core::fmt::Formatter<'_>| {
+            ::core::write!(f, $($arg)+)
+        })
+    };
+}
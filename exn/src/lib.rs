@@ -71,25 +71,311 @@
 //! |
 //! |-> logic error: 0 == 1, at exn/src/lib.rs:40:5
 //! ```
+//!
+//! # Registering error types
+//!
+//! Frames store their errors as `dyn Error` trait objects, which can only be downcast to concrete
+//! types, so `exn` cannot tell on its own whether an error implements one of its capability
+//! traits, like [`ErrorCode`], [`Help`], [`Retryable`] or [`CloneableError`]. Error types opt in
+//! by being registered once, typically at startup, with the `register_*` function of the trait;
+//! frames whose error type is not registered behave as if it did not implement the trait.
+//!
+//! # Stability
+//!
+//! The API surface this crate commits to under semver is re-exported from the [`stable`] module.
+//! Experimental APIs live in the `unstable` module behind the `unstable` feature and may change or
+//! be removed in any release.
+//!
+//! # Stripping locations
+//!
+//! Security-sensitive deployments may not want source paths in their binaries. Building with
+//! `RUSTFLAGS="--cfg exn_no_location"` compiles out the capture and storage of locations: the
+//! public functions of this crate no longer take the location of their caller, frames and
+//! attachments no longer store one, and every report of this crate and of the integration crates
+//! omits them, unless set with [`Exn::with_location`]. [`Frame::location`] then returns one fixed
+//! location inside this crate, and [`Frame::known_location`] returns `None`. This is a cfg rather
+//! than a cargo feature because it changes behavior for every crate in the build, which features
+//! must not do. Panics and `#[track_caller]` functions of other crates still embed paths; see
+//! `--remap-path-prefix` and `-Zlocation-detail=none` for those.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(exn_nightly, feature(error_generic_member_access))]
 #![deny(missing_docs)]
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod attachment;
+mod boundary;
+mod budget;
+mod clone;
+mod code;
 mod debug;
+mod debug_format;
+#[cfg(feature = "std")]
+mod dedup;
+mod deferred;
 mod display;
+pub mod either;
+mod error;
 mod ext;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod ffi;
+mod finally;
+mod fingerprint;
+mod future;
+mod help;
 mod impls;
+mod interrupt;
+mod kind;
+mod lazy;
+mod limit;
+mod list;
+mod local;
+mod location;
 mod macros;
+mod message;
+mod observer;
 mod option;
+mod pattern;
+mod private;
+#[cfg(exn_nightly)]
+mod provide;
+#[cfg(feature = "pyo3")]
+mod python;
+mod raise_from;
+mod redact;
+mod registry;
+pub mod report;
 mod result;
+mod retry;
+#[cfg(feature = "std")]
+mod scope;
+mod severity;
+mod shared;
+#[cfg(feature = "diagnostics")]
+mod snippet;
+mod stats;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+pub mod task;
+mod taxonomy;
+mod template;
+pub mod testing;
+#[cfg(feature = "unstable")]
+mod tree;
+mod tuple;
+mod validate;
+mod warnings;
+#[cfg(feature = "wasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+pub mod wasm;
+
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use exn_macros::Validate;
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use exn_macros::context;
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use exn_macros::main;
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use exn_macros::test;
 
+pub use self::attachment::Attachment;
+pub use self::boundary::BoundaryMap;
+pub use self::budget::Budget;
+pub use self::budget::debug_budget;
+pub use self::budget::set_debug_budget;
+pub use self::clone::CloneableError;
+pub use self::clone::register_cloneable_error;
+pub use self::code::ErrorCode;
+pub use self::code::register_error_code;
+pub use self::debug_format::ParseReportFormatError;
+pub use self::debug_format::ReportFormat;
+pub use self::debug_format::debug_format;
+pub use self::debug_format::set_debug_format;
+#[cfg(feature = "std")]
+pub use self::dedup::DedupLogger;
+#[cfg(feature = "std")]
+pub use self::dedup::Deduped;
+#[cfg(feature = "std")]
+pub use self::dedup::dedup_logger;
+pub use self::deferred::Deferred;
+pub use self::error::ExnError;
 pub use self::ext::ErrorExt;
 pub use self::ext::Ok;
+#[cfg(feature = "std")]
+pub use self::finally::timed_scope;
+pub use self::finally::try_finally;
+pub use self::finally::try_scope;
+pub use self::fingerprint::Fingerprinter;
+pub use self::future::FutureExt;
+pub use self::help::Help;
+pub use self::help::register_help;
 pub use self::impls::Exn;
 pub use self::impls::Frame;
+pub use self::impls::FrameOrigin;
+pub use self::impls::FrameTree;
+pub use self::impls::Frames;
+pub use self::impls::PrimaryChain;
+pub use self::interrupt::CancelledError;
+pub use self::interrupt::TimeoutError;
+pub use self::kind::Kinded;
+pub use self::lazy::LazyMessage;
+pub use self::limit::FramesDropped;
+pub use self::limit::frame_limit;
+pub use self::limit::set_frame_limit;
+pub use self::list::ExnList;
+pub use self::local::LocalError;
+pub use self::local::LocalExn;
+pub use self::location::FrameLocation;
+pub use self::location::SourceLocation;
+pub use self::message::Message;
+pub use self::observer::ExnObserver;
+pub use self::observer::set_observer;
 pub use self::option::OptionExt;
+pub use self::pattern::Matcher;
+pub use self::pattern::Pattern;
+pub use self::raise_from::RaiseFrom;
+pub use self::redact::Redacted;
+pub use self::result::IntoExn;
 pub use self::result::Result;
 pub use self::result::ResultExt;
+pub use self::retry::Retryable;
+pub use self::retry::register_retryable;
+#[cfg(feature = "std")]
+pub use self::scope::context_scope;
+pub use self::severity::Severity;
+pub use self::shared::SharedExn;
+#[cfg(feature = "diagnostics")]
+pub use self::snippet::register_source;
+pub use self::stats::TreeStats;
+#[cfg(feature = "futures")]
+pub use self::stream::StreamExt;
+pub use self::taxonomy::ErrorType;
+pub use self::taxonomy::register_error_type;
+pub use self::template::CodedError;
+pub use self::template::ErrorArgs;
+pub use self::template::ErrorTemplate;
+pub use self::testing::diff;
+pub use self::tuple::ResultTupleExt;
+pub use self::validate::Field;
+pub use self::validate::Validate;
+pub use self::validate::ValidationError;
+pub use self::validate::Validator;
+pub use self::warnings::Warnings;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::private::*;
+}
+
+/// The API surface this crate commits to under semver.
+///
+/// Items re-exported here will only change in a semver-compatible way. Everything reachable from
+/// the crate root is also re-exported from this module, except for the macros, which are always
+/// available at the crate root (e.g., [`bail!`] and [`ensure!`]), and the public modules, such as
+/// [`report`] and [`testing`], which are covered by semver guarantees as a whole.
+pub mod stable {
+    pub use crate::Attachment;
+    pub use crate::BoundaryMap;
+    pub use crate::Budget;
+    pub use crate::CancelledError;
+    pub use crate::CloneableError;
+    pub use crate::CodedError;
+    #[cfg(feature = "std")]
+    pub use crate::DedupLogger;
+    #[cfg(feature = "std")]
+    pub use crate::Deduped;
+    pub use crate::Deferred;
+    pub use crate::ErrorArgs;
+    pub use crate::ErrorCode;
+    pub use crate::ErrorExt;
+    pub use crate::ErrorTemplate;
+    pub use crate::ErrorType;
+    pub use crate::Exn;
+    pub use crate::ExnError;
+    pub use crate::ExnList;
+    pub use crate::ExnObserver;
+    pub use crate::Field;
+    pub use crate::Fingerprinter;
+    pub use crate::Frame;
+    pub use crate::FrameLocation;
+    pub use crate::FrameOrigin;
+    pub use crate::FrameTree;
+    pub use crate::Frames;
+    pub use crate::FramesDropped;
+    pub use crate::FutureExt;
+    pub use crate::Help;
+    pub use crate::IntoExn;
+    pub use crate::Kinded;
+    pub use crate::LazyMessage;
+    pub use crate::LocalError;
+    pub use crate::LocalExn;
+    pub use crate::Matcher;
+    pub use crate::Message;
+    pub use crate::Ok;
+    pub use crate::OptionExt;
+    pub use crate::ParseReportFormatError;
+    pub use crate::Pattern;
+    pub use crate::PrimaryChain;
+    pub use crate::RaiseFrom;
+    pub use crate::Redacted;
+    pub use crate::ReportFormat;
+    pub use crate::Result;
+    pub use crate::ResultExt;
+    pub use crate::ResultTupleExt;
+    pub use crate::Retryable;
+    pub use crate::Severity;
+    pub use crate::SharedExn;
+    pub use crate::SourceLocation;
+    #[cfg(feature = "futures")]
+    pub use crate::StreamExt;
+    pub use crate::TimeoutError;
+    pub use crate::TreeStats;
+    pub use crate::Validate;
+    pub use crate::ValidationError;
+    pub use crate::Validator;
+    pub use crate::Warnings;
+    #[cfg(feature = "std")]
+    pub use crate::context_scope;
+    pub use crate::debug_budget;
+    pub use crate::debug_format;
+    #[cfg(feature = "std")]
+    pub use crate::dedup_logger;
+    pub use crate::diff;
+    pub use crate::frame_limit;
+    pub use crate::register_cloneable_error;
+    pub use crate::register_error_code;
+    pub use crate::register_error_type;
+    pub use crate::register_help;
+    pub use crate::register_retryable;
+    #[cfg(feature = "diagnostics")]
+    pub use crate::register_source;
+    pub use crate::set_debug_budget;
+    pub use crate::set_debug_format;
+    pub use crate::set_frame_limit;
+    pub use crate::set_observer;
+    #[cfg(feature = "std")]
+    pub use crate::timed_scope;
+    pub use crate::try_finally;
+    pub use crate::try_scope;
+}
+
+/// Experimental APIs that are still evolving.
+///
+/// Items in this module are **not** covered by semver guarantees and may change or be removed in
+/// any release.
+#[cfg(feature = "unstable")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+pub mod unstable {
+    pub use crate::tree::StringTree;
+}
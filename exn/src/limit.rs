@@ -0,0 +1,95 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+use core::fmt;
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+static FRAME_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Set the maximum number of frames an exception tree aggregates, to bound the memory held by
+/// exceptions.
+///
+/// [`Exn::raise_all`], [`Exn::adopt`] and [`Exn::suppress`] drop the exceptions that would bring
+/// the tree over the limit, and account for them in a [`FramesDropped`] frame instead. Raising an
+/// exception on a single one, e.g., with [`Exn::raise`], always keeps it, so that the root cause
+/// of a chain is never lost; the limit bounds the amplification of aggregated failures, e.g.,
+/// when every attempt of a tight retry loop is collected.
+///
+/// The [`FramesDropped`] frames count towards the limit: room is kept for one among the children
+/// and one among the suppressed exceptions of each aggregating frame.
+///
+/// The limit is unlimited by default.
+///
+/// [`Exn::raise_all`]: crate::Exn::raise_all
+/// [`Exn::adopt`]: crate::Exn::adopt
+/// [`Exn::suppress`]: crate::Exn::suppress
+/// [`Exn::raise`]: crate::Exn::raise
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+///
+/// exn::set_frame_limit(6);
+///
+/// let attempts = (0..10).map(|_| Exn::new(std::fmt::Error));
+/// let exn = Exn::raise_all(std::io::Error::other("all attempts failed"), attempts);
+/// # exn::set_frame_limit(usize::MAX);
+///
+/// let children = exn.frame().children();
+/// assert_eq!(children.len(), 4);
+/// assert_eq!(children[3].to_string(), "… 7 frames dropped");
+/// ```
+pub fn set_frame_limit(limit: usize) {
+    FRAME_LIMIT.store(limit, Ordering::Relaxed);
+}
+
+/// Return the maximum number of frames an exception tree aggregates.
+///
+/// See [`set_frame_limit`] for more information.
+pub fn frame_limit() -> usize {
+    FRAME_LIMIT.load(Ordering::Relaxed)
+}
+
+/// An error standing in for the frames dropped from an exception tree over the
+/// [frame limit](set_frame_limit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FramesDropped {
+    count: usize,
+}
+
+impl FramesDropped {
+    pub(crate) fn new(count: usize) -> Self {
+        Self { count }
+    }
+
+    pub(crate) fn add(&mut self, count: usize) {
+        self.count = self.count.saturating_add(count);
+    }
+
+    /// Return the number of frames dropped.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl fmt::Display for FramesDropped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "… {} frames dropped", self.count)
+    }
+}
+
+impl Error for FramesDropped {}
@@ -0,0 +1,155 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::slice;
+
+use crate::Exn;
+use crate::IntoExn;
+
+/// A list of sibling exceptions, to be raised together under a parent error.
+///
+/// `ExnList` can be collected from an iterator of exceptions and finished with
+/// [`ExnList::raise`], which is a shorthand for [`Exn::raise_all`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::ExnList;
+///
+/// #[derive(Debug)]
+/// struct Error(String);
+///
+/// impl core::fmt::Display for Error {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for Error {}
+///
+/// fn parse(input: &str) -> exn::Result<u32, Error> {
+///     input
+///         .parse()
+///         .map_err(|_| Exn::new(Error(format!("invalid number: {input}"))))
+/// }
+///
+/// let exn = ["1", "two", "three"]
+///     .into_iter()
+///     .map(parse)
+///     .filter_map(Result::err)
+///     .collect::<ExnList<_>>()
+///     .raise(Error("failed to parse numbers".to_string()));
+///
+/// assert_eq!(exn.frame().children().len(), 2);
+/// ```
+pub struct ExnList<E: Error + Send + Sync + 'static> {
+    exns: Vec<Exn<E>>,
+}
+
+impl<E: Error + Send + Sync + 'static> ExnList<E> {
+    /// Create an empty list.
+    pub fn new() -> Self {
+        Self { exns: vec![] }
+    }
+
+    /// Push an exception into the list.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn push(&mut self, exn: impl IntoExn<Error = E>) {
+        self.exns.push(exn.into_exn());
+    }
+
+    /// Return the number of exceptions in the list.
+    pub fn len(&self) -> usize {
+        self.exns.len()
+    }
+
+    /// Return `true` if the list contains no exception.
+    pub fn is_empty(&self) -> bool {
+        self.exns.is_empty()
+    }
+
+    /// Return an iterator over the exceptions in the list.
+    pub fn iter(&self) -> slice::Iter<'_, Exn<E>> {
+        self.exns.iter()
+    }
+
+    /// Raise a new exception with the exceptions in the list as its children.
+    ///
+    /// The new exception is raised even if the list is empty; check [`ExnList::is_empty`] first
+    /// if that is not desired.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise<T: Error + Send + Sync + 'static>(self, err: T) -> Exn<T> {
+        Exn::raise_all(err, self.exns)
+    }
+
+    /// Extract the exceptions in the list.
+    pub fn into_vec(self) -> Vec<Exn<E>> {
+        self.exns
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Default for ExnList<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> fmt::Debug for ExnList<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.exns).finish()
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Vec<Exn<E>>> for ExnList<E> {
+    fn from(exns: Vec<Exn<E>>) -> Self {
+        Self { exns }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Extend<Exn<E>> for ExnList<E> {
+    fn extend<I: IntoIterator<Item = Exn<E>>>(&mut self, iter: I) {
+        self.exns.extend(iter);
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> FromIterator<Exn<E>> for ExnList<E> {
+    fn from_iter<I: IntoIterator<Item = Exn<E>>>(iter: I) -> Self {
+        Self {
+            exns: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> IntoIterator for ExnList<E> {
+    type Item = Exn<E>;
+    type IntoIter = vec::IntoIter<Exn<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.exns.into_iter()
+    }
+}
+
+impl<'a, E: Error + Send + Sync + 'static> IntoIterator for &'a ExnList<E> {
+    type Item = &'a Exn<E>;
+    type IntoIter = slice::Iter<'a, Exn<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.exns.iter()
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::Exn;
+use crate::location::StoredLocation;
+use crate::observer;
+
+/// An exception tree whose errors need not be `Send` or `Sync`.
+///
+/// [`Exn`] requires its errors to be `Send + Sync`, so that exceptions can cross threads and
+/// `await` points. Single-threaded code, e.g., GUI or wasm code, may have errors holding `Rc` or
+/// raw pointers instead; `LocalExn` records them in the same way, and [`into_send`] converts the
+/// tree into an [`Exn`] at the boundary of the single-threaded code.
+///
+/// `LocalExn` is deliberately minimal; convert it with [`into_send`] to use the rest of the API.
+///
+/// [`into_send`]: LocalExn::into_send
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use exn::LocalExn;
+///
+/// #[derive(Debug)]
+/// struct WidgetError(Rc<str>);
+///
+/// impl core::fmt::Display for WidgetError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "failed to render widget {}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for WidgetError {}
+///
+/// let exn = LocalExn::new(WidgetError(Rc::from("sidebar"))).attach(Rc::new(42));
+/// assert_eq!(exn.0.as_ref(), "sidebar");
+///
+/// let exn = exn.into_send();
+/// assert_eq!(exn.to_string(), "failed to render widget sidebar");
+/// assert_eq!(exn.frame().attachments()[0].to_string(), "42");
+/// ```
+pub struct LocalExn<E: Error + 'static> {
+    frame: Box<LocalFrame>,
+    phantom: PhantomData<E>,
+}
+
+/// A frame of a [`LocalExn`].
+struct LocalFrame {
+    error: Box<dyn Error>,
+    location: StoredLocation,
+    attachments: Vec<(Box<dyn fmt::Display>, StoredLocation)>,
+    children: Vec<LocalFrame>,
+}
+
+impl<E: Error + 'static> LocalExn<E> {
+    /// Create a new exception with the given error.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn new(error: E) -> Self {
+        Self::from_frame(LocalFrame {
+            error: Box::new(error),
+            location: StoredLocation::new(crate::location::caller()),
+            attachments: Vec::new(),
+            children: Vec::new(),
+        })
+    }
+
+    /// Create a new exception with the given error and its children.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise_all<T, I>(error: E, children: I) -> Self
+    where
+        T: Error + 'static,
+        I: IntoIterator,
+        I::Item: Into<LocalExn<T>>,
+    {
+        Self::from_frame(LocalFrame {
+            error: Box::new(error),
+            location: StoredLocation::new(crate::location::caller()),
+            attachments: Vec::new(),
+            children: children.into_iter().map(|exn| *exn.into().frame).collect(),
+        })
+    }
+
+    /// Raise a new exception; this will make the current exception a child of the new one.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise<T: Error + 'static>(self, err: T) -> LocalExn<T> {
+        LocalExn::from_frame(LocalFrame {
+            error: Box::new(err),
+            location: StoredLocation::new(crate::location::caller()),
+            attachments: Vec::new(),
+            children: alloc::vec![*self.frame],
+        })
+    }
+
+    /// Attach a message to the topmost frame as lightweight context.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn attach<M: fmt::Display + 'static>(mut self, message: M) -> Self {
+        let attachment = (
+            Box::new(message) as Box<dyn fmt::Display>,
+            StoredLocation::new(crate::location::caller()),
+        );
+        self.frame.attachments.push(attachment);
+        self
+    }
+
+    /// Convert this exception tree into an [`Exn`], which can be sent across threads.
+    ///
+    /// Every error is degenerated to a [`LocalError`] holding its string representation, and so
+    /// are the errors of its [source chain](Error::source). Locations are kept, and attachments
+    /// are degenerated to strings as well.
+    pub fn into_send(self) -> Exn<LocalError> {
+        let exn = to_send(&self.frame);
+        observer::notify_new(exn.frame());
+        exn
+    }
+
+    fn from_frame(frame: LocalFrame) -> Self {
+        Self {
+            frame: Box::new(frame),
+            phantom: PhantomData,
+        }
+    }
+}
+
+fn to_send(frame: &LocalFrame) -> Exn<LocalError> {
+    let mut exn = Exn::unobserved(LocalError::new(&*frame.error), frame.location.get());
+    for (message, location) in &frame.attachments {
+        exn = exn.attach_at(message.to_string(), location.get());
+    }
+    for child in &frame.children {
+        exn.adopt(to_send(child));
+    }
+    exn
+}
+
+impl<E: Error + 'static> Deref for LocalExn<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        self.frame
+            .error
+            .downcast_ref()
+            .expect("error type must match")
+    }
+}
+
+impl<E: Error + 'static> From<E> for LocalExn<E> {
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn from(error: E) -> Self {
+        LocalExn::new(error)
+    }
+}
+
+impl<E: Error + 'static> fmt::Debug for LocalExn<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&to_send(&self.frame), f)
+    }
+}
+
+impl<E: Error + 'static> fmt::Display for LocalExn<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.frame.error, f)
+    }
+}
+
+/// An error degenerated to its string representation by [`LocalExn::into_send`].
+///
+/// The errors of its [source chain](Error::source) are degenerated as well.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocalError {
+    message: String,
+    source: Option<Box<LocalError>>,
+}
+
+impl LocalError {
+    fn new(error: &dyn Error) -> Self {
+        Self {
+            message: error.to_string(),
+            source: error
+                .source()
+                .map(|source| Box::new(LocalError::new(source))),
+        }
+    }
+
+    /// Return the string representation of the original error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for LocalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for LocalError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn Error + 'static))
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt;
+use core::panic::Location;
+
+/// A source code location, as rendered by reports.
+///
+/// File paths are rendered with `/` as separator on every platform, so that renderings of the
+/// same exception tree compare equal across platforms. Write locations with this type in custom
+/// renderers to stay consistent with the built-in ones.
+///
+/// # Examples
+///
+/// ```
+/// use exn::SourceLocation;
+///
+/// let location = SourceLocation::new(r"src\main.rs", 17, 3);
+/// assert_eq!(location.to_string(), "src/main.rs:17:3");
+///
+/// let exn = exn::Exn::new(std::fmt::Error);
+/// eprintln!("raised at {}", SourceLocation::from(exn.frame().location()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    file: &'static str,
+    line: u32,
+    column: u32,
+}
+
+impl SourceLocation {
+    /// Create a source location in `file` at the given line and column.
+    pub const fn new(file: &'static str, line: u32, column: u32) -> Self {
+        Self { file, line, column }
+    }
+
+    /// Strip the absolute prefix of the file path up to the package directory.
+    ///
+    /// Files of the workspace being built have paths relative to the workspace root already,
+    /// but files of dependencies have absolute paths, e.g.,
+    /// `/home/user/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/tokio-1.47.1/src/lib.rs`.
+    /// Such paths are shortened to start at the package directory, the parent of their last
+    /// `src` directory, e.g., `tokio-1.47.1/src/lib.rs`. Relative paths, and absolute paths
+    /// without a `src` directory, are kept as is.
+    pub fn strip_workspace_prefix(mut self) -> Self {
+        let file = self.file;
+        let is_absolute =
+            file.starts_with(['/', '\\']) || matches!(file.get(1..3), Some(":\\" | ":/"));
+        if !is_absolute {
+            return self;
+        }
+
+        let mut separators = file.rmatch_indices(['/', '\\']).map(|(i, _)| i);
+        let Some(mut end) = separators.next() else {
+            return self;
+        };
+        while let Some(start) = separators.next() {
+            if &file[start + 1..end] == "src" {
+                let package = separators.next().map_or(0, |i| i + 1);
+                self.file = &file[package..];
+                break;
+            }
+            end = start;
+        }
+        self
+    }
+
+    /// Return the file path, with `/` as separator.
+    pub fn file(&self) -> Cow<'static, str> {
+        if self.file.contains('\\') {
+            Cow::Owned(self.file.replace('\\', "/"))
+        } else {
+            Cow::Borrowed(self.file)
+        }
+    }
+
+    /// Return the line number.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Return the column number.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl From<&'static Location<'static>> for SourceLocation {
+    fn from(location: &'static Location<'static>) -> Self {
+        Self::new(location.file(), location.line(), location.column())
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_file_location(f, self.file, self.line, self.column)
+    }
+}
+
+/// The location of a frame, either in Rust source code or in a non-Rust source.
+///
+/// Frames are located in the Rust source code where they were created by default. Interpreters
+/// and loaders of configuration files can locate them in their own sources instead with
+/// [`Exn::with_location`](crate::Exn::with_location), e.g., at `config.toml:17:3`; reports print
+/// such locations in place of the Rust ones.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::FrameLocation;
+///
+/// let exn = Exn::new(std::fmt::Error).with_location(FrameLocation::file("config.toml", 17, 3));
+/// assert_eq!(exn.frame().frame_location().to_string(), "config.toml:17:3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FrameLocation {
+    /// A location in Rust source code.
+    Rust(&'static Location<'static>),
+    /// A location in a source file, rendered as `path:line:column`.
+    File {
+        /// The path of the file.
+        path: String,
+        /// The line number.
+        line: u32,
+        /// The column number.
+        column: u32,
+    },
+    /// A location in any other form, rendered as is, e.g., a URL or a JSON pointer.
+    Opaque(String),
+}
+
+impl FrameLocation {
+    /// Create a location in the source file at `path`, at the given line and column.
+    pub fn file(path: impl Into<String>, line: u32, column: u32) -> Self {
+        FrameLocation::File {
+            path: path.into(),
+            line,
+            column,
+        }
+    }
+
+    /// Create a location rendered as `location`.
+    pub fn opaque(location: impl Into<String>) -> Self {
+        FrameLocation::Opaque(location.into())
+    }
+
+    /// Return the path of the file, with `/` as separator, or `None` for an opaque location.
+    pub fn path(&self) -> Option<Cow<'_, str>> {
+        match self {
+            FrameLocation::Rust(location) => Some(SourceLocation::from(*location).file()),
+            FrameLocation::File { path, .. } if path.contains('\\') => {
+                Some(Cow::Owned(path.replace('\\', "/")))
+            }
+            FrameLocation::File { path, .. } => Some(Cow::Borrowed(path)),
+            FrameLocation::Opaque(_) => None,
+        }
+    }
+
+    /// Return the line number, or `None` for an opaque location.
+    pub fn line(&self) -> Option<u32> {
+        match self {
+            FrameLocation::Rust(location) => Some(location.line()),
+            FrameLocation::File { line, .. } => Some(*line),
+            FrameLocation::Opaque(_) => None,
+        }
+    }
+
+    /// Return the column number, or `None` for an opaque location.
+    pub fn column(&self) -> Option<u32> {
+        match self {
+            FrameLocation::Rust(location) => Some(location.column()),
+            FrameLocation::File { column, .. } => Some(*column),
+            FrameLocation::Opaque(_) => None,
+        }
+    }
+}
+
+impl From<&'static Location<'static>> for FrameLocation {
+    fn from(location: &'static Location<'static>) -> Self {
+        FrameLocation::Rust(location)
+    }
+}
+
+impl fmt::Display for FrameLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameLocation::Rust(location) => fmt::Display::fmt(&SourceLocation::from(*location), f),
+            FrameLocation::File { path, line, column } => {
+                write_file_location(f, path, *line, *column)
+            }
+            FrameLocation::Opaque(location) => f.write_str(location),
+        }
+    }
+}
+
+fn write_file_location(
+    f: &mut fmt::Formatter<'_>,
+    file: &str,
+    line: u32,
+    column: u32,
+) -> fmt::Result {
+    for (i, part) in file.split('\\').enumerate() {
+        if i > 0 {
+            f.write_str("/")?;
+        }
+        f.write_str(part)?;
+    }
+    write!(f, ":{line}:{column}")
+}
+
+/// The location reported by all frames and attachments when locations are compiled out.
+#[cfg(exn_no_location)]
+const UNKNOWN: &Location<'static> = Location::caller();
+
+/// Return the location of the caller, or a fixed location if locations are compiled out with
+/// `--cfg exn_no_location`.
+#[cfg_attr(not(exn_no_location), track_caller)]
+#[inline]
+pub(crate) fn caller() -> &'static Location<'static> {
+    #[cfg(not(exn_no_location))]
+    {
+        Location::caller()
+    }
+    #[cfg(exn_no_location)]
+    {
+        UNKNOWN
+    }
+}
+
+/// The location stored in frames and attachments; zero-sized if locations are compiled out with
+/// `--cfg exn_no_location`.
+#[derive(Clone, Copy)]
+pub(crate) struct StoredLocation {
+    #[cfg(not(exn_no_location))]
+    location: &'static Location<'static>,
+}
+
+impl StoredLocation {
+    #[cfg_attr(exn_no_location, allow(unused_variables))]
+    pub(crate) fn new(location: &'static Location<'static>) -> Self {
+        Self {
+            #[cfg(not(exn_no_location))]
+            location,
+        }
+    }
+
+    pub(crate) fn get(self) -> &'static Location<'static> {
+        #[cfg(not(exn_no_location))]
+        {
+            self.location
+        }
+        #[cfg(exn_no_location)]
+        {
+            UNKNOWN
+        }
+    }
+}
+
+impl StoredLocation {
+    /// Return the stored location, or `None` if locations are compiled out.
+    pub(crate) fn known(self) -> Option<&'static Location<'static>> {
+        #[cfg(not(exn_no_location))]
+        {
+            Some(self.location)
+        }
+        #[cfg(exn_no_location)]
+        {
+            None
+        }
+    }
+}
+
+impl fmt::Debug for StoredLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.get(), f)
+    }
+}
@@ -38,7 +38,7 @@
 #[macro_export]
 macro_rules! bail {
     ($err:expr) => {{
-        return ::core::result::Result::Err($crate::Exn::from($err));
+        return ::core::result::Result::Err($crate::IntoExn::__into_exn_in($err, $crate::here!()));
     }};
 }
 
@@ -89,3 +89,231 @@ macro_rules! ensure {
         }
     }};
 }
+
+/// Ensures `$left == $right`; otherwise return an error with both operands attached.
+///
+/// Like [`ensure!`], but on failure the `Debug` output of the operands is attached to the new
+/// exception as `left: ...` and `right: ...`, located at the macro call, like the message of
+/// [`assert_eq!`]. The operands are evaluated once and compared by reference.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// struct ChecksumMismatch;
+///
+/// impl core::fmt::Display for ChecksumMismatch {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "checksum mismatch")
+///     }
+/// }
+///
+/// impl core::error::Error for ChecksumMismatch {}
+///
+/// fn verify(actual: u32, expected: u32) -> exn::Result<(), ChecksumMismatch> {
+///     exn::ensure_eq!(actual, expected, ChecksumMismatch);
+///     Ok(())
+/// }
+///
+/// let exn = verify(0xdead, 0xbeef).unwrap_err();
+/// let attachments = exn.frame().attachments();
+/// assert_eq!(attachments[0].to_string(), "left: 57005");
+/// assert_eq!(attachments[1].to_string(), "right: 48879");
+/// ```
+#[macro_export]
+macro_rules! ensure_eq {
+    ($left:expr, $right:expr, $err:expr $(,)?) => {{
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    let exn = $crate::IntoExn::__into_exn_in($err, $crate::here!());
+                    return ::core::result::Result::Err($crate::__private::compare_failed(
+                        exn, left, right,
+                    ));
+                }
+            }
+        }
+    }};
+}
+
+/// Ensures `$left != $right`; otherwise return an error with both operands attached.
+///
+/// See [`ensure_eq!`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// struct SelfTransfer;
+///
+/// impl core::fmt::Display for SelfTransfer {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "cannot transfer to the same account")
+///     }
+/// }
+///
+/// impl core::error::Error for SelfTransfer {}
+///
+/// fn transfer(from: &str, to: &str) -> exn::Result<(), SelfTransfer> {
+///     exn::ensure_ne!(from, to, SelfTransfer);
+///     Ok(())
+/// }
+///
+/// let exn = transfer("alice", "alice").unwrap_err();
+/// assert_eq!(
+///     exn.frame().attachments()[1].to_string(),
+///     r#"right: "alice""#
+/// );
+/// ```
+#[macro_export]
+macro_rules! ensure_ne {
+    ($left:expr, $right:expr, $err:expr $(,)?) => {{
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left == *right {
+                    let exn = $crate::IntoExn::__into_exn_in($err, $crate::here!());
+                    return ::core::result::Result::Err($crate::__private::compare_failed(
+                        exn, left, right,
+                    ));
+                }
+            }
+        }
+    }};
+}
+
+/// Raises a new exception on the `Err` variant of a [`Result`], like [`ResultExt::or_raise`].
+///
+/// Shorthand for `expr.or_raise(|| err)`; the new exception is located at the macro call.
+///
+/// [`Result`]: core::result::Result
+/// [`ResultExt::or_raise`]: crate::ResultExt::or_raise
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// struct AppError(&'static str);
+///
+/// impl core::fmt::Display for AppError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for AppError {}
+///
+/// fn parse_port(input: &str) -> exn::Result<u16, AppError> {
+///     let port = exn::wrap!(input.parse::<u16>(), AppError("invalid port"))?;
+///     Ok(port)
+/// }
+///
+/// let exn = parse_port("http").unwrap_err();
+/// assert_eq!(
+///     format!("{exn:#}"),
+///     "invalid port: invalid digit found in string"
+/// );
+/// ```
+#[macro_export]
+macro_rules! wrap {
+    ($expr:expr, $err:expr $(,)?) => {
+        $crate::ResultExt::or_raise($expr, || $err)
+    };
+}
+
+/// Attaches a formatted message to the exception in the `Err` variant of a [`Result`], like
+/// [`ResultExt::or_attach`].
+///
+/// Shorthand for `expr.or_attach(|| format!(...))`; the message is only formatted on error, and
+/// the attachment is located at the macro call. Unlike [`wrap!`], this adds no new error layer:
+/// the message is rendered as an annotation under the topmost frame.
+///
+/// [`Result`]: core::result::Result
+/// [`ResultExt::or_attach`]: crate::ResultExt::or_attach
+///
+/// # Examples
+///
+/// ```
+/// fn parse_chunk(i: usize, input: &str) -> exn::Result<u32, std::num::ParseIntError> {
+///     let value = exn::note!(input.parse::<u32>(), "processing chunk {i}")?;
+///     Ok(value)
+/// }
+///
+/// let exn = parse_chunk(3, "NaN").unwrap_err();
+/// assert_eq!(
+///     exn.frame().attachments()[0].to_string(),
+///     "processing chunk 3"
+/// );
+/// ```
+#[macro_export]
+macro_rules! note {
+    ($expr:expr, $($arg:tt)+) => {
+        $crate::ResultExt::or_attach($expr, || $crate::__private::format!($($arg)+))
+    };
+}
+
+/// Creates a [`Pattern`] of the primary chain of an exception tree.
+///
+/// Each element is either `any!`, which matches any number of frames, including none, or a
+/// [`Matcher`] of one frame, e.g., a message or a closure. See [`Pattern`] for more information.
+///
+/// [`Pattern`]: crate::Pattern
+/// [`Matcher`]: crate::Matcher
+///
+/// # Examples
+///
+/// ```
+/// use exn::Frame;
+///
+/// let exn = exn::Exn::new(std::io::Error::other("disk full"))
+///     .raise(std::io::Error::other("failed to write"))
+///     .raise(std::io::Error::other("app failed"));
+///
+/// assert!(exn.matches(exn::pattern!["app failed", any!, "disk full"]));
+/// assert!(exn.matches(exn::pattern![
+///     "app failed",
+///     |frame: &Frame| frame.error().is::<std::io::Error>(),
+///     any!,
+/// ]));
+/// assert!(!exn.matches(exn::pattern!["app failed", "disk full"]));
+/// ```
+#[macro_export]
+macro_rules! pattern {
+    (@push $pattern:expr;) => {
+        $pattern
+    };
+    (@push $pattern:expr; any! $(, $($rest:tt)*)?) => {
+        $crate::pattern!(@push $pattern.any(); $($($rest)*)?)
+    };
+    (@push $pattern:expr; $element:expr $(, $($rest:tt)*)?) => {
+        $crate::pattern!(@push $pattern.frame($element); $($($rest)*)?)
+    };
+    ($($elements:tt)*) => {
+        $crate::pattern!(@push $crate::Pattern::new(); $($elements)*)
+    };
+}
+
+/// Returns the path of the enclosing function, such as `"my_app::config::load"`, as a
+/// `&'static str`.
+///
+/// Closures and async blocks are attributed to the function that defines them. The path is
+/// derived from [`core::any::type_name`], so its exact format is not guaranteed.
+///
+/// Use it with [`Exn::with_function`](crate::Exn::with_function) to record where an exception
+/// was created besides its location; [`bail!`] and [`ensure!`] do so automatically.
+///
+/// # Examples
+///
+/// ```
+/// fn load_config() -> &'static str {
+///     exn::here!()
+/// }
+///
+/// assert!(load_config().ends_with("::load_config"));
+/// ```
+#[macro_export]
+macro_rules! here {
+    () => {{
+        fn __exn_here() {}
+        $crate::__private::function_path(::core::any::type_name_of_val(&__exn_here))
+    }};
+}
@@ -0,0 +1,158 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use core::hash::Hash;
+use core::hash::Hasher;
+
+/// An error that is just a message, for modules that do not need to match on their errors.
+///
+/// Static messages are stored without allocation; formatted messages are stored as a `String`.
+/// Messages compare and hash by their text, regardless of how they are stored.
+///
+/// A static message can be defined in a `const` item. Creating an [`Exn`](crate::Exn) always
+/// allocates its frame, so hot paths and embedded targets can return a plain
+/// `Result<T, Message>` instead, which never allocates for static messages. It becomes an
+/// exception only when it propagates past the first function returning an
+/// [`exn::Result`](crate::Result) with `?`, where it is located.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Message;
+/// use exn::ResultExt;
+///
+/// fn parse_port(input: &str) -> exn::Result<u16, Message> {
+///     let port = input
+///         .parse::<u16>()
+///         .or_raise(|| Message::from(format!("invalid port: {input}")))?;
+///     exn::ensure!(port != 0, Message::Static("port must not be zero"));
+///     Ok(port)
+/// }
+///
+/// assert_eq!(
+///     parse_port("http").unwrap_err().to_string(),
+///     "invalid port: http"
+/// );
+/// assert_eq!(
+///     parse_port("0").unwrap_err().as_str(),
+///     "port must not be zero"
+/// );
+///
+/// const NOT_FOUND: Message = Message::Static("key not found");
+///
+/// // no allocation on the hot path
+/// fn lookup(key: u32) -> Result<u32, Message> {
+///     if key == 0 {
+///         return Err(NOT_FOUND);
+///     }
+///     Ok(key)
+/// }
+///
+/// fn handle(key: u32) -> exn::Result<u32, Message> {
+///     // the exception is created here
+///     let value = lookup(key)?;
+///     Ok(value)
+/// }
+///
+/// assert_eq!(*handle(0).unwrap_err(), NOT_FOUND);
+/// ```
+#[derive(Clone)]
+pub enum Message {
+    /// A static message.
+    Static(&'static str),
+    /// A formatted message.
+    Owned(String),
+}
+
+impl Message {
+    /// Return the message as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Message::Static(message) => message,
+            Message::Owned(message) => message,
+        }
+    }
+}
+
+impl From<&'static str> for Message {
+    fn from(message: &'static str) -> Self {
+        Message::Static(message)
+    }
+}
+
+impl From<String> for Message {
+    fn from(message: String) -> Self {
+        Message::Owned(message)
+    }
+}
+
+impl From<Cow<'static, str>> for Message {
+    fn from(message: Cow<'static, str>) -> Self {
+        match message {
+            Cow::Borrowed(message) => Message::Static(message),
+            Cow::Owned(message) => Message::Owned(message),
+        }
+    }
+}
+
+impl AsRef<str> for Message {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Message {}
+
+impl PartialOrd for Message {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Message {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for Message {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Debug for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Message").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Error for Message {}
@@ -0,0 +1,113 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use core::ptr;
+use core::sync::atomic::AtomicPtr;
+use core::sync::atomic::Ordering;
+
+use crate::Frame;
+
+/// An observer notified of every exception created, e.g., to count errors by type or code.
+///
+/// Install it with [`set_observer`]. Both methods do nothing by default.
+///
+/// Observers are called synchronously wherever exceptions are created, so they should be cheap,
+/// and they must not create exceptions themselves, which would notify them again.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use std::sync::atomic::Ordering;
+///
+/// use exn::ExnObserver;
+/// use exn::Frame;
+///
+/// # #[derive(Debug)]
+/// # struct QuotaError;
+/// # impl core::fmt::Display for QuotaError {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// #         write!(f, "quota exceeded")
+/// #     }
+/// # }
+/// # impl core::error::Error for QuotaError {}
+/// static QUOTA_ERRORS: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct Metrics;
+///
+/// impl ExnObserver for Metrics {
+///     fn on_new(&self, frame: &Frame) {
+///         if frame.error().is::<QuotaError>() {
+///             QUOTA_ERRORS.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// exn::set_observer(Metrics);
+///
+/// let _ = exn::Exn::new(QuotaError);
+/// assert_eq!(QUOTA_ERRORS.load(Ordering::Relaxed), 1);
+/// ```
+pub trait ExnObserver: Send + Sync + 'static {
+    /// Called when a new exception is created from an error, e.g., with [`Exn::new`].
+    ///
+    /// `frame` is the topmost frame of the new exception; its children are the frames walked
+    /// from the [source chain](core::error::Error::source) of the error, if any.
+    ///
+    /// [`Exn::new`]: crate::Exn::new
+    fn on_new(&self, frame: &Frame) {
+        let _ = frame;
+    }
+
+    /// Called when a new exception is raised on existing ones, e.g., with [`Exn::raise`] or
+    /// [`Exn::raise_all`].
+    ///
+    /// `frame` is the topmost frame of the new exception, whose children include the topmost
+    /// frames of the existing exceptions, which were already observed when they were created.
+    ///
+    /// [`Exn::raise`]: crate::Exn::raise
+    /// [`Exn::raise_all`]: crate::Exn::raise_all
+    fn on_raise(&self, frame: &Frame) {
+        let _ = frame;
+    }
+}
+
+/// Install the global observer notified of every exception created.
+///
+/// This is meant to be called once at startup. Calling it again replaces the observer; since
+/// other threads may still be using the replaced observer, it is leaked.
+pub fn set_observer(observer: impl ExnObserver) {
+    let observer: Box<dyn ExnObserver> = Box::new(observer);
+    OBSERVER.store(Box::leak(Box::new(observer)), Ordering::Release);
+}
+
+static OBSERVER: AtomicPtr<Box<dyn ExnObserver>> = AtomicPtr::new(ptr::null_mut());
+
+fn observer() -> Option<&'static dyn ExnObserver> {
+    // SAFETY: observers are leaked on installation and never freed or mutated once published.
+    unsafe { OBSERVER.load(Ordering::Acquire).as_ref() }.map(|observer| &**observer)
+}
+
+pub(crate) fn notify_new(frame: &Frame) {
+    if let Some(observer) = observer() {
+        observer.on_new(frame);
+    }
+}
+
+pub(crate) fn notify_raise(frame: &Frame) {
+    if let Some(observer) = observer() {
+        observer.on_raise(frame);
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use core::error::Error;
+use core::fmt;
 
 use crate::Exn;
 use crate::Result;
@@ -27,12 +28,62 @@ pub trait OptionExt {
     where
         A: Error + Send + Sync + 'static,
         F: FnOnce() -> A;
+
+    /// Construct a new [`Exn`] on the `None` variant, with a message attached to it as
+    /// lightweight context.
+    ///
+    /// Shorthand for `.ok_or_raise(err).or_attach(message)`, refer to
+    /// [`ResultExt::or_attach`](crate::ResultExt::or_attach) for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::OptionExt;
+    ///
+    /// let section = "server";
+    /// let result = None::<u16>.ok_or_attach(
+    ///     || std::io::Error::other("missing key `port`"),
+    ///     || format!("in section `{section}`"),
+    /// );
+    /// let exn = result.unwrap_err();
+    /// assert_eq!(
+    ///     exn.frame().attachments()[0].to_string(),
+    ///     "in section `server`"
+    /// );
+    /// ```
+    fn ok_or_attach<A, F, M, G>(self, err: F, message: G) -> Result<Self::Some, A>
+    where
+        A: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+        M: fmt::Display + Send + Sync + 'static,
+        G: FnOnce() -> M;
+
+    /// Construct a new [`Exn`] on the `None` variant, or if `predicate` returns `false` for the
+    /// `Some` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::OptionExt;
+    ///
+    /// let port = "8080".parse::<u16>().ok();
+    /// let port = port.filter_or_raise(|port| *port >= 1024, || std::fmt::Error);
+    /// assert_eq!(port.unwrap(), 8080);
+    ///
+    /// let port = Some(80).filter_or_raise(|port| *port >= 1024, || std::fmt::Error);
+    /// assert!(port.is_err());
+    /// ```
+    fn filter_or_raise<P, A, F>(self, predicate: P, err: F) -> Result<Self::Some, A>
+    where
+        P: FnOnce(&Self::Some) -> bool,
+        A: Error + Send + Sync + 'static,
+        F: FnOnce() -> A;
 }
 
 impl<T> OptionExt for Option<T> {
     type Some = T;
 
-    #[track_caller]
+    #[cfg_attr(not(exn_no_location), track_caller)]
     fn ok_or_raise<A, F>(self, err: F) -> Result<T, A>
     where
         A: Error + Send + Sync + 'static,
@@ -43,4 +94,31 @@ impl<T> OptionExt for Option<T> {
             None => Err(Exn::new(err())),
         }
     }
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn ok_or_attach<A, F, M, G>(self, err: F, message: G) -> Result<T, A>
+    where
+        A: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+        M: fmt::Display + Send + Sync + 'static,
+        G: FnOnce() -> M,
+    {
+        match self {
+            Some(v) => Ok(v),
+            None => Err(Exn::new(err()).attach(message())),
+        }
+    }
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn filter_or_raise<P, A, F>(self, predicate: P, err: F) -> Result<T, A>
+    where
+        P: FnOnce(&T) -> bool,
+        A: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        match self {
+            Some(v) if predicate(&v) => Ok(v),
+            _ => Err(Exn::new(err())),
+        }
+    }
 }
@@ -0,0 +1,217 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::fmt::Write;
+
+use crate::Exn;
+use crate::Frame;
+
+/// A test of an exception tree, given its topmost frame.
+///
+/// This trait is implemented for:
+///
+/// * `&str` and [`String`], which match a frame whose error displays as the string.
+/// * Closures taking a [`Frame`] and returning a `bool`.
+/// * [`Pattern`], which matches the shape of the [primary chain](Frame::primary_chain).
+///
+/// Matchers are used with [`Exn::matches`] and [`Frame::matches`], and as the elements of a
+/// [`Pattern`], where they test each frame of the primary chain in turn.
+pub trait Matcher {
+    /// Return `true` if the exception tree rooted at `frame` matches.
+    fn matches(&self, frame: &Frame) -> bool;
+}
+
+impl Matcher for &str {
+    fn matches(&self, frame: &Frame) -> bool {
+        displays_as(frame.error(), self)
+    }
+}
+
+impl Matcher for String {
+    fn matches(&self, frame: &Frame) -> bool {
+        displays_as(frame.error(), self)
+    }
+}
+
+impl<F: Fn(&Frame) -> bool> Matcher for F {
+    fn matches(&self, frame: &Frame) -> bool {
+        self(frame)
+    }
+}
+
+/// A pattern of the [primary chain](Frame::primary_chain) of an exception tree, e.g., to route
+/// errors or to assert on them in tests without comparing rendered reports.
+///
+/// A pattern is a sequence of elements matched against the frames of the primary chain in order,
+/// from the topmost frame to the root cause. Each [`frame`](Pattern::frame) element matches one
+/// frame with a [`Matcher`], while an [`any`](Pattern::any) element matches any number of frames,
+/// including none. The whole chain must match: start or end the pattern with `any` to match only
+/// the top or the bottom of the chain.
+///
+/// Patterns are usually written with the [`pattern!`](crate::pattern!) macro.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Pattern;
+///
+/// let exn = exn::Exn::new(std::io::Error::other("connection reset"))
+///     .raise(std::io::Error::other("failed to fetch"))
+///     .raise(std::io::Error::other("failed to sync"));
+///
+/// let pattern = Pattern::new()
+///     .frame("failed to sync")
+///     .any()
+///     .frame("connection reset");
+/// assert!(exn.matches(&pattern));
+///
+/// let pattern = Pattern::new()
+///     .frame("failed to sync")
+///     .frame("connection reset");
+/// assert!(!exn.matches(&pattern));
+/// ```
+#[derive(Default)]
+pub struct Pattern<'a> {
+    elements: Vec<Element<'a>>,
+}
+
+enum Element<'a> {
+    Frame(Box<dyn Matcher + Send + Sync + 'a>),
+    Any,
+}
+
+impl<'a> Pattern<'a> {
+    /// Create an empty pattern, which matches no chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an element matching one frame with `matcher`.
+    pub fn frame<M: Matcher + Send + Sync + 'a>(mut self, matcher: M) -> Self {
+        self.elements.push(Element::Frame(Box::new(matcher)));
+        self
+    }
+
+    /// Append an element matching any number of frames, including none.
+    pub fn any(mut self) -> Self {
+        self.elements.push(Element::Any);
+        self
+    }
+}
+
+impl Matcher for Pattern<'_> {
+    fn matches(&self, frame: &Frame) -> bool {
+        let chain = frame.primary_chain().collect::<Vec<_>>();
+        // the wildcard matching of globs, backtracking to the last `any` on mismatch
+        let (mut e, mut c) = (0, 0);
+        let mut backtrack = None;
+        while c < chain.len() {
+            match self.elements.get(e) {
+                Some(Element::Any) => {
+                    backtrack = Some((e + 1, c));
+                    e += 1;
+                }
+                Some(Element::Frame(matcher)) if matcher.matches(chain[c]) => {
+                    e += 1;
+                    c += 1;
+                }
+                _ => match backtrack {
+                    Some((next, start)) => {
+                        backtrack = Some((next, start + 1));
+                        e = next;
+                        c = start + 1;
+                    }
+                    None => return false,
+                },
+            }
+        }
+        self.elements[e..]
+            .iter()
+            .all(|element| matches!(element, Element::Any))
+    }
+}
+
+impl Matcher for &Pattern<'_> {
+    fn matches(&self, frame: &Frame) -> bool {
+        (**self).matches(frame)
+    }
+}
+
+impl fmt::Debug for Pattern<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for element in &self.elements {
+            match element {
+                Element::Frame(_) => list.entry(&format_args!("frame")),
+                Element::Any => list.entry(&format_args!("any!")),
+            };
+        }
+        list.finish()
+    }
+}
+
+/// Return `true` if `error` displays as `expected`, without allocating.
+fn displays_as(error: &(dyn Error + Send + Sync + 'static), expected: &str) -> bool {
+    struct Compare<'a>(&'a str);
+
+    impl Write for Compare<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            match self.0.strip_prefix(s) {
+                Some(rest) => {
+                    self.0 = rest;
+                    Ok(())
+                }
+                None => Err(fmt::Error),
+            }
+        }
+    }
+
+    let mut compare = Compare(expected);
+    write!(compare, "{error}").is_ok() && compare.0.is_empty()
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return `true` if the exception tree matches `matcher`, e.g., a message, a closure taking
+    /// the topmost frame, or a [`Pattern`] of the primary chain.
+    ///
+    /// See [`Matcher`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let exn = exn::Exn::new(std::io::Error::other("connection reset"))
+    ///     .raise(std::io::Error::other("failed to fetch"));
+    ///
+    /// assert!(exn.matches("failed to fetch"));
+    /// assert!(exn.matches(|frame: &exn::Frame| frame.children().len() == 1));
+    /// assert!(exn.matches(&exn::pattern![any!, "connection reset"]));
+    /// ```
+    pub fn matches<M: Matcher>(&self, matcher: M) -> bool {
+        self.frame().matches(matcher)
+    }
+}
+
+impl Frame {
+    /// Return `true` if the exception tree rooted at this frame matches `matcher`.
+    ///
+    /// See [`Exn::matches`] for more information.
+    pub fn matches<M: Matcher>(&self, matcher: M) -> bool {
+        matcher.matches(self)
+    }
+}
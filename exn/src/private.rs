@@ -0,0 +1,117 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation details of the macros. Not public API.
+
+use alloc::boxed::Box;
+pub use alloc::format;
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+use crate::Frame;
+
+/// Return the path of the function enclosing the item whose type name is `name`.
+pub fn function_path(name: &'static str) -> &'static str {
+    let mut path = name.strip_suffix("::__exn_here").unwrap_or(name);
+    while let Some(parent) = path.strip_suffix("::{{closure}}") {
+        path = parent;
+    }
+    path
+}
+
+/// An exception whose top-level error type is erased, which any error or exception converts into.
+pub struct Erased(Box<Frame>);
+
+impl<E: Error + Send + Sync + 'static> From<E> for Erased {
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn from(error: E) -> Self {
+        Erased(Exn::new(error).into_boxed_frame())
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Erased {
+    fn from(exn: Exn<E>) -> Self {
+        Erased(exn.into_boxed_frame())
+    }
+}
+
+/// Raise the erased exception of `result`, if any, under the error returned by `err`.
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn context<T, E, F>(result: Result<T, Erased>, err: F) -> crate::Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+    F: FnOnce() -> E,
+{
+    match result {
+        Ok(v) => Ok(v),
+        Err(Erased(frame)) => Err(Exn::raise_frame_at(err(), frame, crate::location::caller())),
+    }
+}
+
+/// Attach the operands of a failed comparison of `ensure_eq!` or `ensure_ne!` to `exn`.
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn compare_failed<E, L, R>(exn: Exn<E>, left: &L, right: &R) -> Exn<E>
+where
+    E: Error + Send + Sync + 'static,
+    L: fmt::Debug + ?Sized,
+    R: fmt::Debug + ?Sized,
+{
+    exn.attach(format!("left: {left:?}"))
+        .attach(format!("right: {right:?}"))
+}
+
+/// Report the exception of `result` returned from a function annotated with `#[exn::main]`, if
+/// any, and return the exit code of the process.
+#[cfg(feature = "std")]
+pub fn run_main<E>(result: Result<(), Exn<E>>) -> std::process::ExitCode
+where
+    E: Error + Send + Sync + 'static,
+{
+    match result {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(exn) => {
+            std::eprintln!("error: {exn:?}");
+            std::process::ExitCode::from(exit_code(exn.frame()))
+        }
+    }
+}
+
+/// Return the exit code of a process failing with the exception tree rooted at `frame`.
+///
+/// Follows the conventions of the shell: 130 for cancellations, as if interrupted by `SIGINT`,
+/// 124 for timeouts, as the `timeout` utility, and 1 otherwise.
+#[cfg(feature = "std")]
+pub fn exit_code(frame: &Frame) -> u8 {
+    if frame.is_cancelled() {
+        130
+    } else if frame.is_timeout() {
+        124
+    } else {
+        1
+    }
+}
+
+/// Panic with the exception of `result` returned from a function annotated with `#[exn::test]`,
+/// if any.
+#[cfg(feature = "std")]
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn run_test<E>(result: Result<(), Exn<E>>)
+where
+    E: Error + Send + Sync + 'static,
+{
+    if let Err(exn) = result {
+        std::panic!("test failed with an exception: {exn:?}");
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration with the unstable [`Error::provide`] API.
+//!
+//! This module requires a nightly compiler and is enabled with `RUSTFLAGS="--cfg exn_nightly"`.
+
+use core::error::Error;
+
+use crate::Exn;
+use crate::Frame;
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Request a reference of type `T` from the errors of the exception tree.
+    ///
+    /// See [`Frame::request_ref`] for more information.
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        self.frame().request_ref()
+    }
+
+    /// Request a value of type `T` from the errors of the exception tree.
+    ///
+    /// See [`Frame::request_value`] for more information.
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        self.frame().request_value()
+    }
+
+    /// Return an iterator over the references of type `T` provided by the errors of the exception
+    /// tree.
+    ///
+    /// See [`Frame::request_refs`] for more information.
+    pub fn request_refs<T: ?Sized + 'static>(&self) -> impl Iterator<Item = &T> {
+        self.frame().request_refs()
+    }
+
+    /// Return an iterator over the values of type `T` provided by the errors of the exception
+    /// tree.
+    ///
+    /// See [`Frame::request_values`] for more information.
+    pub fn request_values<T: 'static>(&self) -> impl Iterator<Item = T> + '_ {
+        self.frame().request_values()
+    }
+}
+
+impl Frame {
+    /// Request a reference of type `T` from the errors of this frame and its descendants.
+    ///
+    /// Frames are visited in depth-first pre-order, and the first provided reference wins.
+    pub fn request_ref<T: ?Sized + 'static>(&self) -> Option<&T> {
+        self.request_refs().next()
+    }
+
+    /// Request a value of type `T` from the errors of this frame and its descendants.
+    ///
+    /// Frames are visited in depth-first pre-order, and the first provided value wins.
+    pub fn request_value<T: 'static>(&self) -> Option<T> {
+        self.request_values().next()
+    }
+
+    /// Return an iterator over the references of type `T` provided by the errors of this frame and
+    /// its descendants, in depth-first pre-order.
+    pub fn request_refs<T: ?Sized + 'static>(&self) -> impl Iterator<Item = &T> {
+        self.frames()
+            .filter_map(|frame| core::error::request_ref(frame.error_ref()))
+    }
+
+    /// Return an iterator over the values of type `T` provided by the errors of this frame and its
+    /// descendants, in depth-first pre-order.
+    pub fn request_values<T: 'static>(&self) -> impl Iterator<Item = T> + '_ {
+        self.frames()
+            .filter_map(|frame| core::error::request_value(frame.error_ref()))
+    }
+
+    fn error_ref(&self) -> &(dyn Error + 'static) {
+        self.error()
+    }
+}
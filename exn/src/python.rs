@@ -0,0 +1,95 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::error::Error;
+
+use pyo3::PyErr;
+use pyo3::Python;
+use pyo3::exceptions::PyException;
+use pyo3::types::PyAnyMethods;
+use pyo3::types::PyList;
+
+use crate::Exn;
+use crate::Frame;
+use crate::report::Compact;
+
+/// Convert an exception into a Python exception.
+///
+/// Each frame of the [primary chain](Frame::primary_chain) becomes an `Exception` whose
+/// `__cause__` is the exception of the next frame, so that Python tracebacks show the chain from
+/// the root cause up. The location, the attachments and the other children of each frame, rendered
+/// as with `{:?}`, are added to the `__notes__` of its exception. The messages and attachments of
+/// [sensitive](Exn::mark_sensitive) frames are replaced with `«redacted»`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pyo3::PyResult;
+///
+/// fn load(path: &str) -> PyResult<String> {
+///     let content = std::fs::read_to_string(path)
+///         .map_err(|err| exn::Exn::new(err).attach(format!("while loading {path}")))?;
+///     Ok(content)
+/// }
+/// ```
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for PyErr {
+    fn from(exn: Exn<E>) -> Self {
+        Python::attach(|py| {
+            let errors = exn
+                .primary_chain()
+                .map(|frame| to_py_err(py, frame))
+                .collect::<Vec<_>>();
+            errors
+                .into_iter()
+                .rev()
+                .reduce(|cause, err| {
+                    err.set_cause(py, Some(cause));
+                    err
+                })
+                .expect("the primary chain is never empty")
+        })
+    }
+}
+
+fn to_py_err(py: Python<'_>, frame: &Frame) -> PyErr {
+    let err = PyErr::new::<PyException, _>(frame.redacted_message().to_string());
+
+    let location = frame
+        .known_location()
+        .map(|location| format!("at {location}"));
+    let attachments = frame
+        .redacted_attachments()
+        .map(|attachment| attachment.to_string());
+    let primary = frame.primary_child();
+    let siblings = frame
+        .children()
+        .iter()
+        .filter(|child| !primary.is_some_and(|primary| core::ptr::eq(primary, *child)))
+        .map(|sibling| format!("{:?}", Compact::new(sibling).with_redaction(true)));
+    let notes = location
+        .into_iter()
+        .chain(attachments)
+        .chain(siblings)
+        .collect::<Vec<_>>();
+
+    // setting an attribute of a fresh exception only fails on out-of-memory, in which case the
+    // notes are better lost than the exception
+    if let Ok(notes) = PyList::new(py, notes) {
+        let _ = err.value(py).setattr("__notes__", notes);
+    }
+    err
+}
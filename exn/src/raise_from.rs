@@ -0,0 +1,78 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+
+use crate::Exn;
+
+/// An error type that errors of type `E` may be raised into automatically.
+///
+/// Implementing `RaiseFrom<E>` for `Self` enables `impl From<E> for Exn<Self>`, so the question
+/// mark operator converts an `E` into an `Exn<Self>` without [`or_raise`]. The conversion is still
+/// explicit, since it is declared once per pair of error types, and the frames it creates are
+/// located at the question mark.
+///
+/// Every error type can be raised into itself, which creates a new exception with the error.
+///
+/// [`or_raise`]: crate::ResultExt::or_raise
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::RaiseFrom;
+///
+/// #[derive(Debug)]
+/// struct ConfigError;
+///
+/// impl core::fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "failed to load config")
+///     }
+/// }
+///
+/// impl core::error::Error for ConfigError {}
+///
+/// impl RaiseFrom<std::num::ParseIntError> for ConfigError {
+///     fn raise_from(source: std::num::ParseIntError) -> Exn<Self> {
+///         Exn::new(source).raise(ConfigError)
+///     }
+/// }
+///
+/// fn load_port(input: &str) -> exn::Result<u16, ConfigError> {
+///     Ok(input.parse()?)
+/// }
+///
+/// let exn = load_port("http").unwrap_err();
+/// assert_eq!(
+///     format!("{exn:#}"),
+///     "failed to load config: invalid digit found in string"
+/// );
+/// ```
+pub trait RaiseFrom<E: Error + Send + Sync + 'static>:
+    Error + Send + Sync + Sized + 'static
+{
+    /// Raise an exception of this error type from the error `source`.
+    ///
+    /// The location of the caller is propagated to the implementations of this method, so that the
+    /// exceptions they create are located where the conversion happens.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn raise_from(source: E) -> Exn<Self>;
+}
+
+impl<E: Error + Send + Sync + 'static> RaiseFrom<E> for E {
+    fn raise_from(source: E) -> Exn<Self> {
+        Exn::new(source)
+    }
+}
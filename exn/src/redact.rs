@@ -0,0 +1,93 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::error::Error;
+use core::fmt;
+
+use crate::Attachment;
+use crate::Frame;
+
+/// A message of a frame as shown to end users, replaced with `«redacted»` if the frame is
+/// [sensitive](crate::Exn::mark_sensitive).
+///
+/// This struct is created by [`Frame::redacted_message`] and [`Frame::redacted_attachments`].
+#[derive(Clone, Copy)]
+pub struct Redacted<'a>(Inner<'a>);
+
+#[derive(Clone, Copy)]
+enum Inner<'a> {
+    Error(&'a (dyn Error + Send + Sync + 'static)),
+    Attachment(&'a Attachment),
+    Redacted,
+}
+
+impl Redacted<'_> {
+    /// Return `true` if the message is replaced with `«redacted»`.
+    pub fn is_redacted(&self) -> bool {
+        matches!(self.0, Inner::Redacted)
+    }
+}
+
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Inner::Error(error) => fmt::Display::fmt(error, f),
+            Inner::Attachment(attachment) => fmt::Display::fmt(attachment, f),
+            Inner::Redacted => f.write_str("«redacted»"),
+        }
+    }
+}
+
+impl fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", format_args!("{self}"))
+    }
+}
+
+impl Frame {
+    /// Return the error message of this frame as shown to end users: `«redacted»` if the frame
+    /// is [sensitive](crate::Exn::mark_sensitive), or else the message itself.
+    ///
+    /// Integrations that export exception trees to other systems use this, so that sensitive
+    /// messages are redacted the same way everywhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let exn = exn::Exn::new(std::io::Error::other("password is hunter2")).mark_sensitive();
+    /// assert_eq!(exn.frame().redacted_message().to_string(), "«redacted»");
+    /// ```
+    pub fn redacted_message(&self) -> Redacted<'_> {
+        if self.is_sensitive() {
+            Redacted(Inner::Redacted)
+        } else {
+            Redacted(Inner::Error(self.error()))
+        }
+    }
+
+    /// Return the messages of the [attachments](Frame::attachments) of this frame as shown to end
+    /// users, in the same order: each is `«redacted»` if the frame is
+    /// [sensitive](crate::Exn::mark_sensitive), since attachments often describe the same data as
+    /// the error message.
+    pub fn redacted_attachments(&self) -> impl ExactSizeIterator<Item = Redacted<'_>> {
+        let sensitive = self.is_sensitive();
+        self.attachments().iter().map(move |attachment| {
+            if sensitive {
+                Redacted(Inner::Redacted)
+            } else {
+                Redacted(Inner::Attachment(attachment))
+            }
+        })
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use core::any::TypeId;
+use core::ptr;
+use core::sync::atomic::AtomicPtr;
+use core::sync::atomic::Ordering;
+
+/// A lock-free, append-only registry of values keyed by type.
+///
+/// Each capability trait keeps one registry of functions downcasting an error to its registered
+/// type; see the [crate documentation](crate#registering-error-types) for why.
+pub(crate) struct Registry<T: 'static> {
+    head: AtomicPtr<Entry<T>>,
+}
+
+pub(crate) struct Entry<T: 'static> {
+    pub(crate) type_id: TypeId,
+    pub(crate) value: T,
+    next: *const Entry<T>,
+}
+
+impl<T: 'static> Registry<T> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Register `value` for `type_id`.
+    ///
+    /// Registering the same type more than once has no further effect.
+    pub(crate) fn register(&self, type_id: TypeId, value: T) {
+        if self.entries().any(|entry| entry.type_id == type_id) {
+            return;
+        }
+
+        let entry = Box::leak(Box::new(Entry {
+            type_id,
+            value,
+            next: ptr::null(),
+        }));
+
+        let mut head = self.head.load(Ordering::Acquire);
+        loop {
+            entry.next = head;
+            match self
+                .head
+                .compare_exchange_weak(head, entry, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    pub(crate) fn entries(&self) -> impl Iterator<Item = &'static Entry<T>> {
+        let mut next = self.head.load(Ordering::Acquire).cast_const();
+        core::iter::from_fn(move || {
+            // SAFETY: entries are leaked on registration and never freed or mutated once published.
+            let entry = unsafe { next.as_ref() }?;
+            next = entry.next;
+            Some(entry)
+        })
+    }
+}
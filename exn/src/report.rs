@@ -0,0 +1,984 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configurable renderings of exception trees.
+//!
+//! A report either owns the exception tree, when converted from an [`Exn`], or borrows it, when
+//! created from a [`&Frame`](Frame):
+//!
+//! ```
+//! use exn::report::Compact;
+//!
+//! #[derive(Debug)]
+//! struct Error;
+//!
+//! impl core::fmt::Display for Error {
+//!     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+//!         write!(f, "something failed")
+//!     }
+//! }
+//!
+//! impl core::error::Error for Error {}
+//!
+//! fn run() -> exn::Result<(), Error> {
+//!     exn::bail!(Error)
+//! }
+//!
+//! // borrow the exception tree, e.g., to log it before propagating the error
+//! let exn = run().unwrap_err();
+//! eprintln!("{:?}", Compact::new(exn.frame()).with_type_names(true));
+//!
+//! // or take ownership of it, e.g., to return it from `main`
+//! fn entrypoint() -> Result<(), Compact> {
+//!     run()?;
+//!     Ok(())
+//! }
+//! # let _ = entrypoint();
+//! ```
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use core::error::Error;
+use core::fmt;
+use core::fmt::Write;
+use core::ptr;
+
+use crate::Budget;
+use crate::Exn;
+use crate::Frame;
+use crate::FrameOrigin;
+use crate::SourceLocation;
+use crate::debug::Options;
+use crate::debug::write_tree;
+
+/// A report rendering the exception tree in the same layout as the `Debug` output of [`Exn`].
+///
+/// Both `Debug` and `Display` write the exception tree.
+pub struct Compact<F = Box<Frame>> {
+    frame: F,
+    options: Options,
+}
+
+impl<F: AsRef<Frame>> Compact<F> {
+    /// Create a report of the exception tree rooted at `frame`.
+    pub fn new(frame: F) -> Self {
+        Self {
+            frame,
+            options: Options::default(),
+        }
+    }
+
+    /// Return the topmost frame of the exception tree.
+    pub fn frame(&self) -> &Frame {
+        self.frame.as_ref()
+    }
+
+    /// Set the formatting budget of this report.
+    ///
+    /// Defaults to the budget set with [`set_debug_budget`](crate::set_debug_budget).
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.options.budget = budget;
+        self
+    }
+
+    /// Set whether to write the location of each frame. Defaults to `true`.
+    pub fn with_locations(mut self, locations: bool) -> Self {
+        self.options.locations = locations;
+        self
+    }
+
+    /// Set whether to write the [function](Frame::function) where each frame was created, if
+    /// known. Defaults to `false`.
+    pub fn with_functions(mut self, functions: bool) -> Self {
+        self.options.functions = functions;
+        self
+    }
+
+    /// Set whether to write the [type name](Frame::type_name) of the error of each frame.
+    /// Defaults to `false`.
+    pub fn with_type_names(mut self, type_names: bool) -> Self {
+        self.options.type_names = type_names;
+        self
+    }
+
+    /// Set the width to wrap error messages and attachments at, in characters. Defaults to
+    /// `None`, i.e., no wrapping.
+    ///
+    /// Messages are wrapped at spaces; continuation lines are indented under the tree guides, so
+    /// that the layout of the tree is preserved. Words longer than the width and locations are
+    /// never broken.
+    pub fn with_width(mut self, width: Option<usize>) -> Self {
+        self.options.width = width;
+        self
+    }
+
+    /// Set the width to wrap messages at to the width of the terminal attached to standard
+    /// error, if any. See [`with_width`](Compact::with_width) for more information.
+    ///
+    /// The width is left unchanged if standard error is not a terminal.
+    #[cfg(feature = "term")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "term")))]
+    pub fn with_terminal_width(mut self) -> Self {
+        if let Some((width, _)) = terminal_size::terminal_size_of(std::io::stderr()) {
+            self.options.width = Some(usize::from(width.0));
+        }
+        self
+    }
+
+    /// Set the number of lines of context of the source snippet written under each frame.
+    /// Defaults to `None`, i.e., no snippets.
+    ///
+    /// See [`Frame::source_snippet`] for how the source files are found; frames whose source
+    /// cannot be found are written without a snippet.
+    #[cfg(feature = "diagnostics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+    pub fn with_snippets(mut self, context: Option<usize>) -> Self {
+        self.options.snippets = context;
+        self
+    }
+
+    /// Set whether to write [transparent](Exn::raise_transparent) frames. Defaults to `true`.
+    ///
+    /// Hidden frames are replaced with their children; their attachments and suppressed
+    /// exceptions are not written. The topmost frame is always written.
+    pub fn with_transparent_frames(mut self, transparent_frames: bool) -> Self {
+        self.options.transparent_frames = transparent_frames;
+        self
+    }
+
+    /// Write only the frames [tagged](Exn::with_tag) with any of `tags`. Defaults to writing
+    /// frames regardless of their tags.
+    ///
+    /// Hidden frames are replaced with their children, as with
+    /// [`with_transparent_frames`](Compact::with_transparent_frames). The topmost frame is always
+    /// written.
+    pub fn with_included_tags(mut self, tags: impl IntoIterator<Item = &'static str>) -> Self {
+        let filter = self.options.tags.get_or_insert_with(Box::default);
+        filter.included = tags.into_iter().collect();
+        self
+    }
+
+    /// Hide the frames [tagged](Exn::with_tag) with any of `tags`, which takes precedence over
+    /// [`with_included_tags`](Compact::with_included_tags). Defaults to hiding no frames.
+    ///
+    /// Hidden frames are replaced with their children, as with
+    /// [`with_transparent_frames`](Compact::with_transparent_frames). The topmost frame is always
+    /// written.
+    pub fn with_excluded_tags(mut self, tags: impl IntoIterator<Item = &'static str>) -> Self {
+        let filter = self.options.tags.get_or_insert_with(Box::default);
+        filter.excluded = tags.into_iter().collect();
+        self
+    }
+
+    /// Set the depth below which frames are elided. Defaults to unlimited.
+    ///
+    /// The topmost frame has depth 0. The descendants of frames at depth `max_depth` are
+    /// replaced with a `… and N more` line, where `N` is the number of elided frames.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.options.max_depth = max_depth;
+        self
+    }
+
+    /// Set the number of children written for each frame. Defaults to unlimited.
+    ///
+    /// Children beyond the first `max_children` are replaced with a `… and N more` line, where
+    /// `N` is the number of elided children, e.g., to keep reports of batch failures aggregating
+    /// thousands of errors readable.
+    pub fn with_max_children(mut self, max_children: usize) -> Self {
+        self.options.max_children = max_children;
+        self
+    }
+
+    /// Set whether to replace the error message and the attachments of
+    /// [sensitive](Exn::mark_sensitive) frames with `«redacted»`. Defaults to `false`.
+    ///
+    /// Enable this for reports destined for end users.
+    pub fn with_redaction(mut self, redact: bool) -> Self {
+        self.options.redact = redact;
+        self
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Compact {
+    fn from(exn: Exn<E>) -> Self {
+        Compact::new(exn.into_boxed_frame())
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Debug for Compact<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.frame.as_ref(), &self.options)
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Display for Compact<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.frame.as_ref(), &self.options)
+    }
+}
+
+/// A report rendering the exception tree in the same layout as [`Compact`], with locations
+/// replaced by deterministic placeholders.
+///
+/// Every location is written as `<file>:<L>:<C>`, so that snapshot tests of the output, e.g.,
+/// with [insta](https://docs.rs/insta), do not break whenever code moves. Unlike
+/// [`Compact::with_locations`], the placeholders keep the shape of the output, showing which
+/// frames and attachments have a location.
+///
+/// Both `Debug` and `Display` write the exception tree.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ErrorExt;
+/// use exn::report::Stable;
+///
+/// let exn = std::fmt::Error
+///     .raise()
+///     .attach("while rendering")
+///     .raise(std::io::Error::other("failed to respond"));
+///
+/// assert_eq!(
+///     format!("{}", Stable::new(exn.frame())),
+///     "failed to respond, at <file>:<L>:<C>
+/// |
+/// |-> an error occurred when formatting an argument, at <file>:<L>:<C>
+///     = while rendering, at <file>:<L>:<C>"
+/// );
+/// ```
+pub struct Stable<F = Box<Frame>> {
+    frame: F,
+    options: Options,
+}
+
+impl<F: AsRef<Frame>> Stable<F> {
+    /// Create a report of the exception tree rooted at `frame`.
+    pub fn new(frame: F) -> Self {
+        Self {
+            frame,
+            options: Options {
+                placeholders: true,
+                ..Options::default()
+            },
+        }
+    }
+
+    /// Return the topmost frame of the exception tree.
+    pub fn frame(&self) -> &Frame {
+        self.frame.as_ref()
+    }
+
+    /// Set the formatting budget of this report.
+    ///
+    /// Defaults to the budget set with [`set_debug_budget`](crate::set_debug_budget).
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.options.budget = budget;
+        self
+    }
+
+    /// Set whether to keep the file of each location, e.g., `exn/src/lib.rs:<L>:<C>`. Defaults to
+    /// `false`.
+    ///
+    /// Files of the workspace being built are relative to the workspace root, and files of
+    /// dependencies are relative to the directory containing their package. See
+    /// [`SourceLocation::strip_workspace_prefix`](crate::SourceLocation::strip_workspace_prefix)
+    /// for more information.
+    pub fn with_files(mut self, files: bool) -> Self {
+        self.options.placeholder_files = files;
+        self
+    }
+
+    /// Set whether to write the [function](Frame::function) where each frame was created, if
+    /// known. Defaults to `false`.
+    pub fn with_functions(mut self, functions: bool) -> Self {
+        self.options.functions = functions;
+        self
+    }
+
+    /// Set whether to write the [type name](Frame::type_name) of the error of each frame.
+    /// Defaults to `false`.
+    pub fn with_type_names(mut self, type_names: bool) -> Self {
+        self.options.type_names = type_names;
+        self
+    }
+
+    /// Set whether to replace the error message and the attachments of
+    /// [sensitive](Exn::mark_sensitive) frames with `«redacted»`. Defaults to `false`.
+    pub fn with_redaction(mut self, redact: bool) -> Self {
+        self.options.redact = redact;
+        self
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Stable {
+    fn from(exn: Exn<E>) -> Self {
+        Stable::new(exn.into_boxed_frame())
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Debug for Stable<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.frame.as_ref(), &self.options)
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Display for Stable<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.frame.as_ref(), &self.options)
+    }
+}
+
+/// A report rendering the exception tree as an [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457)
+/// problem details JSON object, for the body of REST API error responses.
+///
+/// The object has the standard `type`, `title`, `status`, `detail` and `instance` members, where
+/// `detail` is the message of the top-level error, and, if enabled with
+/// [`with_tree`](ProblemDetails::with_tree), an `exception` extension member carrying the
+/// exception tree. Each frame of the tree is an object with the following members:
+///
+/// * `message`: the error message;
+/// * `type`: the stable name of the [registered type](crate::register_error_type) of the error,
+///   omitted for unregistered types;
+/// * `location`: the source code location of the frame, as `file:line:column`;
+/// * `attachments`: the attachments of the frame, each with its `message` and `location`;
+/// * `children`: the child frames, rendered in the same way.
+///
+/// Both `Debug` and `Display` write the JSON object, which should be served with the
+/// [`CONTENT_TYPE`](ProblemDetails::CONTENT_TYPE) media type.
+///
+/// # Examples
+///
+/// ```
+/// use exn::report::ProblemDetails;
+///
+/// let exn = exn::Exn::new(std::io::Error::other("user not found"));
+/// let report = ProblemDetails::new(exn.frame())
+///     .with_type("https://example.com/problems/not-found")
+///     .with_title("Not Found")
+///     .with_status(404)
+///     .with_instance("/users/42");
+///
+/// assert_eq!(
+///     report.to_string(),
+///     r#"{"type":"https://example.com/problems/not-found","title":"Not Found","status":404,"detail":"user not found","instance":"/users/42"}"#
+/// );
+/// ```
+pub struct ProblemDetails<F = Box<Frame>> {
+    frame: F,
+    problem_type: Cow<'static, str>,
+    title: Option<Cow<'static, str>>,
+    status: Option<u16>,
+    instance: Option<Cow<'static, str>>,
+    tree: bool,
+    redact: bool,
+}
+
+impl<F: AsRef<Frame>> ProblemDetails<F> {
+    /// The media type of problem details JSON objects.
+    pub const CONTENT_TYPE: &'static str = "application/problem+json";
+
+    /// Create a report of the exception tree rooted at `frame`.
+    pub fn new(frame: F) -> Self {
+        Self {
+            frame,
+            problem_type: Cow::Borrowed("about:blank"),
+            title: None,
+            status: None,
+            instance: None,
+            tree: false,
+            redact: true,
+        }
+    }
+
+    /// Return the topmost frame of the exception tree.
+    pub fn frame(&self) -> &Frame {
+        self.frame.as_ref()
+    }
+
+    /// Set the URI identifying the problem type. Defaults to `about:blank`.
+    pub fn with_type(mut self, problem_type: impl Into<Cow<'static, str>>) -> Self {
+        self.problem_type = problem_type.into();
+        self
+    }
+
+    /// Set the short, human-readable summary of the problem type. Omitted by default.
+    pub fn with_title(mut self, title: impl Into<Cow<'static, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the HTTP status code of the response. Omitted by default.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the URI identifying this occurrence of the problem, e.g., the request path. Omitted by
+    /// default.
+    pub fn with_instance(mut self, instance: impl Into<Cow<'static, str>>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    /// Set whether to write the `exception` extension member carrying the exception tree.
+    /// Defaults to `false`, since problem details are sent to clients.
+    ///
+    /// The tree reveals internals of the service, like messages and locations of all frames;
+    /// enable it for development builds or trusted clients only.
+    pub fn with_tree(mut self, tree: bool) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Set whether to replace the error message and the attachments of
+    /// [sensitive](Exn::mark_sensitive) frames with `«redacted»`. Defaults to `true`, unlike other
+    /// reports, since problem details are sent to clients.
+    pub fn with_redaction(mut self, redact: bool) -> Self {
+        self.redact = redact;
+        self
+    }
+
+    fn write_json(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{\"type\":")?;
+        write_json_string(f, &self.problem_type)?;
+        if let Some(title) = &self.title {
+            write!(f, ",\"title\":")?;
+            write_json_string(f, title)?;
+        }
+        if let Some(status) = self.status {
+            write!(f, ",\"status\":{status}")?;
+        }
+        write!(f, ",\"detail\":")?;
+        write_json_message(f, self.frame(), self.redact)?;
+        if let Some(instance) = &self.instance {
+            write!(f, ",\"instance\":")?;
+            write_json_string(f, instance)?;
+        }
+        if self.tree {
+            write!(f, ",\"exception\":")?;
+            write_json_frame(f, self.frame(), self.redact)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for ProblemDetails {
+    fn from(exn: Exn<E>) -> Self {
+        ProblemDetails::new(exn.into_boxed_frame())
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Debug for ProblemDetails<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_json(f)
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Display for ProblemDetails<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_json(f)
+    }
+}
+
+/// A report rendering the exception tree as a [Graphviz](https://graphviz.org) digraph, to
+/// visualize large aggregated failures, e.g., in postmortems of batch systems.
+///
+/// Each frame is a node labeled with its message and location, with an edge to each of its
+/// children. Suppressed exceptions are connected with dashed edges. Render the output with
+/// `dot -Tsvg`.
+///
+/// Both `Debug` and `Display` write the digraph.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::report::Dot;
+///
+/// let exn = Exn::raise_all(
+///     std::io::Error::other("batch failed"),
+///     [
+///         Exn::new(std::io::Error::other("item 1 timed out")),
+///         Exn::new(std::io::Error::other("item 7 timed out")),
+///     ],
+/// );
+///
+/// assert_eq!(
+///     Dot::new(exn.frame()).with_locations(false).to_string(),
+///     r#"digraph exn {
+///     node [shape=box];
+///     n0 [label="batch failed"];
+///     n1 [label="item 1 timed out"];
+///     n0 -> n1;
+///     n2 [label="item 7 timed out"];
+///     n0 -> n2;
+/// }
+/// "#
+/// );
+/// ```
+pub struct Dot<F = Box<Frame>> {
+    frame: F,
+    locations: bool,
+    redact: bool,
+}
+
+impl<F: AsRef<Frame>> Dot<F> {
+    /// Create a report of the exception tree rooted at `frame`.
+    pub fn new(frame: F) -> Self {
+        Self {
+            frame,
+            locations: true,
+            redact: false,
+        }
+    }
+
+    /// Return the topmost frame of the exception tree.
+    pub fn frame(&self) -> &Frame {
+        self.frame.as_ref()
+    }
+
+    /// Set whether to write the location of each frame under its message. Defaults to `true`.
+    pub fn with_locations(mut self, locations: bool) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Set whether to replace the error message of [sensitive](Exn::mark_sensitive) frames with
+    /// `«redacted»`. Defaults to `false`.
+    pub fn with_redaction(mut self, redact: bool) -> Self {
+        self.redact = redact;
+        self
+    }
+
+    fn write_dot(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph exn {{")?;
+        writeln!(f, "    node [shape=box];")?;
+        self.write_node(f, self.frame(), &mut 0)?;
+        writeln!(f, "}}")
+    }
+
+    /// Write the node of `frame` and its descendants, numbering them from `next`, and return the
+    /// number of the node of `frame`.
+    fn write_node(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        frame: &Frame,
+        next: &mut usize,
+    ) -> Result<usize, fmt::Error> {
+        let id = *next;
+        *next += 1;
+
+        write!(f, "    n{id} [label=\"")?;
+        if self.redact {
+            write!(DotEscape(f), "{}", frame.redacted_message())?;
+        } else {
+            write!(DotEscape(f), "{}", frame.error())?;
+        }
+        // frames walked from the source chain share the location of their parent
+        if let Some(location) = frame
+            .known_location()
+            .filter(|_| self.locations && frame.origin() != FrameOrigin::SourceChain)
+        {
+            write!(f, "\\n")?;
+            write!(DotEscape(f), "{location}")?;
+        }
+        writeln!(f, "\"];")?;
+
+        for child in frame.children() {
+            let child_id = self.write_node(f, child, next)?;
+            writeln!(f, "    n{id} -> n{child_id};")?;
+        }
+        for suppressed in frame.suppressed() {
+            let suppressed_id = self.write_node(f, suppressed, next)?;
+            writeln!(
+                f,
+                "    n{id} -> n{suppressed_id} [style=dashed, label=\"suppressed\"];"
+            )?;
+        }
+        Ok(id)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Dot {
+    fn from(exn: Exn<E>) -> Self {
+        Dot::new(exn.into_boxed_frame())
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Debug for Dot<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_dot(f)
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Display for Dot<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_dot(f)
+    }
+}
+
+/// A report rendering the exception tree as a flat list of causes, in the layout of the `Debug`
+/// output of [`anyhow::Error`](https://docs.rs/anyhow), to ease migrating tooling that parses
+/// that layout.
+///
+/// The message of the topmost error is followed by the errors of the rest of the
+/// [primary chain](Frame::primary_chain) in a `Caused by:` section, numbered if there is more
+/// than one. The other children of the frames of the primary chain follow in an `Additionally:`
+/// section, each written on one line as with the alternate `Display` format (`{:#}`).
+///
+/// Both `Debug` and `Display` write the list.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::report::Flat;
+///
+/// let exn = Exn::raise_all(
+///     std::io::Error::other("failed to sync"),
+///     [
+///         Exn::new(std::io::Error::other("disk full")),
+///         Exn::new(std::io::Error::other("timed out")),
+///     ],
+/// );
+/// let exn = exn.raise(std::io::Error::other("job failed"));
+///
+/// assert_eq!(
+///     Flat::new(exn.frame()).to_string(),
+///     "job failed
+///
+/// Caused by:
+///     0: failed to sync
+///     1: disk full
+///
+/// Additionally:
+///     timed out"
+/// );
+/// ```
+pub struct Flat<F = Box<Frame>> {
+    frame: F,
+    locations: bool,
+    redact: bool,
+}
+
+impl<F: AsRef<Frame>> Flat<F> {
+    /// Create a report of the exception tree rooted at `frame`.
+    pub fn new(frame: F) -> Self {
+        Self {
+            frame,
+            locations: false,
+            redact: false,
+        }
+    }
+
+    /// Return the topmost frame of the exception tree.
+    pub fn frame(&self) -> &Frame {
+        self.frame.as_ref()
+    }
+
+    /// Set whether to write the location of each frame after its message. Defaults to `false`,
+    /// like `anyhow`.
+    pub fn with_locations(mut self, locations: bool) -> Self {
+        self.locations = locations;
+        self
+    }
+
+    /// Set whether to replace the error message of [sensitive](Exn::mark_sensitive) frames with
+    /// `«redacted»`. Defaults to `false`.
+    pub fn with_redaction(mut self, redact: bool) -> Self {
+        self.redact = redact;
+        self
+    }
+
+    fn write_flat(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut chain = self.frame().primary_chain();
+        if let Some(frame) = chain.next() {
+            self.write_frame(f, frame)?;
+        }
+
+        let causes = chain.clone().count();
+        if causes > 0 {
+            write!(f, "\n\nCaused by:")?;
+            for (i, frame) in chain.enumerate() {
+                writeln!(f)?;
+                let number = Some(i).filter(|_| causes > 1);
+                self.write_frame(&mut FlatIndent::new(f, number), frame)?;
+            }
+        }
+
+        let siblings = self.frame().primary_chain().flat_map(|frame| {
+            let primary = frame.primary_child();
+            let children = frame.children().iter();
+            children.filter(move |child| !primary.is_some_and(|primary| ptr::eq(primary, *child)))
+        });
+        let count = siblings.clone().count();
+        if count > 0 {
+            write!(f, "\n\nAdditionally:")?;
+            for (i, sibling) in siblings.enumerate() {
+                writeln!(f)?;
+                let number = Some(i).filter(|_| count > 1);
+                let w = &mut FlatIndent::new(f, number);
+                for (j, frame) in sibling.primary_chain().enumerate() {
+                    if j > 0 {
+                        write!(w, ": ")?;
+                    }
+                    self.write_frame(w, frame)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_frame(&self, w: &mut dyn Write, frame: &Frame) -> fmt::Result {
+        if self.redact {
+            write!(w, "{}", frame.redacted_message())?;
+        } else {
+            write!(w, "{}", frame.error())?;
+        }
+        // frames walked from the source chain share the location of their parent
+        if let Some(location) = frame
+            .known_location()
+            .filter(|_| self.locations && frame.origin() != FrameOrigin::SourceChain)
+        {
+            write!(w, ", at {location}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Flat {
+    fn from(exn: Exn<E>) -> Self {
+        Flat::new(exn.into_boxed_frame())
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Debug for Flat<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_flat(f)
+    }
+}
+
+impl<F: AsRef<Frame>> fmt::Display for Flat<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_flat(f)
+    }
+}
+
+/// A writer indenting an entry of a [`Flat`] section on the fly, numbered if `number` is set, with
+/// continuation lines aligned under the first one.
+struct FlatIndent<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    number: Option<usize>,
+    started: bool,
+}
+
+impl<'a, 'b> FlatIndent<'a, 'b> {
+    fn new(f: &'a mut fmt::Formatter<'b>, number: Option<usize>) -> Self {
+        Self {
+            f,
+            number,
+            started: false,
+        }
+    }
+}
+
+impl Write for FlatIndent<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if !self.started {
+                self.started = true;
+                match self.number {
+                    Some(number) => write!(self.f, "{number: >5}: ")?,
+                    None => self.f.write_str("    ")?,
+                }
+            } else if i > 0 {
+                self.f.write_char('\n')?;
+                match self.number {
+                    Some(_) => self.f.write_str("       ")?,
+                    None => self.f.write_str("    ")?,
+                }
+            }
+            self.f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Display` adapter streaming the `Debug` output of an exception tree.
+///
+/// Unlike [`Frame::render_tree`], the rendering is written directly to the destination without
+/// building a `String`, which matters for huge aggregated trees, e.g., in `format_args!` or
+/// logging macros.
+///
+/// This struct is created by [`Exn::display_tree`] and [`Frame::display_tree`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayTree<'a> {
+    frame: &'a Frame,
+}
+
+impl fmt::Display for DisplayTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.frame)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return a `Display` adapter streaming the exception tree, the same as the `Debug` output.
+    ///
+    /// See [`DisplayTree`] for more information.
+    pub fn display_tree(&self) -> DisplayTree<'_> {
+        self.frame().display_tree()
+    }
+}
+
+impl Frame {
+    /// Return a `Display` adapter streaming the exception tree rooted at this frame, the same as
+    /// the `Debug` output.
+    ///
+    /// See [`DisplayTree`] for more information.
+    pub fn display_tree(&self) -> DisplayTree<'_> {
+        DisplayTree { frame: self }
+    }
+}
+
+/// Write the exception tree of `exn` to `out`, the same as the `Debug` output, without building
+/// intermediate strings.
+///
+/// # Examples
+///
+/// ```
+/// let exn = exn::Exn::new(std::io::Error::other("disk full"));
+///
+/// let mut out = Vec::new();
+/// exn::report::write_to(&exn, &mut out).unwrap();
+/// assert!(out.starts_with(b"disk full, at "));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn write_to<F, W>(exn: F, out: &mut W) -> std::io::Result<()>
+where
+    F: AsRef<Frame>,
+    W: std::io::Write + ?Sized,
+{
+    write!(out, "{:?}", exn.as_ref())
+}
+
+/// Write the exception tree of `exn` to `out`, the same as the `Debug` output, without building
+/// intermediate strings.
+///
+/// This is the [`fmt::Write`] counterpart of [`write_to`], also available without `std`.
+pub fn write_fmt_to<F, W>(exn: F, out: &mut W) -> fmt::Result
+where
+    F: AsRef<Frame>,
+    W: Write + ?Sized,
+{
+    write!(out, "{:?}", exn.as_ref())
+}
+
+/// A writer escaping the content of a quoted Graphviz string on the fly.
+struct DotEscape<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl Write for DotEscape<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' => self.0.write_str("\\\"")?,
+                '\\' => self.0.write_str("\\\\")?,
+                '\n' => self.0.write_str("\\n")?,
+                '\r' => {}
+                c => self.0.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Write the exception tree rooted at `frame` as a JSON object, as in the `exception` member of
+/// [`ProblemDetails`].
+pub(crate) fn write_json_frame(
+    f: &mut fmt::Formatter<'_>,
+    frame: &Frame,
+    redact: bool,
+) -> fmt::Result {
+    write!(f, "{{\"message\":")?;
+    write_json_message(f, frame, redact)?;
+    if let Some(error_type) = frame.error_type() {
+        write!(f, ",\"type\":")?;
+        write_json_string(f, error_type.name())?;
+    }
+    if let Some(location) = frame.known_location() {
+        write!(f, ",\"location\":")?;
+        write_json_string(f, location)?;
+    }
+    write!(f, ",\"attachments\":[")?;
+    let attachments = frame.attachments().iter().zip(frame.redacted_attachments());
+    for (i, (attachment, redacted)) in attachments.enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{{\"message\":")?;
+        if redact {
+            write_json_string(f, redacted)?;
+        } else {
+            write_json_string(f, attachment)?;
+        }
+        if let Some(location) = attachment.known_location() {
+            write!(f, ",\"location\":")?;
+            write_json_string(f, SourceLocation::from(location))?;
+        }
+        write!(f, "}}")?;
+    }
+    write!(f, "],\"children\":[")?;
+    for (i, child) in frame.children().iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write_json_frame(f, child, redact)?;
+    }
+    write!(f, "]}}")
+}
+
+fn write_json_message(f: &mut fmt::Formatter<'_>, frame: &Frame, redact: bool) -> fmt::Result {
+    if redact {
+        write_json_string(f, frame.redacted_message())
+    } else {
+        write_json_string(f, frame.error())
+    }
+}
+
+/// Write `value` as a JSON string, escaping its rendering on the fly.
+fn write_json_string(f: &mut fmt::Formatter<'_>, value: impl fmt::Display) -> fmt::Result {
+    struct Escape<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+    impl Write for Escape<'_, '_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for c in s.chars() {
+                match c {
+                    '"' => self.0.write_str("\\\"")?,
+                    '\\' => self.0.write_str("\\\\")?,
+                    '\n' => self.0.write_str("\\n")?,
+                    '\r' => self.0.write_str("\\r")?,
+                    '\t' => self.0.write_str("\\t")?,
+                    c if c.is_control() => write!(self.0, "\\u{:04x}", c as u32)?,
+                    c => self.0.write_char(c)?,
+                }
+            }
+            Ok(())
+        }
+    }
+
+    f.write_char('"')?;
+    write!(Escape(f), "{value}")?;
+    f.write_char('"')
+}
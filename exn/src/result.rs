@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use alloc::boxed::Box;
 use core::error::Error;
+use core::fmt;
+use core::panic::Location;
 
 use crate::Exn;
+use crate::Frame;
+use crate::LazyMessage;
 
 /// A reasonable return type to use throughout an application.
 pub type Result<T, E> = core::result::Result<T, Exn<E>>;
@@ -34,16 +39,125 @@ pub trait ResultExt {
     where
         A: Error + Send + Sync + 'static,
         F: FnOnce() -> A;
+
+    /// Raise a new exception of the same type on the [`Exn`] inside the [`Result`], only if
+    /// `predicate` returns `true` for its topmost frame.
+    ///
+    /// Apply [`Exn::raise_when`] on the `Err` variant, refer to it for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::ErrorKind;
+    ///
+    /// use exn::ResultExt;
+    ///
+    /// fn is_not_found(frame: &exn::Frame) -> bool {
+    ///     let error = frame.error().downcast_ref::<std::io::Error>();
+    ///     error.is_some_and(|error| error.kind() == ErrorKind::NotFound)
+    /// }
+    ///
+    /// // a missing file speaks for itself, other failures get context
+    /// let result = std::fs::read("/nonexistent/config.toml").or_raise_if(
+    ///     |frame| !is_not_found(frame),
+    ///     || std::io::Error::other("failed to read the config file"),
+    /// );
+    /// let exn = result.unwrap_err();
+    /// assert_eq!(exn.kind(), ErrorKind::NotFound);
+    /// assert!(exn.frame().children().is_empty());
+    /// ```
+    fn or_raise_if<P, F>(self, predicate: P, err: F) -> Result<Self::Success, Self::Error>
+    where
+        P: FnOnce(&Frame) -> bool,
+        F: FnOnce() -> Self::Error;
+
+    /// Attach a message to the [`Exn`] inside the [`Result`], e.g., "while processing row 42".
+    ///
+    /// Apply [`Exn::attach`] on the `Err` variant, refer to it for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::ResultExt;
+    ///
+    /// let row = 42;
+    /// let result = "NaN"
+    ///     .parse::<u32>()
+    ///     .or_attach(|| format!("while processing row {row}"));
+    /// let exn = result.unwrap_err();
+    /// assert_eq!(
+    ///     exn.frame().attachments()[0].to_string(),
+    ///     "while processing row 42"
+    /// );
+    /// ```
+    fn or_attach<M, F>(self, message: F) -> Result<Self::Success, Self::Error>
+    where
+        M: fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> M;
+
+    /// Record the location of the caller on the [`Exn`] inside the [`Result`], as an attachment
+    /// reading `propagated`.
+    ///
+    /// This leaves a breadcrumb where an exception passes through on its way up, without a
+    /// message of its own.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn attach_location(self) -> Result<Self::Success, Self::Error>
+    where
+        Self: Sized,
+    {
+        self.or_attach(|| "propagated")
+    }
+
+    /// Convert the `Err` variant into a boxed error, e.g., to return it from a `main` function
+    /// or a trait method requiring boxed errors.
+    ///
+    /// The box holds the whole exception tree, whose `Debug` output renders it as [`Exn`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::ResultExt;
+    ///
+    /// let port = "8080".parse::<u16>().boxed()?;
+    /// assert_eq!(port, 8080);
+    /// # Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
+    /// ```
+    fn boxed(self) -> core::result::Result<Self::Success, Box<dyn Error + Send + Sync + 'static>>;
+
+    /// Raise a new exception whose message is written by `message` only when it is formatted.
+    ///
+    /// This avoids building the message on hot error paths where the error is usually handled and
+    /// discarded. See [`LazyMessage`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::ResultExt;
+    ///
+    /// let key = 42;
+    /// let result = "NaN"
+    ///     .parse::<u32>()
+    ///     .or_raise_lazy(move |f| write!(f, "invalid value for key {key}"));
+    /// assert_eq!(result.unwrap_err().to_string(), "invalid value for key 42");
+    /// ```
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn or_raise_lazy<F>(self, message: F) -> Result<Self::Success, LazyMessage<F>>
+    where
+        Self: Sized,
+        F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync + 'static,
+    {
+        self.or_raise(|| LazyMessage::new(message))
+    }
 }
 
 impl<T, E> ResultExt for core::result::Result<T, E>
 where
-    E: Error + Send + Sync + 'static,
+    E: IntoExn,
 {
     type Success = T;
-    type Error = E;
+    type Error = E::Error;
 
-    #[track_caller]
+    #[cfg_attr(not(exn_no_location), track_caller)]
     fn or_raise<A, F>(self, err: F) -> Result<Self::Success, A>
     where
         A: Error + Send + Sync + 'static,
@@ -51,27 +165,135 @@ where
     {
         match self {
             Ok(v) => Ok(v),
-            Err(e) => Err(Exn::new(e).raise(err())),
+            Err(e) => Err(e.into_exn().raise(err())),
         }
     }
-}
 
-impl<T, E> ResultExt for core::result::Result<T, Exn<E>>
-where
-    E: Error + Send + Sync + 'static,
-{
-    type Success = T;
-    type Error = E;
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn or_raise_if<P, F>(self, predicate: P, err: F) -> Result<Self::Success, Self::Error>
+    where
+        P: FnOnce(&Frame) -> bool,
+        F: FnOnce() -> Self::Error,
+    {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into_exn().raise_when(predicate, err)),
+        }
+    }
 
-    #[track_caller]
-    fn or_raise<A, F>(self, err: F) -> Result<Self::Success, A>
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn boxed(self) -> core::result::Result<T, Box<dyn Error + Send + Sync + 'static>> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e.into_exn().into()),
+        }
+    }
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn or_attach<M, F>(self, message: F) -> Result<Self::Success, Self::Error>
     where
-        A: Error + Send + Sync + 'static,
-        F: FnOnce() -> A,
+        M: fmt::Display + Send + Sync + 'static,
+        F: FnOnce() -> M,
     {
         match self {
             Ok(v) => Ok(v),
-            Err(e) => Err(e.raise(err())),
+            Err(e) => Err(e.into_exn().attach(message())),
         }
     }
 }
+
+/// A value that can be raised as an [`Exn`]: either an error, or an exception already.
+///
+/// This trait is sealed and implemented for every `E: Error + Send + Sync + 'static` and every
+/// [`Exn<E>`]. It lets [`ResultExt`] and generic code accept both shapes uniformly.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::IntoExn;
+///
+/// fn log_and_propagate<E: IntoExn>(err: E) -> Exn<E::Error> {
+///     let exn = err.into_exn();
+///     eprintln!("{exn:?}");
+///     exn
+/// }
+///
+/// let io = std::io::Error::other("disk full");
+/// let exn = log_and_propagate(log_and_propagate(io));
+/// assert_eq!(exn.to_string(), "disk full");
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be raised as an exception",
+    label = "this is neither an error nor an `Exn`",
+    note = "errors must implement `core::error::Error + Send + Sync + 'static` to be raised"
+)]
+pub trait IntoExn: sealed::Sealed {
+    /// The type of the top-level error of the exception.
+    type Error: Error + Send + Sync + 'static;
+
+    /// Convert this value into an exception.
+    ///
+    /// An error is raised as a new exception located at the caller, and an exception is returned
+    /// unchanged.
+    fn into_exn(self) -> Exn<Self::Error>;
+
+    /// Convert this value into an exception, recording `function` on the new exception, if any.
+    #[doc(hidden)]
+    fn __into_exn_in(self, function: &'static str) -> Exn<Self::Error>;
+
+    /// Convert this value into an exception, locating the new exception, if any, at `location`.
+    #[doc(hidden)]
+    fn __into_exn_at(self, location: &'static Location<'static>) -> Exn<Self::Error>;
+}
+
+impl<E: Error + Send + Sync + 'static> IntoExn for E {
+    type Error = E;
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn into_exn(self) -> Exn<E> {
+        Exn::new(self)
+    }
+
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn __into_exn_in(self, function: &'static str) -> Exn<E> {
+        Exn::new(self).with_function(function)
+    }
+
+    fn __into_exn_at(self, location: &'static Location<'static>) -> Exn<E> {
+        Exn::new_at(self, location)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> IntoExn for Exn<E> {
+    type Error = E;
+
+    fn into_exn(self) -> Exn<E> {
+        self
+    }
+
+    fn __into_exn_in(self, _: &'static str) -> Exn<E> {
+        self
+    }
+
+    fn __into_exn_at(self, _: &'static Location<'static>) -> Exn<E> {
+        self
+    }
+}
+
+mod sealed {
+    use core::error::Error;
+
+    use crate::Exn;
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` cannot be raised as an exception",
+        label = "this is neither an error nor an `Exn`",
+        note = "errors must implement `core::error::Error + Send + Sync + 'static` to be raised"
+    )]
+    pub trait Sealed {}
+
+    impl<E: Error + Send + Sync + 'static> Sealed for E {}
+
+    impl<E: Error + Send + Sync + 'static> Sealed for Exn<E> {}
+}
@@ -0,0 +1,117 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::any::TypeId;
+use core::error::Error;
+use core::time::Duration;
+
+use crate::Exn;
+use crate::Frame;
+use crate::registry::Registry;
+
+/// An error that tells whether the failed operation may be retried, and when.
+///
+/// Error types implementing this trait must be [registered](crate#registering-error-types) with
+/// [`register_retryable`] before their hints can be found in an exception tree.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use exn::Retryable;
+///
+/// #[derive(Debug)]
+/// struct RateLimited;
+///
+/// impl core::fmt::Display for RateLimited {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "rate limited")
+///     }
+/// }
+///
+/// impl core::error::Error for RateLimited {}
+///
+/// impl Retryable for RateLimited {
+///     fn retry_after(&self) -> Option<Duration> {
+///         Some(Duration::from_secs(1))
+///     }
+/// }
+///
+/// exn::register_retryable::<RateLimited>();
+///
+/// let exn = exn::Exn::new(RateLimited);
+/// assert_eq!(exn.retry_hint(), Some(Duration::from_secs(1)));
+/// ```
+pub trait Retryable: Error + Send + Sync + 'static {
+    /// Return the delay to wait before retrying, or `None` if the operation must not be retried.
+    ///
+    /// Return [`Duration::ZERO`] if the operation may be retried immediately.
+    fn retry_after(&self) -> Option<Duration>;
+}
+
+/// Register the error type `T` so that its hints are reported by [`Exn::retry_hint`] and
+/// [`Frame::retry_after`].
+///
+/// Registering the same type more than once has no further effect.
+pub fn register_retryable<T: Retryable>() {
+    fn retry_after_of<T: Retryable>(error: &(dyn Error + 'static)) -> Option<Option<Duration>> {
+        error.downcast_ref::<T>().map(T::retry_after)
+    }
+
+    REGISTRY.register(TypeId::of::<T>(), retry_after_of::<T>);
+}
+
+type RetryAfterFn = fn(&(dyn Error + 'static)) -> Option<Option<Duration>>;
+
+static REGISTRY: Registry<RetryAfterFn> = Registry::new();
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return the delay to wait before retrying the operation that raised this exception, or
+    /// `None` if it must not be retried.
+    ///
+    /// Every frame in the exception tree whose error implements [`Retryable`] is queried. The
+    /// operation must not be retried if any of them forbids it; otherwise it may be retried if any
+    /// of them allows it, after the longest delay they requested, so that a retry honors every
+    /// layer that asked to back off.
+    pub fn retry_hint(&self) -> Option<Duration> {
+        let mut hint = None;
+        for frame in self.frames() {
+            match frame.retry_verdict() {
+                Some(None) => return None,
+                Some(Some(delay)) => hint = hint.max(Some(delay)),
+                None => {}
+            }
+        }
+        hint
+    }
+}
+
+impl Frame {
+    /// Return the delay to wait before retrying, as reported by the error that occurred at this
+    /// frame.
+    ///
+    /// Returns `None` if the error must not be retried, or if its type does not implement
+    /// [`Retryable`] or has not been registered with [`register_retryable`].
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_verdict().flatten()
+    }
+
+    /// Return the hint of the error that occurred at this frame, or `None` if its type has not
+    /// been registered with [`register_retryable`].
+    fn retry_verdict(&self) -> Option<Option<Duration>> {
+        let error = self.error() as &(dyn Error + 'static);
+        REGISTRY.entries().find_map(|entry| (entry.value)(error))
+    }
+}
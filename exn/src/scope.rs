@@ -0,0 +1,129 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::sync::Arc;
+use core::cell::RefCell;
+use core::fmt;
+use std::vec::Vec;
+
+use crate::Frame;
+use crate::location::StoredLocation;
+
+/// An entry of the context stack, attached to exceptions created within its scope.
+#[derive(Clone)]
+pub(crate) struct ScopeEntry {
+    message: Arc<dyn fmt::Display + Send + Sync>,
+    location: StoredLocation,
+}
+
+impl ScopeEntry {
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub(crate) fn new<M: fmt::Display + Send + Sync + 'static>(message: M) -> Self {
+        Self {
+            message: Arc::new(message),
+            location: StoredLocation::new(crate::location::caller()),
+        }
+    }
+
+    fn attach_to(&self, frame: &mut Frame) {
+        frame.push_attachment(ScopeMessage(self.message.clone()), self.location.get());
+    }
+}
+
+struct ScopeMessage(Arc<dyn fmt::Display + Send + Sync>);
+
+impl fmt::Display for ScopeMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+std::thread_local! {
+    static CONTEXT: RefCell<Vec<ScopeEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `body` with `message` on the context stack of the current thread.
+///
+/// While `body` runs, `message` is attached to every exception created with [`Exn::new`] and the
+/// functions built on it, like [`ErrorExt::raise`] and [`ResultExt::or_raise`] on plain errors,
+/// located where the scope was entered. This gives deep call stacks breadcrumbs without threading
+/// values through every signature. Exceptions raised on existing ones are left alone, since their
+/// children already carry the breadcrumbs.
+///
+/// Scopes nest: the messages of all enclosing scopes are attached, the outermost first. The stack
+/// belongs to the current thread; use [`task::context_scope`] for futures, which can move between
+/// threads.
+///
+/// [`Exn::new`]: crate::Exn::new
+/// [`ErrorExt::raise`]: crate::ErrorExt::raise
+/// [`ResultExt::or_raise`]: crate::ResultExt::or_raise
+/// [`task::context_scope`]: crate::task::context_scope
+///
+/// # Examples
+///
+/// ```
+/// use exn::ResultExt;
+///
+/// fn parse(line: &str) -> exn::Result<u32, std::io::Error> {
+///     line.parse()
+///         .or_raise(|| std::io::Error::other("invalid number"))
+/// }
+///
+/// let exn = exn::context_scope("while importing prices.csv", || parse("NaN")).unwrap_err();
+/// let leaf = &exn.frame().children()[0];
+/// assert_eq!(
+///     leaf.attachments()[0].to_string(),
+///     "while importing prices.csv"
+/// );
+/// ```
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn context_scope<M, F, T>(message: M, body: F) -> T
+where
+    M: fmt::Display + Send + Sync + 'static,
+    F: FnOnce() -> T,
+{
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            CONTEXT.with(|context| context.borrow_mut().pop());
+        }
+    }
+
+    let entry = ScopeEntry::new(message);
+    CONTEXT.with(|context| context.borrow_mut().push(entry));
+    let _guard = Guard;
+    body()
+}
+
+/// Return the entries of the context scopes of the current thread, the outermost first.
+#[cfg(feature = "tokio")]
+pub(crate) fn thread_context() -> Vec<ScopeEntry> {
+    CONTEXT
+        .try_with(|context| context.borrow().clone())
+        .unwrap_or_default()
+}
+
+/// Attach the entries of the active context scopes to `frame`.
+pub(crate) fn attach_scopes(frame: &mut Frame) {
+    #[cfg(feature = "tokio")]
+    crate::task::with_task_context(|entries| {
+        entries.iter().for_each(|entry| entry.attach_to(frame));
+    });
+    // the thread may be torn down, in which case there is no context left to attach
+    let _ = CONTEXT.try_with(|context| {
+        let context = context.borrow();
+        context.iter().for_each(|entry| entry.attach_to(frame));
+    });
+}
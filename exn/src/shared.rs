@@ -0,0 +1,144 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::error::Error;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::Exn;
+use crate::Frame;
+
+/// A reference-counted exception that can be cloned cheaply.
+///
+/// Cloning a `SharedExn` only increments a reference count, so the same exception tree can be
+/// reported to multiple sinks, e.g., metrics, tracing and a response body, without deep-cloning the
+/// tree or rendering it to strings first.
+///
+/// This struct is created by [`Exn::into_shared`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::SharedExn;
+///
+/// #[derive(Debug)]
+/// struct Error;
+///
+/// impl core::fmt::Display for Error {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "something failed")
+///     }
+/// }
+///
+/// impl core::error::Error for Error {}
+///
+/// let shared: SharedExn<Error> = Exn::new(Error).into_shared();
+/// let for_metrics = shared.clone();
+///
+/// assert_eq!(for_metrics.to_string(), "something failed");
+/// assert!(core::ptr::eq(shared.frame(), for_metrics.frame()));
+/// ```
+pub struct SharedExn<E: Error + Send + Sync + 'static> {
+    frame: Arc<Frame>,
+    phantom: PhantomData<E>,
+}
+
+impl<E: Error + Send + Sync + 'static> SharedExn<E> {
+    /// Return the underlying exception frame.
+    pub fn frame(&self) -> &Frame {
+        &self.frame
+    }
+
+    /// Return the underlying exception frame as a reference-counted pointer.
+    pub fn to_arc_frame(&self) -> Arc<Frame> {
+        self.frame.clone()
+    }
+
+    /// Return the number of clones of this exception, including itself.
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.frame)
+    }
+
+    /// Recover the unique [`Exn`] if this is the only clone of the exception.
+    ///
+    /// Otherwise, the shared exception is returned unchanged as the error.
+    pub fn try_unwrap(self) -> Result<Exn<E>, Self> {
+        match Arc::try_unwrap(self.frame) {
+            Ok(frame) => Ok(Exn::from_boxed_frame(Box::new(frame))),
+            Err(frame) => Err(Self {
+                frame,
+                phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Convert this exception into a [`SharedExn`] that can be cloned cheaply.
+    pub fn into_shared(self) -> SharedExn<E> {
+        SharedExn {
+            frame: Arc::from(self.into_boxed_frame()),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for SharedExn<E> {
+    fn from(exn: Exn<E>) -> Self {
+        exn.into_shared()
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Clone for SharedExn<E> {
+    fn clone(&self) -> Self {
+        Self {
+            frame: self.frame.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Deref for SharedExn<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        self.frame
+            .error()
+            .downcast_ref()
+            .expect("error type must match")
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> AsRef<Frame> for SharedExn<E> {
+    fn as_ref(&self) -> &Frame {
+        &self.frame
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> fmt::Debug for SharedExn<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.frame, f)
+    }
+}
+
+/// See the `Display` implementation of [`Exn`] for the alternate format.
+impl<E: Error + Send + Sync + 'static> fmt::Display for SharedExn<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.frame, f)
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::panic::Location;
+use std::sync::RwLock;
+
+use crate::Frame;
+
+static SOURCES: RwLock<Vec<(&'static str, &'static str)>> = RwLock::new(Vec::new());
+
+/// Register the contents of the source file `file`, so that snippets of it can be rendered
+/// without reading it at runtime.
+///
+/// `file` must be the path recorded in locations, i.e., the output of [`file!`] in that file.
+/// This is useful for binaries deployed without their sources, by embedding the relevant files
+/// with [`include_str!`]. Registering the same file more than once has no further effect.
+///
+/// # Examples
+///
+/// ```
+/// exn::register_source("src/main.rs", "fn main() {\n    run().unwrap();\n}\n");
+/// ```
+///
+/// In a binary, embed the file itself with
+/// `exn::register_source(file!(), include_str!("main.rs"))`.
+#[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+pub fn register_source(file: &'static str, source: &'static str) {
+    let mut sources = SOURCES.write().unwrap_or_else(|err| err.into_inner());
+    if !sources.iter().any(|(f, _)| *f == file) {
+        sources.push((file, source));
+    }
+}
+
+fn source_of(file: &'static str) -> Option<Cow<'static, str>> {
+    let sources = SOURCES.read().unwrap_or_else(|err| err.into_inner());
+    if let Some((_, source)) = sources.iter().find(|(f, _)| *f == file) {
+        return Some(Cow::Borrowed(source));
+    }
+    drop(sources);
+    std::fs::read_to_string(file).ok().map(Cow::Owned)
+}
+
+impl Frame {
+    /// Render the lines of source code around the location of this frame, with a caret under the
+    /// column where the frame was created.
+    ///
+    /// `context` is the number of lines written before and after the line of the location.
+    /// The source file is looked up among those registered with [`register_source`], then read
+    /// from disk relative to the current directory; `None` is returned if it cannot be found or
+    /// does not contain the location.
+    ///
+    /// Use [`report::Compact::with_snippets`](crate::report::Compact::with_snippets) to render
+    /// snippets within the exception tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let exn = exn::Exn::new(std::fmt::Error);
+    ///
+    /// // e.g., `12 |     let exn = exn::Exn::new(std::fmt::Error);` and a caret line
+    /// if let Some(snippet) = exn.frame().source_snippet(0) {
+    ///     eprintln!("{snippet}");
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "diagnostics")))]
+    pub fn source_snippet(&self, context: usize) -> Option<String> {
+        snippet(self.location(), context)
+    }
+}
+
+/// Render the lines of source code around `location`. See [`Frame::source_snippet`].
+pub(crate) fn snippet(location: &'static Location<'static>, context: usize) -> Option<String> {
+    let source = source_of(location.file())?;
+    let line = usize::try_from(location.line()).ok()?.checked_sub(1)?;
+    let lines = source.lines().collect::<Vec<_>>();
+    let target = *lines.get(line)?;
+
+    let first = line.saturating_sub(context);
+    let last = line.saturating_add(context).min(lines.len() - 1);
+    let gutter = (last + 1).to_string().len();
+
+    let mut output = String::new();
+    for (i, code) in lines[first..=last].iter().enumerate() {
+        let number = first + i + 1;
+        let _ = writeln!(
+            output,
+            "{number:>gutter$} |{}{code}",
+            if code.is_empty() { "" } else { " " }
+        );
+        if first + i == line {
+            // keep tabs so that the caret lines up with the code
+            let column = usize::try_from(location.column()).unwrap_or(1).max(1);
+            let padding = target
+                .chars()
+                .take(column - 1)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+            let _ = writeln!(output, "{:gutter$} | {padding}^", "");
+        }
+    }
+    output.pop();
+    Some(output)
+}
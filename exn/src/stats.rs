@@ -0,0 +1,144 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use core::error::Error;
+
+use crate::Exn;
+use crate::Frame;
+
+/// Size metrics of an exception tree, e.g., to emit metrics about errors or to guard against
+/// pathological trees before rendering them.
+///
+/// The metrics cover suppressed exceptions, unlike [`Frame::frames`].
+///
+/// This struct is created by [`Exn::stats`] and [`Frame::stats`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+///
+/// let exn = Exn::raise_all(
+///     std::io::Error::other("failed to sync"),
+///     [
+///         Exn::new(std::io::Error::other("disk full")),
+///         Exn::new(std::io::Error::other("timed out")),
+///     ],
+/// );
+///
+/// let stats = exn.stats();
+/// assert_eq!(stats.frame_count(), 3);
+/// assert_eq!(stats.depth(), 2);
+/// assert_eq!(stats.type_counts()["std::io::error::Error"], 3);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeStats {
+    frame_count: usize,
+    depth: usize,
+    type_counts: BTreeMap<&'static str, usize>,
+}
+
+impl TreeStats {
+    /// Return the number of frames in the tree.
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Return the number of frames on the longest path from the topmost frame down to a leaf.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Return the number of frames per error type.
+    ///
+    /// Error types are identified by the stable name of their
+    /// [registered type](crate::register_error_type), or else by their
+    /// [type name](Frame::type_name). Frames whose type is unknown, like those walked from a
+    /// source chain, are not counted here.
+    pub fn type_counts(&self) -> &BTreeMap<&'static str, usize> {
+        &self.type_counts
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Return the number of frames in the exception tree, including suppressed exceptions.
+    ///
+    /// See [`Frame::frame_count`] for more information.
+    pub fn frame_count(&self) -> usize {
+        self.frame().frame_count()
+    }
+
+    /// Return the number of frames on the longest path from the topmost frame down to a leaf.
+    ///
+    /// See [`Frame::depth`] for more information.
+    pub fn depth(&self) -> usize {
+        self.frame().depth()
+    }
+
+    /// Return size metrics of the exception tree.
+    ///
+    /// See [`TreeStats`] for more information.
+    pub fn stats(&self) -> TreeStats {
+        self.frame().stats()
+    }
+}
+
+impl Frame {
+    /// Return the number of frames in the tree rooted at this frame, including suppressed
+    /// exceptions.
+    ///
+    /// The count is kept up to date as the tree grows, so this takes constant time.
+    pub fn frame_count(&self) -> usize {
+        self.cached_frame_count()
+    }
+
+    /// Return the number of frames on the longest path from this frame down to a leaf, including
+    /// suppressed exceptions. A frame without children has depth 1.
+    ///
+    /// The tree is walked without recursion, so this is safe to call on pathologically deep trees.
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        walk(self, |_, level| depth = depth.max(level));
+        depth
+    }
+
+    /// Return size metrics of the tree rooted at this frame.
+    ///
+    /// See [`TreeStats`] for more information.
+    pub fn stats(&self) -> TreeStats {
+        let mut stats = TreeStats::default();
+        walk(self, |frame, level| {
+            stats.frame_count += 1;
+            stats.depth = stats.depth.max(level);
+            let error_type = frame.error_type().map(|error_type| error_type.name());
+            if let Some(name) = error_type.or(frame.type_name()) {
+                *stats.type_counts.entry(name).or_default() += 1;
+            }
+        });
+        stats
+    }
+}
+
+/// Visit each frame of the tree rooted at `frame`, including suppressed exceptions, with its
+/// level, the topmost frame having level 1.
+fn walk(frame: &Frame, mut visit: impl FnMut(&Frame, usize)) {
+    let mut stack = vec![(frame, 1)];
+    while let Some((frame, level)) = stack.pop() {
+        visit(frame, level);
+        let children = frame.children().iter().chain(frame.suppressed());
+        stack.extend(children.map(|child| (child, level + 1)));
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec::Vec;
+use core::error::Error;
+use core::future::poll_fn;
+use core::pin::pin;
+
+use futures_core::Stream;
+
+use crate::Exn;
+use crate::IntoExn;
+use crate::Result;
+
+/// An extension trait for [`Stream`]s of [`Result`]s to aggregate their failures into one
+/// [`Exn`].
+pub trait StreamExt: Stream<Item = core::result::Result<Self::Success, Self::Failure>> {
+    /// The `Ok` type of the items.
+    type Success;
+
+    /// The `Err` type of the items, either an error or an [`Exn`].
+    type Failure: IntoExn;
+
+    /// Drain the stream, collecting the successful items into `C`.
+    ///
+    /// If any item failed, a new exception is raised with all the failures as its children, in
+    /// the order they were yielded, like [`Exn::raise_all`]. The new exception is located at the
+    /// caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::StreamExt;
+    ///
+    /// # #[derive(Debug)]
+    /// # struct ImportError;
+    /// # impl core::fmt::Display for ImportError {
+    /// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    /// #         write!(f, "failed to import rows")
+    /// #     }
+    /// # }
+    /// # impl core::error::Error for ImportError {}
+    /// let rows = futures::stream::iter(["1", "x", "3", "y"].map(str::parse::<u32>));
+    /// let future = rows.collect_all_exn::<Vec<_>, _, _>(|| ImportError);
+    /// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// let exn = runtime.block_on(future).unwrap_err();
+    /// assert_eq!(exn.frame().children().len(), 2);
+    /// ```
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    fn collect_all_exn<C, A, F>(self, err: F) -> impl Future<Output = Result<C, A>>
+    where
+        Self: Sized,
+        C: Default + Extend<Self::Success>,
+        A: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        let location = crate::location::caller();
+        async move {
+            let mut stream = pin!(self);
+            let mut output = C::default();
+            let mut failures = Vec::new();
+            while let Some(item) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                match item {
+                    Ok(v) => output.extend(Some(v)),
+                    Err(e) => failures.push(e.__into_exn_at(location)),
+                }
+            }
+            if failures.is_empty() {
+                Ok(output)
+            } else {
+                Err(Exn::raise_all_at(err(), failures, location))
+            }
+        }
+    }
+}
+
+impl<S, T, E> StreamExt for S
+where
+    S: Stream<Item = core::result::Result<T, E>>,
+    E: IntoExn,
+{
+    type Success = T;
+    type Failure = E;
+}
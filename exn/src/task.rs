@@ -0,0 +1,290 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to run tasks on the [`tokio`] runtime and report their failures as exceptions.
+//!
+//! A task can fail by returning an exception, by panicking, or by being cancelled. The helpers in
+//! this module report all of them as an [`Exn<TaskError>`] located where the task was spawned, so
+//! failures of background work read the same as failures of the code awaiting it.
+
+use alloc::string::String;
+use alloc::string::ToString;
+use core::error::Error;
+use core::fmt;
+use core::panic::Location;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use std::any::Any;
+use std::boxed::Box;
+use std::vec::Vec;
+
+use crate::Exn;
+use crate::Result;
+use crate::scope::ScopeEntry;
+
+/// An error raised when a task spawned by this module fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TaskError {
+    /// The task returned an exception, which is the child of this error.
+    Failed,
+    /// The task panicked with the given message.
+    Panicked(String),
+    /// The task was cancelled before it completed.
+    Cancelled,
+}
+
+impl fmt::Display for TaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskError::Failed => write!(f, "task failed"),
+            TaskError::Panicked(message) => write!(f, "task panicked: {message}"),
+            TaskError::Cancelled => write!(f, "task was cancelled"),
+        }
+    }
+}
+
+impl Error for TaskError {}
+
+/// Spawn the future `future` on the [`tokio`] runtime, like [`tokio::spawn`].
+///
+/// The task starts running immediately. Awaiting the returned [`JoinHandle`] resolves to its
+/// output, or to an [`Exn<TaskError>`] located at the caller if the task returned an exception
+/// (which becomes the child of the new one), panicked, or was cancelled. Thus, an error raised
+/// deep inside the task shows where the task was launched from, not only where it was awaited.
+///
+/// The task inherits the [context scopes](context_scope) of the current task and thread.
+///
+/// # Panics
+///
+/// Panics if called outside of a [`tokio`] runtime.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+///
+/// # #[derive(Debug)]
+/// # struct FetchError;
+/// # impl core::fmt::Display for FetchError {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// #         write!(f, "failed to fetch page")
+/// #     }
+/// # }
+/// # impl core::error::Error for FetchError {}
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let handle = exn::task::spawn(async { exn::Result::<(), _>::Err(Exn::new(FetchError)) });
+///
+/// let exn = handle.await.unwrap_err();
+/// assert_eq!(format!("{exn:#}"), "task failed: failed to fetch page");
+/// # });
+/// ```
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn spawn<F, T, E>(future: F) -> JoinHandle<T, E>
+where
+    F: Future<Output = Result<T, E>> + Send + 'static,
+    T: Send + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    JoinHandle {
+        handle: tokio::spawn(CONTEXT.scope(inherited_context(), future)),
+        location: crate::location::caller(),
+    }
+}
+
+/// An owned permission to await a task spawned by [`spawn`].
+///
+/// Dropping the handle detaches the task; it keeps running in the background.
+pub struct JoinHandle<T, E: Error + Send + Sync + 'static> {
+    handle: tokio::task::JoinHandle<Result<T, E>>,
+    location: &'static Location<'static>,
+}
+
+impl<T, E: Error + Send + Sync + 'static> JoinHandle<T, E> {
+    /// Cancel the task. Awaiting the handle afterward resolves to [`TaskError::Cancelled`] unless
+    /// the task has already completed.
+    pub fn abort(&self) {
+        self.handle.abort();
+    }
+
+    /// Return `true` if the task has finished.
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Return the location where the task was spawned.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl<T, E: Error + Send + Sync + 'static> fmt::Debug for JoinHandle<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("id", &self.handle.id())
+            .field("location", &self.location)
+            .finish()
+    }
+}
+
+impl<T, E: Error + Send + Sync + 'static> Future for JoinHandle<T, E> {
+    type Output = Result<T, TaskError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let location = self.location;
+        Pin::new(&mut self.handle)
+            .poll(cx)
+            .map(|result| join(result, location))
+    }
+}
+
+/// Run the blocking closure `f` on the thread pool of the [`tokio`] runtime, like
+/// [`tokio::task::spawn_blocking`].
+///
+/// The closure starts running immediately. The returned future resolves to its output, or to an
+/// [`Exn<TaskError>`] located at the caller if the closure returned an exception (which becomes
+/// the child of the new one), panicked, or was cancelled.
+///
+/// The closure inherits the [context scopes](context_scope) of the current task and thread.
+///
+/// # Panics
+///
+/// Panics if called outside of a [`tokio`] runtime.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+///
+/// # #[derive(Debug)]
+/// # struct HashError;
+/// # impl core::fmt::Display for HashError {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// #         write!(f, "failed to hash password")
+/// #     }
+/// # }
+/// # impl core::error::Error for HashError {}
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let result =
+///     exn::task::spawn_blocking(|| -> exn::Result<(), HashError> { Err(Exn::new(HashError)) })
+///         .await;
+///
+/// let exn = result.unwrap_err();
+/// assert_eq!(format!("{exn:#}"), "task failed: failed to hash password");
+/// # });
+/// ```
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn spawn_blocking<F, T, E>(f: F) -> impl Future<Output = Result<T, TaskError>> + Send
+where
+    F: FnOnce() -> Result<T, E> + Send + 'static,
+    T: Send + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    let location = crate::location::caller();
+    let entries = inherited_context();
+    let handle = tokio::task::spawn_blocking(move || CONTEXT.sync_scope(entries, f));
+    async move { join(handle.await, location) }
+}
+
+tokio::task_local! {
+    static CONTEXT: Vec<ScopeEntry>;
+}
+
+/// Run `future` with `message` on the context stack of the current task.
+///
+/// This is the counterpart of [`context_scope`](crate::context_scope) for futures, which can move
+/// between threads at each `.await`: while `future` runs, `message` is attached to every exception
+/// created with [`Exn::new`] and the functions built on it. Scopes nest with each other and with
+/// the scopes of the current thread, which are attached after those of the task.
+///
+/// Tasks spawned within the scope with [`spawn`] and [`spawn_blocking`] inherit it; those spawned
+/// with [`tokio::spawn`] do not.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ResultExt;
+///
+/// async fn parse(line: &str) -> exn::Result<u32, std::io::Error> {
+///     tokio::task::yield_now().await;
+///     line.parse()
+///         .or_raise(|| std::io::Error::other("invalid number"))
+/// }
+///
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let exn = exn::task::context_scope("while importing prices.csv", parse("NaN"))
+///     .await
+///     .unwrap_err();
+/// let leaf = &exn.frame().children()[0];
+/// assert_eq!(
+///     leaf.attachments()[0].to_string(),
+///     "while importing prices.csv"
+/// );
+/// # });
+/// ```
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn context_scope<M, F>(message: M, future: F) -> impl Future<Output = F::Output>
+where
+    M: fmt::Display + Send + Sync + 'static,
+    F: Future,
+{
+    let mut entries = CONTEXT.try_with(Vec::clone).unwrap_or_default();
+    entries.push(ScopeEntry::new(message));
+    CONTEXT.scope(entries, future)
+}
+
+/// Return the entries of the context scopes of the current task and thread, in the order they are
+/// attached, for a task spawned from here to run with.
+fn inherited_context() -> Vec<ScopeEntry> {
+    let mut entries = CONTEXT.try_with(Vec::clone).unwrap_or_default();
+    entries.extend(crate::scope::thread_context());
+    entries
+}
+
+/// Call `f` with the entries of the context scopes of the current task, if any.
+pub(crate) fn with_task_context(f: impl FnOnce(&[ScopeEntry])) {
+    let _ = CONTEXT.try_with(|entries| f(entries));
+}
+
+fn join<T, E>(
+    result: core::result::Result<Result<T, E>, tokio::task::JoinError>,
+    location: &'static Location<'static>,
+) -> Result<T, TaskError>
+where
+    E: Error + Send + Sync + 'static,
+{
+    match result {
+        Ok(Ok(output)) => Ok(output),
+        Ok(Err(exn)) => Err(exn.raise_at(TaskError::Failed, location)),
+        Err(err) if err.is_panic() => {
+            let message = panic_message(err.into_panic());
+            Err(Exn::new_at(TaskError::Panicked(message), location))
+        }
+        Err(_) => Err(Exn::new_at(TaskError::Cancelled, location)),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast_ref::<&'static str>() {
+            Some(message) => message.to_string(),
+            None => "Box<dyn Any>".to_string(),
+        },
+    }
+}
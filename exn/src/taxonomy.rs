@@ -0,0 +1,109 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::any::TypeId;
+use core::error::Error;
+use core::fmt;
+
+use crate::Frame;
+use crate::registry::Registry;
+
+/// A known error type, registered with a stable name and a description.
+///
+/// [Type names](Frame::type_name) change whenever an error type is renamed or moved, which breaks
+/// alerting rules and dashboards matching on them. Registered error types are identified by their
+/// stable name instead in serialized reports, such as
+/// [`ProblemDetails`](crate::report::ProblemDetails).
+///
+/// This struct is created by [`register_error_type`] and returned by [`Frame::error_type`].
+///
+/// # Examples
+///
+/// ```
+/// #[derive(Debug)]
+/// struct QuotaExceeded;
+///
+/// impl core::fmt::Display for QuotaExceeded {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "quota exceeded")
+///     }
+/// }
+///
+/// impl core::error::Error for QuotaExceeded {}
+///
+/// exn::register_error_type::<QuotaExceeded>(
+///     "billing.quota_exceeded",
+///     "The account has used up its quota for the billing period.",
+/// );
+///
+/// let exn = exn::Exn::new(QuotaExceeded);
+/// let error_type = exn.frame().error_type().unwrap();
+/// assert_eq!(error_type.name(), "billing.quota_exceeded");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ErrorType {
+    name: &'static str,
+    description: &'static str,
+}
+
+impl ErrorType {
+    /// Return the stable name of the error type.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the description of the error type.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+}
+
+impl fmt::Display for ErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Register the error type `T` with a stable `name` and a `description`, so that it is reported
+/// by [`Frame::error_type`].
+///
+/// Registering the same type more than once has no further effect; the first name is kept.
+pub fn register_error_type<T: Error + Send + Sync + 'static>(
+    name: &'static str,
+    description: &'static str,
+) {
+    fn is<T: Error + 'static>(error: &(dyn Error + 'static)) -> bool {
+        error.is::<T>()
+    }
+
+    let error_type = ErrorType { name, description };
+    REGISTRY.register(TypeId::of::<T>(), (is::<T>, error_type));
+}
+
+type IsFn = fn(&(dyn Error + 'static)) -> bool;
+
+static REGISTRY: Registry<(IsFn, ErrorType)> = Registry::new();
+
+impl Frame {
+    /// Return the registered type of the error that occurred at this frame.
+    ///
+    /// Returns `None` if the error type has not been registered with [`register_error_type`].
+    pub fn error_type(&self) -> Option<ErrorType> {
+        let error = self.error() as &(dyn Error + 'static);
+        REGISTRY
+            .entries()
+            .find(|entry| (entry.value.0)(error))
+            .map(|entry| entry.value.1)
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::ErrorCode;
+use crate::Exn;
+use crate::register_error_code;
+
+/// An entry of a static error table, mapping an error code to a message template.
+///
+/// The template may refer to named arguments as `{name}`, which are filled in from the
+/// [`ErrorArgs`] given to [`Exn::from_code`]. Write `{{` and `}}` for literal braces. Placeholders
+/// without a matching argument are written verbatim.
+///
+/// Since templates can be built in `const` contexts, a service can declare all its errors in one
+/// place and guarantee that every error raised from them has a registered code and consistent
+/// wording.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ErrorTemplate;
+/// use exn::Exn;
+///
+/// struct Code;
+///
+/// impl Code {
+///     const DB_NOT_FOUND: ErrorTemplate = ErrorTemplate::new("DB0404", "record {id} not found");
+/// }
+///
+/// let id = 42;
+/// let exn = Exn::from_code(&Code::DB_NOT_FOUND, exn::args! {id});
+/// assert_eq!(exn.to_string(), "record 42 not found");
+/// assert_eq!(exn.code(), Some("DB0404"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ErrorTemplate {
+    code: &'static str,
+    message: &'static str,
+}
+
+impl ErrorTemplate {
+    /// Create a new template for the given error code and message.
+    pub const fn new(code: &'static str, message: &'static str) -> Self {
+        Self { code, message }
+    }
+
+    /// Return the error code of this template.
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Return the message template.
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+/// The named arguments filling in the placeholders of an [`ErrorTemplate`].
+///
+/// This struct is usually created with the [`args!`] macro.
+///
+/// [`args!`]: crate::args!
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ErrorArgs {
+    args: Vec<(&'static str, String)>,
+}
+
+impl ErrorArgs {
+    /// Create an empty list of arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the argument `name`, rendered with its `Display` implementation.
+    pub fn push(&mut self, name: &'static str, value: &dyn fmt::Display) {
+        self.args.push((name, value.to_string()));
+    }
+
+    /// Return the rendered value of the argument `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.args
+            .iter()
+            .find(|(arg, _)| *arg == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// An error created from an [`ErrorTemplate`] and its arguments.
+///
+/// This struct is created by [`Exn::from_code`]. It implements [`ErrorCode`] and is registered
+/// automatically, so its code is reported by [`Exn::code`].
+#[derive(Debug, Clone)]
+pub struct CodedError {
+    template: &'static ErrorTemplate,
+    args: ErrorArgs,
+}
+
+impl CodedError {
+    /// Return the template of this error.
+    pub fn template(&self) -> &'static ErrorTemplate {
+        self.template
+    }
+
+    /// Return the arguments of this error.
+    pub fn args(&self) -> &ErrorArgs {
+        &self.args
+    }
+}
+
+impl fmt::Display for CodedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rest = self.template.message;
+        while let Some(start) = rest.find(['{', '}']) {
+            f.write_str(&rest[..start])?;
+            rest = &rest[start..];
+            if let Some(escaped) = rest.strip_prefix("{{") {
+                f.write_str("{")?;
+                rest = escaped;
+            } else if let Some(escaped) = rest.strip_prefix("}}") {
+                f.write_str("}")?;
+                rest = escaped;
+            } else if let Some(end) = rest.find('}').filter(|_| rest.starts_with('{')) {
+                match self.args.get(&rest[1..end]) {
+                    Some(value) => f.write_str(value)?,
+                    None => f.write_str(&rest[..=end])?,
+                }
+                rest = &rest[end + 1..];
+            } else {
+                f.write_str(&rest[..1])?;
+                rest = &rest[1..];
+            }
+        }
+        f.write_str(rest)
+    }
+}
+
+impl Error for CodedError {}
+
+impl ErrorCode for CodedError {
+    fn code(&self) -> &'static str {
+        self.template.code
+    }
+}
+
+impl Exn<CodedError> {
+    /// Create a new exception from an entry of a static error table.
+    ///
+    /// See [`ErrorTemplate`] for more information.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn from_code(template: &'static ErrorTemplate, args: ErrorArgs) -> Self {
+        register_error_code::<CodedError>();
+        Exn::new(CodedError { template, args })
+    }
+}
+
+/// Creates [`ErrorArgs`] from a list of named arguments.
+///
+/// Each argument is either an identifier, whose name is used as the argument name, or a
+/// `name = value` pair.
+///
+/// [`ErrorArgs`]: crate::ErrorArgs
+///
+/// # Examples
+///
+/// ```
+/// let id = 42;
+/// let args = exn::args! {id, table = "users"};
+/// assert_eq!(args.get("id"), Some("42"));
+/// assert_eq!(args.get("table"), Some("users"));
+/// ```
+#[macro_export]
+macro_rules! args {
+    () => {
+        $crate::ErrorArgs::new()
+    };
+    ($($name:ident $(= $value:expr)?),+ $(,)?) => {{
+        let mut args = $crate::ErrorArgs::new();
+        $(
+            args.push(
+                ::core::stringify!($name),
+                &$crate::args!(@value $name $(, $value)?),
+            );
+        )+
+        args
+    }};
+    (@value $name:ident) => {
+        $name
+    };
+    (@value $name:ident, $value:expr) => {
+        $value
+    };
+}
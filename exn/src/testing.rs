@@ -0,0 +1,383 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities for asserting on exception trees in tests.
+//!
+//! See also the [`assert_exn_contains!`](crate::assert_exn_contains) and
+//! [`assert_exn_matches!`](crate::assert_exn_matches) macros.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+
+use crate::Frame;
+use crate::debug::Options;
+use crate::debug::write_tree;
+
+/// Return a wrapper whose `Debug` output is the exception tree of `frame` without locations.
+///
+/// This is useful for snapshot tests that should not break whenever a line number changes.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ErrorExt;
+///
+/// #[derive(Debug)]
+/// struct Error(&'static str);
+///
+/// impl core::fmt::Display for Error {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for Error {}
+///
+/// let exn = Error("inner").raise().raise(Error("outer"));
+/// let output = format!("{:?}", exn::testing::without_locations(exn.frame()));
+/// assert_eq!(output, "outer\n|\n|-> inner");
+/// ```
+pub fn without_locations(frame: &Frame) -> WithoutLocations<'_> {
+    WithoutLocations { frame }
+}
+
+/// An exception tree rendered without locations.
+///
+/// This struct is created by [`without_locations`].
+pub struct WithoutLocations<'a> {
+    frame: &'a Frame,
+}
+
+impl fmt::Debug for WithoutLocations<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = Options {
+            locations: false,
+            ..Options::default()
+        };
+        write_tree(f, self.frame, &options)
+    }
+}
+
+/// Compare the exception tree of `frame` with `expected`, ignoring locations.
+///
+/// `expected` is written in the layout of [`without_locations`]. Leading and trailing blank
+/// lines, the indentation common to all lines, and trailing whitespace are ignored, so the
+/// expected tree can be written as an indented multi-line string literal.
+///
+/// On mismatch, the returned [`TreeDiff`] shows the lines only in the expected tree prefixed with
+/// `-`, and the lines only in the actual tree prefixed with `+`.
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+///
+/// #[derive(Debug)]
+/// struct Error(&'static str);
+///
+/// impl core::fmt::Display for Error {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for Error {}
+///
+/// let exn = Exn::raise_all(
+///     Error("failed to sync"),
+///     [Exn::new(Error("timeout")), Exn::new(Error("refused"))],
+/// );
+///
+/// exn::diff(
+///     "
+///     failed to sync
+///     |
+///     |-> timeout
+///     |
+///     |-> refused
+///     ",
+///     &exn,
+/// )
+/// .unwrap();
+///
+/// let diff = exn::diff("failed to sync\n|\n|-> timeout", &exn).unwrap_err();
+/// assert_eq!(
+///     diff.to_string(),
+///     "  failed to sync\n  |\n  |-> timeout\n+ |\n+ |-> refused"
+/// );
+/// ```
+pub fn diff(expected: &str, frame: impl AsRef<Frame>) -> Result<(), TreeDiff> {
+    let actual = format!("{:?}", without_locations(frame.as_ref()));
+    let expected = normalize(expected);
+    let actual = normalize(&actual);
+    if expected == actual {
+        return Ok(());
+    }
+
+    // the longest common subsequence of lines, computed from the end
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            lines.push(format!("  {}", actual[j]));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    Err(TreeDiff { lines })
+}
+
+fn normalize(tree: &str) -> Vec<&str> {
+    let mut lines = tree.lines().map(str::trim_end).collect::<Vec<_>>();
+    while lines.first().is_some_and(|line| line.is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .into_iter()
+        .map(|line| line.get(indent..).unwrap_or(""))
+        .collect()
+}
+
+/// A line diff between an expected exception tree and the actual one.
+///
+/// This struct is created by [`diff`]. Both `Debug` and `Display` write the diff, so that
+/// unwrapping the result of [`diff`] panics with a readable message.
+pub struct TreeDiff {
+    lines: Vec<String>,
+}
+
+impl fmt::Debug for TreeDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "exception tree mismatch (- expected, + actual):")?;
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for TreeDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, line) in self.lines.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static PINNED_FILE: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+/// Replace the file path of every location written by reports with `placeholder`, until the
+/// returned guard is dropped.
+///
+/// The override only applies to the current thread, so tests running in parallel do not observe
+/// each other's placeholders. This makes it possible to assert on the exact report output of
+/// exceptions raised in other crates, whose file paths depend on where the crate is built.
+///
+/// # Examples
+///
+/// ```
+/// use exn::ErrorExt;
+///
+/// #[derive(Debug)]
+/// struct Error(&'static str);
+///
+/// impl core::fmt::Display for Error {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "{}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for Error {}
+///
+/// let exn = Error("failed").raise();
+/// let line = line!() - 1;
+///
+/// let guard = exn::testing::pin_locations("SRC");
+/// assert_eq!(format!("{exn:?}"), format!("failed, at SRC:{line}:27"));
+/// drop(guard);
+/// assert!(!format!("{exn:?}").contains("SRC"));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn pin_locations(placeholder: &'static str) -> PinnedLocations {
+    let previous = PINNED_FILE.with(|pinned| pinned.replace(Some(placeholder)));
+    PinnedLocations {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// A guard restoring the previous file path override when dropped.
+///
+/// This struct is created by [`pin_locations`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use = "locations are only pinned until the guard is dropped"]
+pub struct PinnedLocations {
+    previous: Option<&'static str>,
+    // the override is thread-local, so it must be restored on the same thread
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for PinnedLocations {
+    fn drop(&mut self) {
+        PINNED_FILE.with(|pinned| pinned.set(self.previous));
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn pinned_file() -> Option<&'static str> {
+    PINNED_FILE.with(Cell::get)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn pinned_file() -> Option<&'static str> {
+    None
+}
+
+/// Asserts that an exception tree contains an error of the given type.
+///
+/// The first argument can be an [`Exn`] or a [`Frame`]. On failure, the whole exception tree is
+/// included in the panic message.
+///
+/// [`Exn`]: crate::Exn
+/// [`Frame`]: crate::Frame
+///
+/// # Examples
+///
+/// ```
+/// use exn::ErrorExt;
+///
+/// #[derive(Debug)]
+/// struct NotFound;
+///
+/// impl core::fmt::Display for NotFound {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "not found")
+///     }
+/// }
+///
+/// impl core::error::Error for NotFound {}
+///
+/// let exn = NotFound
+///     .raise()
+///     .raise(std::io::Error::other("failed to load user"));
+/// exn::assert_exn_contains!(exn, NotFound);
+/// ```
+#[macro_export]
+macro_rules! assert_exn_contains {
+    ($exn:expr, $ty:ty $(,)?) => {{
+        let exn = &$exn;
+        if !exn.frames().any(|frame| frame.error().is::<$ty>()) {
+            ::core::panic!(
+                "assertion failed: exception tree does not contain a `{}`\n{:?}",
+                ::core::stringify!($ty),
+                exn,
+            );
+        }
+    }};
+}
+
+/// Asserts that an exception tree contains an error matching the given pattern.
+///
+/// The type of the error is inferred from the pattern, which may be followed by an `if` guard. The
+/// first argument can be an [`Exn`] or a [`Frame`]. On failure, the whole exception tree is
+/// included in the panic message.
+///
+/// [`Exn`]: crate::Exn
+/// [`Frame`]: crate::Frame
+///
+/// # Examples
+///
+/// ```
+/// use exn::ErrorExt;
+///
+/// #[derive(Debug)]
+/// enum DbError {
+///     NotFound { id: u64 },
+///     ConnectionDropped,
+/// }
+///
+/// impl core::fmt::Display for DbError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "database error")
+///     }
+/// }
+///
+/// impl core::error::Error for DbError {}
+///
+/// let exn = DbError::NotFound { id: 42 }
+///     .raise()
+///     .raise(std::io::Error::other("failed to load user"));
+/// exn::assert_exn_matches!(exn, DbError::NotFound { .. });
+/// exn::assert_exn_matches!(exn, DbError::NotFound { id } if *id == 42);
+/// ```
+#[macro_export]
+macro_rules! assert_exn_matches {
+    ($exn:expr, $pat:pat $(if $guard:expr)? $(,)?) => {{
+        let exn = &$exn;
+        let found = exn.frames().any(|frame| {
+            frame
+                .error()
+                .downcast_ref()
+                .is_some_and(|error| ::core::matches!(error, $pat $(if $guard)?))
+        });
+        if !found {
+            ::core::panic!(
+                "assertion failed: exception tree does not contain an error matching `{}`\n{:?}",
+                ::core::stringify!($pat $(if $guard)?),
+                exn,
+            );
+        }
+    }};
+}
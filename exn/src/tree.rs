@@ -0,0 +1,106 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+use crate::Frame;
+use crate::FrameLocation;
+
+/// An owned mirror of an exception tree, where every error is degenerated to its string
+/// representation.
+///
+/// Unlike [`Exn`] and [`Frame`], a `StringTree` holds no trait objects, so it can be cloned,
+/// compared, stored and (with the `serde` feature) serialized freely.
+///
+/// This struct is created by [`Exn::to_string_tree`] and [`Frame::to_string_tree`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+pub struct StringTree {
+    message: String,
+    file: String,
+    line: u32,
+    column: u32,
+    children: Vec<StringTree>,
+}
+
+impl StringTree {
+    /// Return the string representation of the error that occurred at this node.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Return the source file of the [location](Frame::frame_location) of the exception frame of
+    /// this node, the whole location if it is opaque, or an empty string if it is unknown; see
+    /// [`Frame::known_location`].
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Return the line of the location of the exception frame of this node, or 0 if it is opaque
+    /// or unknown.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Return the column of the location of the exception frame of this node, or 0 if it is
+    /// opaque or unknown.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    /// Return a slice of the children of this node.
+    pub fn children(&self) -> &[StringTree] {
+        &self.children
+    }
+}
+
+impl fmt::Display for StringTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Exn<E> {
+    /// Convert the exception tree into an owned [`StringTree`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn to_string_tree(&self) -> StringTree {
+        self.frame().to_string_tree()
+    }
+}
+
+impl Frame {
+    /// Convert this frame and all its descendants into an owned [`StringTree`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable")))]
+    pub fn to_string_tree(&self) -> StringTree {
+        let location = self.known_location();
+        let location = location.as_deref();
+        StringTree {
+            message: self.error().to_string(),
+            file: match location.map(|location| (location, location.path())) {
+                Some((_, Some(path))) => path.into_owned(),
+                Some((location, None)) => location.to_string(),
+                None => String::new(),
+            },
+            line: location.and_then(FrameLocation::line).unwrap_or(0),
+            column: location.and_then(FrameLocation::column).unwrap_or(0),
+            children: self.children().iter().map(Frame::to_string_tree).collect(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::error::Error;
+
+use crate::Exn;
+use crate::Frame;
+use crate::IntoExn;
+use crate::Result;
+
+/// An extension trait for tuples of [`Result`](core::result::Result)s to raise all their
+/// failures together, e.g., when validating the fields of a struct.
+///
+/// Implemented for tuples of up to 8 results, whose `Ok` and `Err` types may differ. The `Err`
+/// types may be errors or [`Exn`]s.
+pub trait ResultTupleExt {
+    /// The tuple of the `Ok` types.
+    type Success;
+
+    /// Return the `Ok` values if all results are `Ok`; otherwise raise a new exception with all
+    /// the failures as its children, in order, like [`Exn::raise_all`].
+    ///
+    /// Unlike `?` on each result, this reports every failure rather than the first one. The new
+    /// exception, and the exceptions created from plain errors, are located at the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use exn::ResultTupleExt;
+    ///
+    /// # #[derive(Debug)]
+    /// # struct InvalidUser;
+    /// # impl core::fmt::Display for InvalidUser {
+    /// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    /// #         write!(f, "invalid user")
+    /// #     }
+    /// # }
+    /// # impl core::error::Error for InvalidUser {}
+    /// fn validate_name(name: &str) -> exn::Result<String, std::io::Error> {
+    ///     exn::ensure!(!name.is_empty(), std::io::Error::other("empty name"));
+    ///     Ok(name.to_string())
+    /// }
+    ///
+    /// let result = (validate_name(""), "two".parse::<u8>()).or_raise_all(|| InvalidUser);
+    /// let exn = result.unwrap_err();
+    /// assert_eq!(exn.frame().children().len(), 2);
+    ///
+    /// let (name, age) = (validate_name("alice"), "42".parse::<u8>())
+    ///     .or_raise_all(|| InvalidUser)
+    ///     .unwrap();
+    /// assert_eq!((name.as_str(), age), ("alice", 42));
+    /// ```
+    fn or_raise_all<A, F>(self, err: F) -> Result<Self::Success, A>
+    where
+        A: Error + Send + Sync + 'static,
+        F: FnOnce() -> A;
+}
+
+macro_rules! impl_result_tuple_ext {
+    ($($v:ident: $T:ident, $E:ident);+) => {
+        impl<$($T, $E: IntoExn),+> ResultTupleExt for ($(core::result::Result<$T, $E>,)+) {
+            type Success = ($($T,)+);
+
+            #[cfg_attr(not(exn_no_location), track_caller)]
+            fn or_raise_all<A, F>(self, err: F) -> Result<Self::Success, A>
+            where
+                A: Error + Send + Sync + 'static,
+                F: FnOnce() -> A,
+            {
+                let location = crate::location::caller();
+                let mut failures = Vec::<Box<Frame>>::new();
+                let ($($v,)+) = self;
+                $(
+                    let $v = match $v {
+                        Ok(v) => Some(v),
+                        Err(e) => {
+                            failures.push(e.into_exn().into_boxed_frame());
+                            None
+                        }
+                    };
+                )+
+                match ($($v,)+) {
+                    ($(Some($v),)+) => Ok(($($v,)+)),
+                    _ => Err(Exn::raise_frames_at(err(), failures.into_iter(), location)),
+                }
+            }
+        }
+    };
+}
+
+impl_result_tuple_ext!(a: T0, E0; b: T1, E1);
+impl_result_tuple_ext!(a: T0, E0; b: T1, E1; c: T2, E2);
+impl_result_tuple_ext!(a: T0, E0; b: T1, E1; c: T2, E2; d: T3, E3);
+impl_result_tuple_ext!(a: T0, E0; b: T1, E1; c: T2, E2; d: T3, E3; e: T4, E4);
+impl_result_tuple_ext!(a: T0, E0; b: T1, E1; c: T2, E2; d: T3, E3; e: T4, E4; f: T5, E5);
+impl_result_tuple_ext!(a: T0, E0; b: T1, E1; c: T2, E2; d: T3, E3; e: T4, E4; f: T5, E5; g: T6, E6);
+impl_result_tuple_ext!(a: T0, E0; b: T1, E1; c: T2, E2; d: T3, E3; e: T4, E4; f: T5, E5; g: T6, E6; h: T7, E7);
@@ -0,0 +1,150 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+use crate::Exn;
+use crate::Frame;
+use crate::IntoExn;
+use crate::Result;
+
+/// A value whose invariants can be checked, e.g., a configuration or a request body.
+///
+/// With the `macros` feature, this trait can be derived for structs with `#[derive(Validate)]`,
+/// which checks each field annotated with `#[validate]` or `#[validate(with = path)]`.
+pub trait Validate {
+    /// Check the value, raising one exception with all the violations found.
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// The error of a failed [validation](Validate::validate).
+///
+/// The children of its frame are the failures of each invalid field, with a [`Field`]
+/// attachment naming the field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    name: &'static str,
+}
+
+impl ValidationError {
+    /// Create an error of the failed validation of a value of the type named `name`.
+    pub fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    /// Return the name of the type of the invalid value.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid `{}`", self.name)
+    }
+}
+
+impl Error for ValidationError {}
+
+/// The name of an invalid field, attached to its failure by [`Validator::check`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Field;
+/// use exn::ValidationError;
+/// use exn::Validator;
+///
+/// let mut validator = Validator::new();
+/// validator.check("port", Err::<(), _>(std::io::Error::other("must not be 0")));
+/// let exn = validator
+///     .finish(ValidationError::new("Config"))
+///     .unwrap_err();
+///
+/// let child = &exn.frame().children()[0];
+/// let field = child.attachments()[0].downcast_ref::<Field>().unwrap();
+/// assert_eq!(field.name(), "port");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Field(&'static str);
+
+impl Field {
+    /// Return the name of the field.
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "in field `{}`", self.0)
+    }
+}
+
+/// A collector of the failures of the fields of a value, to be raised together under a
+/// [`ValidationError`].
+///
+/// This is what `#[derive(Validate)]` expands to, and it can be used to implement [`Validate`]
+/// by hand, e.g., for checks across fields.
+#[derive(Default)]
+pub struct Validator {
+    failures: Vec<Frame>,
+}
+
+impl Validator {
+    /// Create a validator without failures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the failure of `result`, if any, as the failure of the field named `field`.
+    ///
+    /// The exception of the failure is attached a [`Field`] with the name of the field. A plain
+    /// error is raised as a new exception located at the caller.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn check<T, E: IntoExn>(
+        &mut self,
+        field: &'static str,
+        result: core::result::Result<T, E>,
+    ) {
+        if let Err(err) = result {
+            let exn = err
+                .into_exn()
+                .attach_at(Field(field), crate::location::caller());
+            self.failures.push(*exn.into_boxed_frame());
+        }
+    }
+
+    /// Return `true` if no failure was recorded.
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Return `Ok` if no failure was recorded; otherwise raise a new exception of `err` with
+    /// the failures as its children, in the order they were recorded. The new exception is
+    /// located at the caller.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn finish(self, err: ValidationError) -> Result<(), ValidationError> {
+        if self.failures.is_empty() {
+            Ok(())
+        } else {
+            let location = crate::location::caller();
+            let children = self.failures.into_iter().map(Box::new);
+            Err(Exn::raise_frames_at(err, children, location))
+        }
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::slice;
+
+use crate::Exn;
+use crate::IntoExn;
+use crate::Result;
+use crate::Severity;
+
+/// An accumulator of non-fatal exceptions.
+///
+/// Code that can carry on after a failure pushes the exception into a `Warnings` and continues.
+/// The collected warnings can later be merged into a final error with [`Warnings::merge_into`], or
+/// emitted standalone under a new error with [`Warnings::raise`]. Either way, warnings whose
+/// topmost frame has [`Severity::Error`] are downgraded to [`Severity::Warning`].
+///
+/// # Examples
+///
+/// ```
+/// use exn::Exn;
+/// use exn::Warnings;
+///
+/// #[derive(Debug)]
+/// struct ParseError(String);
+///
+/// impl core::fmt::Display for ParseError {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         write!(f, "invalid number: {}", self.0)
+///     }
+/// }
+///
+/// impl core::error::Error for ParseError {}
+///
+/// fn parse(input: &str) -> exn::Result<u32, ParseError> {
+///     input
+///         .parse()
+///         .map_err(|_| Exn::new(ParseError(input.to_string())))
+/// }
+///
+/// let mut warnings = Warnings::new();
+/// let numbers = ["1", "two", "3"]
+///     .into_iter()
+///     .filter_map(|input| warnings.ok(parse(input)))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(numbers, [1, 3]);
+/// assert_eq!(warnings.len(), 1);
+/// ```
+pub struct Warnings<E: Error + Send + Sync + 'static> {
+    warnings: Vec<Exn<E>>,
+}
+
+impl<E: Error + Send + Sync + 'static> Warnings<E> {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self { warnings: vec![] }
+    }
+
+    /// Push a non-fatal exception into the accumulator.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn push(&mut self, warning: impl IntoExn<Error = E>) {
+        self.warnings.push(warning.into_exn());
+    }
+
+    /// Convert `result` into an [`Option`], pushing the exception into the accumulator on the
+    /// `Err` variant.
+    pub fn ok<T>(&mut self, result: Result<T, E>) -> Option<T> {
+        match result {
+            Ok(v) => Some(v),
+            Err(e) => {
+                self.warnings.push(e);
+                None
+            }
+        }
+    }
+
+    /// Return the number of collected warnings.
+    pub fn len(&self) -> usize {
+        self.warnings.len()
+    }
+
+    /// Return `true` if no warning has been collected.
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Return an iterator over the collected warnings.
+    pub fn iter(&self) -> slice::Iter<'_, Exn<E>> {
+        self.warnings.iter()
+    }
+
+    /// Merge the collected warnings into `exn` as children of its topmost frame.
+    pub fn merge_into<T: Error + Send + Sync + 'static>(self, mut exn: Exn<T>) -> Exn<T> {
+        for warning in self.warnings {
+            exn.adopt(downgrade(warning));
+        }
+        exn
+    }
+
+    /// Raise a new exception with the collected warnings as its children.
+    ///
+    /// Returns `None` if no warning has been collected.
+    #[cfg_attr(not(exn_no_location), track_caller)]
+    pub fn raise<T: Error + Send + Sync + 'static>(self, err: T) -> Option<Exn<T>> {
+        if self.warnings.is_empty() {
+            None
+        } else {
+            Some(Exn::raise_all(
+                err,
+                self.warnings.into_iter().map(downgrade),
+            ))
+        }
+    }
+}
+
+fn downgrade<E: Error + Send + Sync + 'static>(warning: Exn<E>) -> Exn<E> {
+    match warning.frame().severity() {
+        Severity::Error => warning.with_severity(Severity::Warning),
+        _ => warning,
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Default for Warnings<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> fmt::Debug for Warnings<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.warnings).finish()
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Extend<Exn<E>> for Warnings<E> {
+    fn extend<I: IntoIterator<Item = Exn<E>>>(&mut self, iter: I) {
+        self.warnings.extend(iter);
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> FromIterator<Exn<E>> for Warnings<E> {
+    fn from_iter<I: IntoIterator<Item = Exn<E>>>(iter: I) -> Self {
+        Self {
+            warnings: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> IntoIterator for Warnings<E> {
+    type Item = Exn<E>;
+    type IntoIter = vec::IntoIter<Exn<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.warnings.into_iter()
+    }
+}
+
+impl<'a, E: Error + Send + Sync + 'static> IntoIterator for &'a Warnings<E> {
+    type Item = &'a Exn<E>;
+    type IntoIter = slice::Iter<'a, Exn<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.warnings.iter()
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interoperability with JavaScript errors for Rust code compiled to WebAssembly with
+//! [`wasm_bindgen`].
+//!
+//! An exception converts into a [`JsValue`] holding a JavaScript `Error`, which keeps the whole
+//! exception tree in its properties:
+//!
+//! * `message`: the error message of the frame, or `«redacted»` for
+//!   [sensitive](Exn::mark_sensitive) frames;
+//! * `location`: the [location](Frame::known_location) of the frame, if known;
+//! * `code`: the [error code](crate::ErrorCode) of the frame, if any;
+//! * `attachments`: an array of the messages attached to the frame, each `«redacted»` for sensitive
+//!   frames;
+//! * `children`: an array of the child frames, converted in the same way;
+//! * `cause`: the primary child frame, so that JavaScript tooling following the standard
+//!   [`Error.cause`] chain sees the primary chain of the tree.
+//!
+//! In the other direction, [`from_js_error`] converts a JavaScript value thrown or rejected by
+//! JavaScript code into an [`Exn<JsError>`], keeping its `cause` chain and the tree of an
+//! exception converted by this module. Values already converted are skipped, so that cyclic
+//! `cause` chains terminate, and the conversion stops 64 levels deep.
+//!
+//! Note that [`wasm_bindgen`] can only call JavaScript on `wasm32` targets; the conversions panic
+//! elsewhere.
+//!
+//! [`Error.cause`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/cause
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use exn::Exn;
+//! use wasm_bindgen::JsValue;
+//!
+//! fn parse(input: &str) -> Result<u32, JsValue> {
+//!     let number = input
+//!         .parse::<u32>()
+//!         .map_err(|err| Exn::new(err).attach(format!("while parsing {input:?}")))?;
+//!     Ok(number)
+//! }
+//!
+//! fn call(callback: &js_sys::Function) -> exn::Result<JsValue, exn::wasm::JsError> {
+//!     callback
+//!         .call0(&JsValue::NULL)
+//!         .map_err(exn::wasm::from_js_error)
+//! }
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::panic::Location;
+
+use js_sys::Array;
+use js_sys::Reflect;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+
+use crate::Exn;
+use crate::Frame;
+use crate::observer;
+
+/// The depth at which [`from_js_error`] stops converting the causes of a JavaScript value.
+const MAX_DEPTH: usize = 64;
+
+/// An error thrown or rejected by JavaScript code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsError {
+    name: Option<String>,
+    message: String,
+}
+
+impl JsError {
+    /// Return the name of the JavaScript error, e.g., `TypeError`, if the thrown value was an
+    /// `Error`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Return the message of the JavaScript error, or the string representation of the thrown
+    /// value if it was not an `Error`.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for JsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{name}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for JsError {}
+
+/// Convert a JavaScript value thrown or rejected by JavaScript code into an exception.
+///
+/// The exception is located at the caller. If the value is an `Error`, its `stack` is attached to
+/// the exception, and its `cause` chain, or the `children` of an exception converted by this
+/// module, become the children of the exception. A value met twice is converted once, and the
+/// causes of values 64 levels deep are replaced by an attachment noting they were
+/// omitted.
+#[cfg_attr(not(exn_no_location), track_caller)]
+pub fn from_js_error(value: JsValue) -> Exn<JsError> {
+    let exn = from_js_value(&value, crate::location::caller(), 1, &mut Vec::new());
+    observer::notify_new(exn.frame());
+    exn
+}
+
+fn from_js_value(
+    value: &JsValue,
+    location: &'static Location<'static>,
+    depth: usize,
+    visited: &mut Vec<JsValue>,
+) -> Exn<JsError> {
+    // primitives compare by value, so only objects can be met twice
+    if value.is_object() {
+        visited.push(value.clone());
+    }
+    let Some(error) = value.dyn_ref::<js_sys::Error>() else {
+        let message = value.as_string().unwrap_or_else(|| format!("{value:?}"));
+        return Exn::unobserved(
+            JsError {
+                name: None,
+                message,
+            },
+            location,
+        );
+    };
+
+    let error_name = String::from(error.name());
+    let name = (error_name != "Error").then_some(error_name);
+    let message = String::from(error.message());
+    let mut exn = Exn::unobserved(JsError { name, message }, location);
+
+    if let Some(attachments) = property(value, "attachments").and_then(array) {
+        for attachment in attachments.iter().filter_map(|value| value.as_string()) {
+            exn = exn.attach_at(attachment, location);
+        }
+    }
+    if let Some(stack) = property(value, "stack").and_then(|stack| stack.as_string()) {
+        exn = exn.attach_at(stack, location);
+    }
+
+    let children = match property(value, "children").and_then(array) {
+        Some(children) => children.iter().collect(),
+        None => property(value, "cause").into_iter().collect::<Vec<_>>(),
+    };
+    let children = children
+        .into_iter()
+        .filter(|child| !visited.contains(child))
+        .collect::<Vec<_>>();
+    if depth >= MAX_DEPTH {
+        if !children.is_empty() {
+            let note = format!("further causes omitted at depth {MAX_DEPTH}");
+            exn = exn.attach_at(note, location);
+        }
+        return exn;
+    }
+    for child in &children {
+        // a sibling may have reached this child already
+        if !visited.contains(child) {
+            exn.adopt(from_js_value(child, location, depth + 1, visited));
+        }
+    }
+    exn
+}
+
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for JsValue {
+    fn from(exn: Exn<E>) -> Self {
+        to_js_value(exn.frame())
+    }
+}
+
+fn to_js_value(frame: &Frame) -> JsValue {
+    let error = js_sys::Error::new(&frame.redacted_message().to_string());
+    if let Some(location) = frame.known_location() {
+        set(
+            &error,
+            "location",
+            &JsValue::from_str(&location.to_string()),
+        );
+    }
+    if let Some(code) = frame.code() {
+        set(&error, "code", &JsValue::from_str(code));
+    }
+
+    let attachments = frame
+        .redacted_attachments()
+        .map(|attachment| JsValue::from_str(&attachment.to_string()))
+        .collect::<Array>();
+    set(&error, "attachments", &attachments);
+
+    let children = frame.children().iter().map(to_js_value).collect::<Array>();
+    let primary = frame.primary_child().and_then(|primary| {
+        let mut siblings = frame.children().iter();
+        siblings.position(|child| core::ptr::eq(child, primary))
+    });
+    if let Some(primary) = primary {
+        error.set_cause(&children.get(primary as u32));
+    }
+    set(&error, "children", &children);
+    error.into()
+}
+
+fn set(target: &JsValue, key: &str, value: &JsValue) {
+    // setting a property of a fresh, extensible object cannot fail
+    let _ = Reflect::set(target, &JsValue::from_str(key), value);
+}
+
+fn property(target: &JsValue, key: &str) -> Option<JsValue> {
+    Reflect::get(target, &JsValue::from_str(key))
+        .ok()
+        .filter(|value| !value.is_undefined() && !value.is_null())
+}
+
+fn array(value: JsValue) -> Option<Array> {
+    value.dyn_into::<Array>().ok()
+}
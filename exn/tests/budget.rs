@@ -0,0 +1,49 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The debug budget is process-global, so these tests live in their own test binary and run
+//! sequentially within a single test function.
+
+use exn::Budget;
+
+#[allow(dead_code)]
+mod common;
+use common::Error;
+
+#[test]
+fn debug_budget() {
+    let e = common::new_tree_error().raise(Error("topmost"));
+    let unlimited = format!("{e:?}");
+    assert_eq!(exn::debug_budget(), Budget::unlimited());
+
+    exn::set_debug_budget(Budget::unlimited().with_max_frames(2));
+    let output = format!("{:?}", exn::testing::without_locations(e.frame()));
+    assert_eq!(
+        output,
+        "topmost\n|\n|-> E6\n… output truncated: formatting budget exhausted"
+    );
+
+    exn::set_debug_budget(Budget::unlimited().with_max_bytes(10));
+    let output = format!("{e:?}");
+    assert_eq!(
+        output,
+        "topmost, a\n… output truncated: formatting budget exhausted"
+    );
+
+    exn::set_debug_budget(Budget::unlimited().with_max_bytes(unlimited.len()));
+    assert_eq!(format!("{e:?}"), unlimited);
+
+    exn::set_debug_budget(Budget::unlimited());
+    assert_eq!(format!("{e:?}"), unlimited);
+}
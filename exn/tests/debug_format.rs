@@ -0,0 +1,60 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The debug format is process-global and read from the environment once, so these tests live in
+//! their own test binary and run sequentially within a single test function.
+
+use exn::Exn;
+use exn::ReportFormat;
+
+#[allow(dead_code)]
+mod common;
+use common::Error;
+
+#[test]
+fn debug_format() {
+    #[cfg(feature = "std")]
+    {
+        // SAFETY: no other thread of this test binary reads the environment
+        unsafe { std::env::set_var("EXN_REPORT", " JSON ") };
+        assert_eq!(exn::debug_format(), ReportFormat::Json);
+
+        // the environment is only read once
+        unsafe { std::env::set_var("EXN_REPORT", "compact") };
+        assert_eq!(exn::debug_format(), ReportFormat::Json);
+    }
+    #[cfg(not(feature = "std"))]
+    assert_eq!(exn::debug_format(), ReportFormat::Full);
+
+    exn::set_debug_format(ReportFormat::Json);
+
+    let exn = Exn::new(Error("connection reset")).raise(Error("failed to fetch"));
+    let output = format!("{exn:?}");
+    assert!(output.starts_with(r#"{"message":"failed to fetch","location":"#));
+    assert!(output.ends_with(r#""attachments":[],"children":[]}]}"#));
+
+    exn::set_debug_format(ReportFormat::Compact);
+    assert_eq!(format!("{exn:?}"), "failed to fetch: connection reset");
+    assert_eq!(
+        format!("{:?}", exn.frame().children()[0]),
+        "connection reset"
+    );
+
+    exn::set_debug_format(ReportFormat::Full);
+    assert!(format!("{exn:?}").starts_with("failed to fetch, at "));
+
+    assert_eq!("Compact".parse(), Ok(ReportFormat::Compact));
+    assert!("verbose".parse::<ReportFormat>().is_err());
+    assert_eq!(ReportFormat::Json.to_string(), "json");
+}
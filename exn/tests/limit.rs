@@ -0,0 +1,79 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The frame limit is process-global, so these tests live in their own test binary and run
+//! sequentially within a single test function.
+
+use exn::Exn;
+use exn::FramesDropped;
+
+#[allow(dead_code)]
+mod common;
+use common::Error;
+
+fn dropped(frame: &exn::Frame) -> Option<usize> {
+    frame
+        .error()
+        .downcast_ref::<FramesDropped>()
+        .map(FramesDropped::count)
+}
+
+#[test]
+fn frame_limit() {
+    assert_eq!(exn::frame_limit(), usize::MAX);
+    exn::set_frame_limit(5);
+
+    // children over the limit are dropped, and accounted for in a single frame
+    let attempts = (0..4).map(|_| Exn::new(Error("E1")).raise(Error("attempt")));
+    let mut exn = Exn::raise_all(Error("outer"), attempts);
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(dropped(&children[1]), Some(6));
+    assert_eq!(children[1].to_string(), "… 6 frames dropped");
+
+    exn.adopt(Exn::new(Error("late")));
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(dropped(&children[1]), Some(7));
+
+    exn.suppress(Exn::new(Error("cleanup")));
+    assert_eq!(dropped(&exn.frame().suppressed()[0]), Some(1));
+
+    // the frames standing in for dropped exceptions fit within the limit too
+    exn.adopt(Exn::new(Error("later")));
+    exn.suppress(Exn::new(Error("cleanup")));
+    assert_eq!(exn.frame_count(), 5);
+    assert_eq!(exn.stats().frame_count(), 5);
+
+    // chains are never cut
+    let mut exn = Exn::new(Error("root cause"));
+    for _ in 0..10 {
+        exn = exn.raise(Error("retry"));
+    }
+    assert_eq!(exn.frame().frames().count(), 11);
+    assert_eq!(
+        exn.frame().frames().last().unwrap().to_string(),
+        "root cause"
+    );
+
+    // within the limit, nothing is dropped
+    let mut exn = Exn::raise_all(Error("outer"), [Exn::new(Error("E1"))]);
+    exn.adopt(Exn::new(Error("E2")));
+    assert_eq!(exn.frame().frames().count(), 3);
+
+    exn::set_frame_limit(usize::MAX);
+    let attempts = (0..10).map(|_| Exn::new(Error("attempt")));
+    let exn = Exn::raise_all(Error("outer"), attempts);
+    assert_eq!(exn.frame().children().len(), 10);
+}
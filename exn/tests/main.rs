@@ -95,3 +95,2578 @@ fn ensure_fail() {
     let result = foo();
     insta::assert_debug_snapshot!(result.unwrap_err());
 }
+
+#[test]
+fn primary_chain() {
+    let e = common::new_tree_error().raise(Error("topmost"));
+    let chain = e
+        .primary_chain()
+        .map(|frame| frame.error().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(chain, ["topmost", "E6", "E5", "E3", "E1"]);
+    assert_eq!(e.root_cause().to_string(), "E1");
+
+    let e = Exn::new(Error("alone"));
+    assert_eq!(e.primary_chain().count(), 1);
+    assert_eq!(e.root_cause().to_string(), "alone");
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn string_tree() {
+    let e = common::new_tree_error().raise(Error("topmost"));
+    let tree = e.to_string_tree();
+    assert_eq!(tree.message(), "topmost");
+    assert_eq!(tree.file(), e.frame().location().file());
+    assert_eq!(tree.line(), e.frame().location().line());
+    assert_eq!(tree.children().len(), 1);
+    assert_eq!(tree.children()[0].children().len(), 3);
+    assert_eq!(tree.clone(), tree);
+}
+
+#[test]
+fn error_code() {
+    #[derive(Debug)]
+    struct CodedError(&'static str);
+
+    impl std::fmt::Display for CodedError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "coded error")
+        }
+    }
+
+    impl std::error::Error for CodedError {}
+
+    impl exn::ErrorCode for CodedError {
+        fn code(&self) -> &'static str {
+            self.0
+        }
+    }
+
+    exn::register_error_code::<CodedError>();
+    exn::register_error_code::<CodedError>();
+
+    let e = Exn::new(Error("no code"));
+    assert_eq!(e.code(), None);
+
+    let e = Exn::raise_all(Error("top"), [Exn::new(Error("first"))]);
+    let e = Exn::raise_all(
+        Error("wrapper"),
+        [e, Exn::new(CodedError("E0002")).raise(Error("second"))],
+    );
+    assert_eq!(e.code(), Some("E0002"));
+    assert_eq!(e.frame().code(), None);
+
+    let e = Exn::new(CodedError("E0001")).raise(Error("wrapped"));
+    assert_eq!(e.code(), Some("E0001"));
+    assert!(format!("{e:?}").contains("|-> [E0001] coded error, at "));
+}
+
+#[test]
+fn warnings() {
+    let mut warnings = exn::Warnings::new();
+    assert!(warnings.is_empty());
+
+    let result: exn::Result<u32, Error> = Err(Exn::new(Error("first")));
+    assert_eq!(warnings.ok(result), None);
+    assert_eq!(warnings.ok(exn::Ok(1)), Some(1));
+    warnings.push(Error("second"));
+    assert_eq!(warnings.len(), 2);
+
+    let e = warnings.merge_into(Exn::new(Error("final")));
+    let children = e.frame().children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].error().to_string(), "first");
+    assert_eq!(children[1].error().to_string(), "second");
+
+    let warnings = exn::Warnings::<Error>::new();
+    assert!(warnings.raise(Error("summary")).is_none());
+
+    let warnings = [Exn::new(Error("only"))]
+        .into_iter()
+        .collect::<exn::Warnings<_>>();
+    let e = warnings.raise(Error("summary")).unwrap();
+    assert_eq!(e.frame().children().len(), 1);
+}
+
+#[test]
+fn severity() {
+    use exn::Severity;
+
+    let e = Exn::new(Error("E1")).with_severity(Severity::Info);
+    assert_eq!(e.max_severity(), Severity::Info);
+
+    let e = e.raise(Error("E2")).with_severity(Severity::Warning);
+    assert_eq!(e.frame().severity(), Severity::Warning);
+    assert_eq!(e.max_severity(), Severity::Warning);
+    assert_eq!(
+        format!("{e:?}")
+            .lines()
+            .map(|l| l.split(", at").next().unwrap())
+            .collect::<Vec<_>>(),
+        ["warning: E2", "|", "|-> info: E1"]
+    );
+
+    let mut warnings = exn::Warnings::new();
+    warnings.push(Error("W1"));
+    let e = warnings.merge_into(Exn::new(Error("final")));
+    assert_eq!(e.frame().severity(), Severity::Error);
+    assert_eq!(e.frame().children()[0].severity(), Severity::Warning);
+    assert_eq!(e.max_severity(), Severity::Error);
+}
+
+#[test]
+fn frame_origin() {
+    use exn::FrameOrigin;
+
+    let e = Exn::new(ErrorWithSource("top", Error("source")));
+    assert_eq!(e.frame().origin(), FrameOrigin::Raised);
+    assert_eq!(e.frame().children()[0].origin(), FrameOrigin::SourceChain);
+
+    let e = e.raise(Error("raised"));
+    assert_eq!(e.frame().origin(), FrameOrigin::Raised);
+}
+
+#[test]
+fn frames() {
+    let e = common::new_tree_error().raise(Error("topmost"));
+    let frames = e
+        .frames()
+        .map(|frame| frame.error().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        frames,
+        [
+            "topmost", "E6", "E5", "E3", "E1", "E10", "E9", "E12", "E11", "E4", "E2", "E8", "E7"
+        ]
+    );
+}
+
+#[test]
+fn without_locations() {
+    let e = common::new_tree_error().raise(Error("topmost"));
+    insta::assert_debug_snapshot!(exn::testing::without_locations(e.frame()));
+}
+
+#[test]
+fn assert_exn_macros() {
+    let e = Exn::new(ErrorWithSource("top", Error("source"))).raise(Error("outer"));
+    exn::assert_exn_contains!(e, ErrorWithSource);
+    exn::assert_exn_contains!(e.frame(), Error);
+    exn::assert_exn_matches!(e, Error("outer"));
+    exn::assert_exn_matches!(e, ErrorWithSource(msg, _) if *msg == "top");
+}
+
+#[test]
+#[should_panic(expected = "exception tree does not contain an error matching `Error(\"missing\")`")]
+fn assert_exn_matches_fail() {
+    let e = Exn::new(Error("present"));
+    exn::assert_exn_matches!(e, Error("missing"));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn ffi_last_error() {
+    #[derive(Debug)]
+    struct FfiError;
+
+    impl std::fmt::Display for FfiError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ffi error")
+        }
+    }
+
+    impl std::error::Error for FfiError {}
+
+    impl exn::ErrorCode for FfiError {
+        fn code(&self) -> &'static str {
+            "FFI001"
+        }
+    }
+
+    exn::register_error_code::<FfiError>();
+    exn::ffi::register_int_code("FFI001", 7);
+
+    assert_eq!(
+        exn::ffi::into_status::<Error>(exn::Ok(())),
+        exn::ffi::STATUS_OK
+    );
+    assert!(exn::ffi::last_error().is_none());
+
+    let e = Exn::new(FfiError).raise(Error("outer"));
+    assert_eq!(exn::ffi::int_code(&e), Some(7));
+    assert_eq!(exn::ffi::set_last_error(e), 7);
+    let frame = exn::ffi::last_error().unwrap();
+    assert_eq!(frame.to_string(), "outer");
+    assert_eq!(frame.children()[0].to_string(), "ffi error");
+    assert!(exn::ffi::last_error().is_none());
+
+    let status = exn::ffi::into_status::<Error>(Err(Exn::new(Error("unknown"))));
+    assert_eq!(status, exn::ffi::STATUS_UNKNOWN);
+    exn::ffi::clear_last_error();
+    assert!(exn::ffi::last_error().is_none());
+}
+
+#[test]
+fn display_summary() {
+    let e = common::new_tree_error().raise(Error("topmost"));
+    assert_eq!(e.to_string(), "topmost");
+    assert_eq!(format!("{e:#}"), "topmost: E6: E5: E3: E1");
+    assert_eq!(format!("{:#}", e.frame().children()[0]), "E6: E5: E3: E1");
+}
+
+#[test]
+fn type_name() {
+    let e = Exn::new(ErrorWithSource("top", Error("source")));
+    assert_eq!(
+        e.frame().type_name(),
+        Some(std::any::type_name::<ErrorWithSource>())
+    );
+    assert_eq!(e.frame().children()[0].type_name(), None);
+
+    let report = exn::report::Compact::new(e.frame())
+        .with_locations(false)
+        .with_type_names(true);
+    assert_eq!(
+        format!("{report:?}"),
+        format!(
+            "top ({})\n|\n|-> source: source",
+            std::any::type_name::<ErrorWithSource>()
+        )
+    );
+}
+
+#[test]
+fn compact_report() {
+    fn run() -> Result<(), exn::report::Compact> {
+        Err(Exn::new(Error("inner")).raise(Error("outer")))?;
+        Ok(())
+    }
+
+    let report = run().unwrap_err();
+    assert_eq!(report.frame().to_string(), "outer");
+    assert_eq!(format!("{report}"), format!("{report:?}"));
+    assert_eq!(
+        format!("{:?}", report.with_locations(false)),
+        "outer\n|\n|-> inner"
+    );
+}
+
+#[test]
+fn frames_at() {
+    let e = common::new_tree_error().raise(Error("topmost"));
+    assert_eq!(e.frames_at("common.rs").count(), 12);
+    assert_eq!(e.frames_at("main.rs").count(), 1);
+    assert_eq!(e.frames_at("no/such/file.rs").count(), 0);
+}
+
+#[test]
+fn redaction() {
+    let e = Exn::new(ErrorWithSource("password=hunter2", Error("token=secret")))
+        .mark_sensitive()
+        .raise(Error("login failed"));
+    assert!(!e.frame().is_sensitive());
+    assert!(e.frame().children()[0].is_sensitive());
+
+    let report = exn::report::Compact::new(e.frame()).with_locations(false);
+    assert_eq!(
+        format!("{:?}", report.with_redaction(true)),
+        "login failed\n|\n|-> «redacted»\n|\n|-> source: «redacted»"
+    );
+    assert!(format!("{e:?}").contains("password=hunter2"));
+}
+
+#[test]
+fn raise_dedup() {
+    let e = Exn::new(Error("inner")).raise(Error("failed to send request"));
+    let e = e.raise_dedup(Error("failed to send request"));
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(e.frame())),
+        "failed to send request\n|\n|-> inner"
+    );
+
+    let e = e.raise_dedup(Error("failed to run app"));
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(e.frame())),
+        "failed to run app\n|\n|-> failed to send request\n|\n|-> inner"
+    );
+}
+
+#[test]
+fn deferred_kind() {
+    fn classify(exn: &Exn<Error>) -> usize {
+        exn.frames().count()
+    }
+
+    let deferred = Exn::new(Error("inner"))
+        .raise(Error("outer"))
+        .defer_kind(classify);
+    assert!(!deferred.is_classified());
+    assert_eq!(deferred.to_string(), "outer");
+    assert!(!deferred.is_classified());
+    assert_eq!(*deferred.kind(), 2);
+    assert!(deferred.is_classified());
+    assert_eq!(deferred.into_exn().frames().count(), 2);
+}
+
+#[test]
+fn lazy_message() {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    static FORMATTED: AtomicUsize = AtomicUsize::new(0);
+
+    let result: Result<(), Error> = Err(Error("miss"));
+    let exn = result
+        .or_raise_lazy(|f| {
+            FORMATTED.fetch_add(1, Ordering::Relaxed);
+            write!(f, "cache lookup failed")
+        })
+        .unwrap_err();
+    assert_eq!(FORMATTED.load(Ordering::Relaxed), 0);
+    assert_eq!(exn.to_string(), "cache lookup failed");
+    assert_eq!(FORMATTED.load(Ordering::Relaxed), 1);
+
+    let key = 7;
+    let exn = exn.raise(exn::lazy_context!("failed to load key {key}"));
+    assert_eq!(
+        format!("{exn:#}"),
+        "failed to load key 7: cache lookup failed: miss"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn pin_locations() {
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+    let line = line!() - 1;
+
+    let outer = exn::testing::pin_locations("SRC");
+    let expected = format!("outer, at SRC:{line}:40\n|\n|-> inner, at SRC:{line}:15");
+    assert_eq!(format!("{exn:?}"), expected);
+
+    let inner = exn::testing::pin_locations("OTHER");
+    assert!(format!("{exn:?}").starts_with("outer, at OTHER:"));
+    drop(inner);
+    assert_eq!(format!("{exn:?}"), expected);
+
+    drop(outer);
+    assert!(format!("{exn:?}").contains("main.rs"));
+}
+
+#[test]
+fn exn_list() {
+    let results: Vec<exn::Result<u32, Error>> = vec![
+        Ok(1),
+        Err(Exn::new(Error("first"))),
+        Ok(2),
+        Err(Exn::new(Error("second"))),
+    ];
+    let list = results
+        .into_iter()
+        .filter_map(Result::err)
+        .collect::<exn::ExnList<_>>();
+    assert_eq!(list.len(), 2);
+
+    let exn = list.raise(Error("parent"));
+    let children = exn
+        .frame()
+        .children()
+        .iter()
+        .map(|frame| frame.error().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(children, ["first", "second"]);
+
+    let mut list = exn::ExnList::from(vec![Exn::new(Error("pushed"))]);
+    list.push(Error("another"));
+    assert_eq!(list.into_vec().len(), 2);
+}
+
+#[test]
+fn children_order() {
+    fn messages(frame: &exn::Frame) -> Vec<String> {
+        frame
+            .children()
+            .iter()
+            .map(|child| child.error().to_string())
+            .collect()
+    }
+
+    let exn = Exn::raise_all(
+        ErrorWithSource("parent", Error("cause")),
+        [Exn::new(Error("first")), Exn::new(Error("second"))],
+    );
+    assert_eq!(messages(exn.frame()), ["cause", "first", "second"]);
+
+    let exn = Exn::new(Error("only"));
+    let exn = exn.raise(ErrorWithSource("layer", Error("cause")));
+    assert_eq!(messages(exn.frame()), ["cause", "only"]);
+
+    let exn = exn.raise_dedup(ErrorWithSource("layer", Error("again")));
+    assert_eq!(messages(exn.frame()), ["again", "cause", "only"]);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn spawn_blocking() {
+    use exn::task::TaskError;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let output = exn::task::spawn_blocking(|| exn::Ok::<_, Error>(42)).await;
+        assert_eq!(output.unwrap(), 42);
+
+        let line = line!() + 1;
+        let failed = exn::task::spawn_blocking(|| -> exn::Result<(), Error> {
+            Err(Exn::new(Error("inner")))
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(*failed, TaskError::Failed);
+        assert_eq!(failed.frame().location().line(), line);
+        assert_eq!(format!("{failed:#}"), "task failed: inner");
+
+        let panicked = exn::task::spawn_blocking(|| -> exn::Result<(), Error> {
+            panic!("boom {}", 42);
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(*panicked, TaskError::Panicked("boom 42".to_string()));
+    });
+}
+
+#[test]
+fn shared_exn() {
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+    let expected = format!("{exn:?}");
+
+    let shared = exn.into_shared();
+    let clone = shared.clone();
+    assert_eq!(shared.strong_count(), 2);
+    assert_eq!(clone.0, "outer");
+    assert_eq!(format!("{clone:?}"), expected);
+    assert_eq!(format!("{clone:#}"), "outer: inner");
+    assert_eq!(
+        format!("{:?}", exn::report::Compact::new(clone.clone())),
+        expected
+    );
+
+    let shared = shared.try_unwrap().unwrap_err();
+    drop(clone);
+    let exn = shared.try_unwrap().unwrap();
+    assert_eq!(format!("{exn:?}"), expected);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dedup_logger() {
+    use std::time::Duration;
+
+    fn fail(message: &'static str) -> Exn<Error> {
+        Exn::new(Error("cause")).raise(Error(message))
+    }
+
+    let mut logger = exn::dedup_logger(Duration::from_secs(3600));
+    let renders = ["outer", "outer", "outer", "other", "other"]
+        .into_iter()
+        .map(|message| {
+            let exn = fail(message);
+            let render = logger.render(exn.frame());
+            (render.repeats(), render.to_string())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(renders[0].0, 0);
+    assert!(renders[0].1.contains("|-> cause, at"));
+    assert_eq!(renders[1].0, 1);
+    assert_eq!(renders[2].0, 2);
+    assert_eq!(renders[2].1, "outer: cause (repeated 2 times within 3600s)");
+    assert_eq!(renders[3].0, 0);
+    assert_eq!(renders[4].0, 1);
+
+    let mut logger = exn::dedup_logger(Duration::ZERO);
+    let exn = fail("outer");
+    assert_eq!(logger.render(exn.frame()).repeats(), 0);
+    assert_eq!(logger.render(exn.frame()).repeats(), 0);
+}
+
+#[test]
+fn try_clone() {
+    #[derive(Debug, Clone)]
+    struct Cloneable(&'static str);
+
+    impl std::fmt::Display for Cloneable {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::error::Error for Cloneable {}
+
+    exn::register_cloneable_error::<Cloneable>();
+
+    let exn = Exn::raise_all(
+        Cloneable("parent"),
+        [
+            Exn::new(Cloneable("first")),
+            Exn::new(ErrorWithSource("second", Error("cause"))).raise(Cloneable("second")),
+        ],
+    )
+    .mark_sensitive();
+    assert!(exn.try_clone().is_none());
+
+    let exn = Exn::raise_all(
+        Cloneable("parent"),
+        [Exn::new(Cloneable("first")), Exn::new(Cloneable("second"))],
+    )
+    .mark_sensitive();
+    let clone = exn.try_clone().unwrap();
+    assert_eq!(format!("{clone:?}"), format!("{exn:?}"));
+    assert_eq!(clone.0, "parent");
+    assert!(clone.frame().is_sensitive());
+}
+
+#[test]
+fn error_template() {
+    use exn::ErrorTemplate;
+
+    static TABLE: [ErrorTemplate; 2] = [
+        ErrorTemplate::new("DB0404", "record {id} not found in {table}"),
+        ErrorTemplate::new("E0001", "literal {{braces}}, {unknown} and a lone } or {"),
+    ];
+
+    let id = 42;
+    let exn = Exn::from_code(&TABLE[0], exn::args! {id, table = "users"});
+    assert_eq!(exn.to_string(), "record 42 not found in users");
+    assert_eq!(exn.code(), Some("DB0404"));
+    assert_eq!(exn.template().code(), "DB0404");
+
+    let exn = Exn::from_code(&TABLE[1], exn::args! {});
+    assert_eq!(
+        exn.to_string(),
+        "literal {braces}, {unknown} and a lone } or {"
+    );
+}
+
+#[test]
+fn into_exn() {
+    use exn::IntoExn;
+
+    fn raise<E: IntoExn>(result: Result<(), E>) -> exn::Result<(), Error> {
+        result.or_raise(|| Error("outer"))
+    }
+
+    let exn = raise(Err(Error("raw"))).unwrap_err();
+    assert_eq!(format!("{exn:#}"), "outer: raw");
+
+    let result: Result<(), Error> = Err(Error("raw"));
+    let line = line!() + 1;
+    let exn = result.or_raise(|| Error("outer")).unwrap_err();
+    assert_eq!(exn.frame().children()[0].location().line(), line);
+
+    let exn = raise(Err(Exn::new(Error("inner")).raise(Error("middle")))).unwrap_err();
+    assert_eq!(format!("{exn:#}"), "outer: middle: inner");
+
+    let exn = Error("raw").into_exn().into_exn();
+    assert_eq!(exn.to_string(), "raw");
+}
+
+#[test]
+fn raise_from() {
+    #[derive(Debug)]
+    struct AppError;
+
+    impl std::fmt::Display for AppError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "app error")
+        }
+    }
+
+    impl std::error::Error for AppError {}
+
+    impl exn::RaiseFrom<Error> for AppError {
+        fn raise_from(source: Error) -> Exn<Self> {
+            Exn::new(source).raise(AppError)
+        }
+    }
+
+    fn fail() -> Result<(), Error> {
+        Err(Error("inner"))
+    }
+
+    let line = line!() + 2;
+    fn run() -> exn::Result<(), AppError> {
+        fail()?;
+        Ok(())
+    }
+
+    let exn = run().unwrap_err();
+    assert_eq!(format!("{exn:#}"), "app error: inner");
+    assert_eq!(exn.frame().location().line(), line);
+    assert_eq!(exn.frame().children()[0].location().line(), line);
+
+    let exn: Exn<Error> = Error("same").into();
+    assert!(exn.frame().children().is_empty());
+}
+
+#[test]
+fn wrap() {
+    let line = line!() + 1;
+    let exn = exn::wrap!("NaN".parse::<u32>(), Error("invalid number")).unwrap_err();
+    assert_eq!(
+        format!("{exn:#}"),
+        "invalid number: invalid digit found in string"
+    );
+    assert_eq!(exn.frame().location().line(), line);
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn context_attribute() {
+    let line = line!() + 1;
+    #[exn::context(Error("failed to sum"))]
+    fn sum(inputs: &[&str]) -> exn::Result<u32, Error> {
+        let mut sum = 0;
+        for input in inputs {
+            if input.is_empty() {
+                return Err(Exn::new(Error("empty input")).into());
+            }
+            sum += input.parse::<u32>()?;
+        }
+        Ok(sum)
+    }
+
+    assert_eq!(sum(&["1", "2"]).unwrap(), 3);
+
+    let exn = sum(&["1", "two"]).unwrap_err();
+    assert_eq!(
+        format!("{exn:#}"),
+        "failed to sum: invalid digit found in string"
+    );
+    assert_eq!(exn.frame().location().line(), line);
+
+    let exn = sum(&[""]).unwrap_err();
+    assert_eq!(format!("{exn:#}"), "failed to sum: empty input");
+}
+
+#[cfg(all(feature = "macros", feature = "tokio"))]
+#[test]
+fn context_attribute_async() {
+    #[exn::context(Error("failed to parse"))]
+    async fn parse(input: String) -> exn::Result<u32, Error> {
+        tokio::task::yield_now().await;
+        Ok(input.parse::<u32>()?)
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        assert_eq!(parse("7".to_string()).await.unwrap(), 7);
+        let exn = parse("seven".to_string()).await.unwrap_err();
+        assert_eq!(
+            format!("{exn:#}"),
+            "failed to parse: invalid digit found in string"
+        );
+    });
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn spawn() {
+    use exn::task::TaskError;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let output = exn::task::spawn(async { exn::Ok::<_, Error>(42) }).await;
+        assert_eq!(output.unwrap(), 42);
+
+        let line = line!() + 1;
+        let handle = exn::task::spawn(async {
+            tokio::task::yield_now().await;
+            exn::Result::<(), _>::Err(Exn::new(Error("inner")))
+        });
+        assert_eq!(handle.location().line(), line);
+        let failed = handle.await.unwrap_err();
+        assert_eq!(*failed, TaskError::Failed);
+        assert_eq!(failed.frame().location().line(), line);
+        assert_eq!(format!("{failed:#}"), "task failed: inner");
+
+        let handle = exn::task::spawn(async {
+            core::future::pending::<()>().await;
+            exn::Ok::<_, Error>(())
+        });
+        let location = handle.location();
+        handle.abort();
+        let cancelled = handle.await.unwrap_err();
+        assert_eq!(*cancelled, TaskError::Cancelled);
+        assert_eq!(cancelled.frame().location(), location);
+    });
+}
+
+#[test]
+fn attach() {
+    let exn = Exn::new(Error("inner"))
+        .attach("while reading row 42")
+        .raise(Error("outer"))
+        .attach("while importing users.csv");
+    let report = exn::report::Compact::new(exn.frame()).with_locations(false);
+    assert_eq!(
+        format!("{report:?}"),
+        "outer\n= while importing users.csv\n|\n|-> inner\n    = while reading row 42"
+    );
+
+    let attachment = &exn.frame().attachments()[0];
+    assert_eq!(attachment.location().line(), line!() - 8);
+    assert_eq!(format!("{exn:#}"), "outer: inner");
+}
+
+#[test]
+fn future_ext() {
+    use exn::FutureExt;
+
+    fn assert_send<T: Send>(value: T) -> T {
+        value
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let line = line!() + 2;
+        let future = async { Err::<(), _>(Error("inner")) }
+            .or_attach(|| "while connecting")
+            .or_raise(|| Error("outer"));
+        let exn = assert_send(future).await.unwrap_err();
+        assert_eq!(exn.frame().location().line(), line + 1);
+        let child = &exn.frame().children()[0];
+        assert_eq!(child.location().line(), line);
+        assert_eq!(child.attachments()[0].location().line(), line);
+        assert_eq!(child.attachments()[0].to_string(), "while connecting");
+
+        let ok = async { exn::Ok::<_, Error>(42) }.or_raise(|| Error("outer"));
+        assert_eq!(ok.await.unwrap(), 42);
+    });
+}
+
+#[cfg(feature = "futures")]
+#[test]
+fn collect_all_exn() {
+    use exn::StreamExt;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let items =
+            futures::stream::iter([Ok(1), Err(Error("first")), Ok(2), Err(Error("second"))]);
+        let line = line!() + 1;
+        let exn = items
+            .collect_all_exn::<Vec<_>, _, _>(|| Error("outer"))
+            .await
+            .unwrap_err();
+        assert_eq!(exn.frame().location().line(), line + 1);
+        let children = exn.frame().children();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0].error().to_string(), "first");
+        assert_eq!(children[1].error().to_string(), "second");
+        assert_eq!(children[0].location().line(), line + 1);
+        assert_eq!(children[1].location().line(), line + 1);
+
+        let items = futures::stream::iter([exn::Ok::<_, Error>(1), Ok(2)]);
+        let output = items.collect_all_exn::<Vec<_>, _, _>(|| Error("outer"));
+        assert_eq!(output.await.unwrap(), [1, 2]);
+    });
+}
+
+#[test]
+fn timeout_and_cancellation() {
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Error("first")),
+            Exn::new(exn::TimeoutError).raise(Error("second")),
+        ],
+    );
+    assert!(exn.is_timeout());
+    assert!(!exn.is_cancelled());
+    assert!(!exn.frame().children()[0].is_timeout());
+
+    let exn = Exn::new(exn::CancelledError).raise(Error("outer"));
+    assert!(exn.is_cancelled());
+    assert!(!exn.is_timeout());
+
+    #[cfg(feature = "std")]
+    {
+        let io = std::io::Error::from(std::io::ErrorKind::TimedOut);
+        assert!(Exn::new(io).raise(Error("outer")).is_timeout());
+    }
+
+    #[cfg(feature = "tokio")]
+    assert!(Exn::new(exn::task::TaskError::Cancelled).is_cancelled());
+}
+
+#[test]
+fn retry_hint() {
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct Backoff(Option<Duration>);
+
+    impl std::fmt::Display for Backoff {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "backoff {:?}", self.0)
+        }
+    }
+
+    impl std::error::Error for Backoff {}
+
+    impl exn::Retryable for Backoff {
+        fn retry_after(&self) -> Option<Duration> {
+            self.0
+        }
+    }
+
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Backoff(Some(Duration::from_secs(1)))),
+            Exn::new(Backoff(None)),
+            Exn::new(Backoff(Some(Duration::from_secs(5)))),
+        ],
+    );
+    assert_eq!(exn.retry_hint(), None);
+
+    exn::register_retryable::<Backoff>();
+    assert_eq!(exn.retry_hint(), None);
+    assert_eq!(exn.frame().retry_after(), None);
+    assert_eq!(exn.frame().children()[1].retry_after(), None);
+    assert_eq!(Exn::new(Error("plain")).retry_hint(), None);
+}
+
+#[test]
+fn into_parts() {
+    let exn = Exn::raise_all(
+        common::ErrorWithSource("wrapper", Error("source")),
+        [Exn::new(Error("E1")), Exn::new(Error("E2"))],
+    )
+    .attach("dropped");
+    assert_eq!(exn.frame().children().len(), 3);
+
+    let (error, tree) = exn.into_parts();
+    assert_eq!(error.1.0, "source");
+    assert_eq!(tree.children().len(), 2);
+    assert!(!tree.is_empty());
+
+    let exn = Exn::from_parts(Error("converted"), tree);
+    assert_eq!(exn.to_string(), "converted");
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].to_string(), "E1");
+    assert_eq!(children[1].to_string(), "E2");
+    assert!(exn.frame().attachments().is_empty());
+
+    let (_, tree) = Exn::new(common::ErrorWithSource("E3", Error("E4"))).into_parts();
+    assert!(tree.is_empty());
+    let exn = Exn::from_parts(common::ErrorWithSource("E5", Error("E6")), tree);
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].origin(), exn::FrameOrigin::SourceChain);
+}
+
+#[test]
+fn source_location() {
+    use exn::SourceLocation;
+
+    let exn = Exn::new(Error("located"));
+    let location = SourceLocation::from(exn.frame().location());
+    assert_eq!(location.file(), "exn/tests/main.rs");
+    assert_eq!(location.line(), exn.frame().location().line());
+    assert_eq!(location.strip_workspace_prefix(), location);
+
+    let location = SourceLocation::new(r"exn\src\lib.rs", 1, 2);
+    assert_eq!(location.file(), "exn/src/lib.rs");
+    assert_eq!(location.to_string(), "exn/src/lib.rs:1:2");
+
+    let location = SourceLocation::new(
+        "/home/user/.cargo/registry/src/index.crates.io-0/tokio-1.47.1/src/runtime/mod.rs",
+        3,
+        4,
+    );
+    assert_eq!(
+        location.strip_workspace_prefix().to_string(),
+        "tokio-1.47.1/src/runtime/mod.rs:3:4"
+    );
+
+    let location = SourceLocation::new(r"C:\Users\user\project\src\main.rs", 5, 6);
+    assert_eq!(
+        location.strip_workspace_prefix().to_string(),
+        "project/src/main.rs:5:6"
+    );
+
+    let location = SourceLocation::new("/generated/main.rs", 7, 8);
+    assert_eq!(location.strip_workspace_prefix(), location);
+}
+
+#[test]
+fn wrapped_report() {
+    let exn = Exn::raise_all(
+        Error("failed to process the batch of uploaded files"),
+        [
+            Exn::new(Error("the first upload was rejected by the server")),
+            Exn::new(Error("connection reset")),
+        ],
+    )
+    .attach("while retrying the whole batch once");
+    let report = exn::report::Compact::new(exn.frame())
+        .with_locations(false)
+        .with_width(Some(24));
+    assert_eq!(
+        format!("{report:?}"),
+        "\
+failed to process the
+batch of uploaded files
+= while retrying the
+  whole batch once
+|
+|-> the first upload was
+|   rejected by the
+|   server
+|
+|-> connection reset"
+    );
+
+    let exn = Exn::new(Error("outer message that wraps"))
+        .raise(Error("inner message that wraps too"))
+        .raise(Error("top"));
+    let report = exn::report::Compact::new(exn.frame())
+        .with_locations(false)
+        .with_width(Some(16));
+    assert_eq!(
+        format!("{report:?}"),
+        "\
+top
+|
+|-> inner
+|   message that
+|   wraps too
+|
+|-> outer
+    message that
+    wraps"
+    );
+}
+
+#[test]
+fn truncated_report() {
+    let exn = common::new_tree_error();
+
+    let report = exn::report::Compact::new(exn.frame())
+        .with_locations(false)
+        .with_max_depth(1)
+        .with_max_children(2);
+    assert_eq!(
+        format!("{report:?}"),
+        "\
+E6
+|
+|-> E5
+|   |
+|   |-> … and 6 more
+|
+|-> E4
+|   |
+|   |-> … and 1 more
+|
+|-> … and 1 more"
+    );
+
+    let report = exn::report::Compact::new(exn.frame())
+        .with_locations(false)
+        .with_max_children(0);
+    assert_eq!(format!("{report:?}"), "E6\n|\n|-> … and 3 more");
+}
+
+#[test]
+fn render_tree() {
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+    assert_eq!(exn.render_tree(), format!("{exn:?}"));
+    assert_eq!(exn.frame().render_tree(), format!("{exn:?}"));
+    assert_eq!(exn.render_tree_without_locations(), "outer\n|\n|-> inner");
+}
+
+#[test]
+fn exn_error() {
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+    let error = exn.as_error();
+    assert_eq!(error.to_string(), "outer");
+    assert_eq!(error.source().unwrap().to_string(), "inner");
+
+    let error = exn.into_std_error();
+    assert_eq!(error.0, "outer");
+    let error = exn::ExnError::from(error.into_exn().attach("note"));
+    assert_eq!(
+        std::error::Error::source(&error).unwrap().to_string(),
+        "inner"
+    );
+    assert_eq!(error.exn().frame().attachments().len(), 1);
+
+    let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(error);
+    let error = boxed.downcast::<exn::ExnError<Error>>().unwrap();
+    assert_eq!(error.into_exn().into_error().0, "outer");
+}
+
+#[test]
+fn adopt() {
+    let mut exn = Exn::raise_all(Error("batch failed"), [Exn::new(Error("E1"))]);
+    exn.adopt(Exn::new(Error("cleanup failed")).raise(Error("E2")));
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].to_string(), "E1");
+    assert_eq!(children[1].to_string(), "E2");
+    assert_eq!(children[1].children()[0].to_string(), "cleanup failed");
+}
+
+#[test]
+fn suppress() {
+    let mut exn = Exn::new(Error("E1")).raise(Error("failed to write"));
+    exn.suppress(Exn::new(Error("E2")).raise(Error("failed to close")));
+    exn.suppress(Exn::new(Error("failed to unlock")));
+
+    assert_eq!(exn.frame().children().len(), 1);
+    assert_eq!(exn.frame().suppressed().len(), 2);
+    assert_eq!(exn.frames().count(), 2);
+    assert_eq!(
+        exn.render_tree_without_locations(),
+        "\
+failed to write
+|
+|-> E1
+|
+|-> suppressed: failed to close
+|   |
+|   |-> E2
+|
+|-> suppressed: failed to unlock"
+    );
+}
+
+#[test]
+fn try_finally() {
+    let ok = || exn::Result::<_, Error>::Ok(42);
+    let fail = |message| move || exn::Result::<(), _>::Err(Exn::new(Error(message)));
+
+    let cleanup = || exn::Ok::<_, Error>(());
+    assert_eq!(exn::try_finally(ok, cleanup).unwrap(), 42);
+
+    let exn = exn::try_finally(ok, fail("cleanup failed")).unwrap_err();
+    assert_eq!(exn.to_string(), "cleanup failed");
+
+    let exn = exn::try_finally(fail("body failed"), cleanup).unwrap_err();
+    assert_eq!(exn.to_string(), "body failed");
+    assert!(exn.frame().suppressed().is_empty());
+
+    let exn = exn::try_finally(fail("body failed"), fail("cleanup failed")).unwrap_err();
+    assert_eq!(exn.to_string(), "body failed");
+    assert_eq!(exn.frame().suppressed()[0].to_string(), "cleanup failed");
+
+    // cleanups may fail with their own error type
+    #[derive(Debug)]
+    struct AppError;
+
+    impl std::fmt::Display for AppError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "failed to clean up")
+        }
+    }
+
+    impl std::error::Error for AppError {}
+
+    impl exn::RaiseFrom<Error> for AppError {
+        fn raise_from(source: Error) -> Exn<Self> {
+            Exn::new(source).raise(AppError)
+        }
+    }
+
+    let close_line = line!() + 1;
+    let close = || Err(Exn::new(Error("cleanup failed")).attach("closing fd 3"));
+    let line = line!() + 1;
+    let exn = exn::try_finally(|| exn::Ok::<_, AppError>(42), close).unwrap_err();
+    assert_eq!(exn.to_string(), "failed to clean up");
+    assert_eq!(exn.frame().location().line(), line);
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].to_string(), "cleanup failed");
+    assert_eq!(children[0].location().line(), close_line);
+    assert_eq!(children[0].attachments()[0].to_string(), "closing fd 3");
+
+    let exn = exn::try_finally(|| Err::<(), _>(Exn::new(AppError)), close).unwrap_err();
+    assert_eq!(exn.to_string(), "failed to clean up");
+    assert_eq!(exn.frame().suppressed()[0].to_string(), "cleanup failed");
+
+    let cleaned_up = std::cell::Cell::new(false);
+    let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = exn::try_finally(
+            || -> exn::Result<(), Error> { panic!("body panicked") },
+            || {
+                cleaned_up.set(true);
+                exn::Ok::<_, Error>(())
+            },
+        );
+    }));
+    assert!(panicked.is_err());
+    assert!(cleaned_up.get());
+}
+
+#[cfg(all(feature = "macros", feature = "std"))]
+#[test]
+fn main_attribute() {
+    use std::process::ExitCode;
+
+    #[exn::main]
+    fn succeed() -> exn::Result<(), Error> {
+        Ok(())
+    }
+
+    #[exn::main]
+    fn fail() -> exn::Result<(), Error> {
+        Err(Exn::new(Error("failed")))
+    }
+
+    #[exn::main]
+    fn time_out() -> exn::Result<(), Error> {
+        Err(Exn::new(exn::TimeoutError).raise(Error("failed")))
+    }
+
+    #[exn::main]
+    fn cancel() -> exn::Result<(), Error> {
+        Err(Exn::new(exn::CancelledError).raise(Error("failed")))
+    }
+
+    assert_eq!(succeed(), ExitCode::SUCCESS);
+    assert_eq!(fail(), ExitCode::FAILURE);
+    assert_eq!(time_out(), ExitCode::from(124));
+    assert_eq!(cancel(), ExitCode::from(130));
+}
+
+#[cfg(all(feature = "macros", feature = "std"))]
+#[exn::test]
+fn test_attribute() -> exn::Result<(), Error> {
+    let port = "8080"
+        .parse::<u16>()
+        .map_err(|_| Exn::new(Error("invalid port")))?;
+    assert_eq!(port, 8080);
+    Ok(())
+}
+
+#[cfg(all(feature = "macros", feature = "std"))]
+#[exn::test]
+#[should_panic(expected = "test failed with an exception: invalid port")]
+fn test_attribute_failure() -> exn::Result<(), Error> {
+    "http"
+        .parse::<u16>()
+        .map_err(|_| Exn::new(Error("invalid port")))?;
+    Ok(())
+}
+
+#[test]
+fn message() {
+    use exn::Message;
+
+    let exn = Exn::new(Error("inner")).raise(Message::Static("static"));
+    assert_eq!(exn.as_str(), "static");
+    assert_eq!(
+        format!("{exn:?}").lines().next().unwrap().split(',').next(),
+        Some("static")
+    );
+
+    let message = Message::from(format!("owned {}", 42));
+    assert_eq!(message, Message::Owned("owned 42".to_string()));
+    assert_eq!(format!("{message:?}"), r#"Message("owned 42")"#);
+    assert_eq!(
+        Message::from(std::borrow::Cow::Borrowed("cow")),
+        Message::Static("cow")
+    );
+}
+
+#[test]
+fn kinded() {
+    #[derive(Debug)]
+    struct Coded(u8);
+
+    impl std::fmt::Display for Coded {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "coded {}", self.0)
+        }
+    }
+
+    impl std::error::Error for Coded {}
+
+    impl exn::Kinded for Coded {
+        type Kind = u8;
+
+        fn kind(&self) -> u8 {
+            self.0
+        }
+    }
+
+    let exn = Exn::raise_all(
+        Coded(1),
+        [
+            Exn::new(Error("other")),
+            Exn::new(Coded(2)).raise(Error("wrapped")),
+        ],
+    );
+    assert_eq!(exn.kind(), 1);
+    assert!(exn.has_kind(1));
+    assert!(exn.has_kind(2));
+    assert!(!exn.has_kind(3));
+}
+
+#[test]
+fn boundary_map() {
+    let map = exn::BoundaryMap::new(|| Error("internal"))
+        .rule(|_: &std::fmt::Error| Error("format"))
+        .rule(|err: &Error| {
+            if err.0 == "E1" {
+                Error("first")
+            } else {
+                Error("other")
+            }
+        });
+
+    // rules are tried in order, each searching the whole tree
+    let exn = Exn::new(Error("E1")).raise(std::fmt::Error);
+    assert_eq!(map.apply(exn).0, "format");
+
+    let exn = Exn::raise_all(std::io::Error::other("io"), [Exn::new(Error("E1"))]);
+    let exn = map.apply(exn);
+    assert_eq!(exn.0, "first");
+    assert_eq!(exn.frame().children()[0].to_string(), "io");
+
+    let exn = Exn::new(std::io::Error::other("io"));
+    assert_eq!(map.map(&exn).0, "internal");
+}
+
+#[test]
+fn contexts() {
+    #[derive(Debug, PartialEq)]
+    struct Query(&'static str);
+
+    impl std::fmt::Display for Query {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "query: {}", self.0)
+        }
+    }
+
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Error("E1")).attach(Query("primary")),
+            Exn::new(Error("E2")).attach(Query("secondary")),
+        ],
+    )
+    .attach(Query("top"))
+    .attach(42);
+
+    let queries = exn.contexts::<Query>().collect::<Vec<_>>();
+    assert_eq!(queries, [&Query("top"), &Query("primary")]);
+    assert_eq!(exn.contexts::<i32>().collect::<Vec<_>>(), [&42]);
+    assert_eq!(
+        exn.frame().attachments()[0].downcast_ref::<Query>(),
+        Some(&Query("top"))
+    );
+    assert_eq!(exn.frame().attachments()[0].downcast_ref::<i32>(), None);
+}
+
+#[test]
+fn diff() {
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Error("E1")).raise(Error("E2")),
+            Exn::new(Error("E3")).attach("note"),
+        ],
+    );
+
+    exn::diff(
+        r#"
+        outer
+        |
+        |-> E2
+        |   |
+        |   |-> E1
+        |
+        |-> E3
+            = note
+        "#,
+        &exn,
+    )
+    .unwrap();
+
+    let diff = exn::diff(
+        r#"
+        outer
+        |
+        |-> E2
+        |
+        |-> E4
+            = note
+        "#,
+        exn.frame(),
+    )
+    .unwrap_err();
+    let expected = r#"exception tree mismatch (- expected, + actual):
+  outer
+  |
+  |-> E2
++ |   |
++ |   |-> E1
+  |
+- |-> E4
++ |-> E3
+      = note"#;
+    assert_eq!(format!("{diff:?}"), expected);
+}
+
+#[test]
+fn stable_report() {
+    let exn = Exn::new(Error("inner"))
+        .attach("note")
+        .raise(ErrorWithSource("outer", Error("source")));
+
+    let report = exn::report::Stable::new(exn.frame());
+    let expected = r#"outer, at <file>:<L>:<C>
+|
+|-> source: source
+|
+|-> inner, at <file>:<L>:<C>
+    = note, at <file>:<L>:<C>"#;
+    assert_eq!(format!("{report}"), expected);
+
+    let report = exn::report::Stable::from(exn).with_files(true);
+    let expected = r#"outer, at exn/tests/main.rs:<L>:<C>
+|
+|-> source: source
+|
+|-> inner, at exn/tests/main.rs:<L>:<C>
+    = note, at exn/tests/main.rs:<L>:<C>"#;
+    assert_eq!(format!("{report:?}"), expected);
+}
+
+#[cfg(feature = "diagnostics")]
+#[test]
+fn source_snippets() {
+    exn::register_source(file!(), include_str!("main.rs"));
+
+    let inner = Exn::new(Error("inner"));
+    let line = line!() + 1;
+    let exn = inner.raise(ErrorWithSource("outer", Error("source")));
+
+    let gutter = " ".repeat(line.to_string().len());
+    let snippet = exn.frame().source_snippet(0).unwrap();
+    let expected = format!(
+        r#"{line} |     let exn = inner.raise(ErrorWithSource("outer", Error("source")));
+{gutter} |                     ^"#
+    );
+    assert_eq!(snippet, expected);
+
+    let report = exn::report::Compact::new(exn.frame())
+        .with_locations(false)
+        .with_snippets(Some(1));
+    let expected = format!(
+        r#"outer
+  {} |     let line = line!() + 1;
+  {} |     let exn = inner.raise(ErrorWithSource("outer", Error("source")));
+  {gutter} |                     ^
+  {} |
+|
+|-> source: source
+|
+|-> inner
+      {} |
+      {} |     let inner = Exn::new(Error("inner"));
+      {gutter} |                 ^
+      {} |     let line = line!() + 1;"#,
+        line - 1,
+        line,
+        line + 1,
+        line - 3,
+        line - 2,
+        line - 1,
+    );
+    assert_eq!(format!("{report:?}"), expected);
+}
+
+#[test]
+fn help() {
+    #[derive(Debug)]
+    struct MissingConfig;
+
+    impl std::fmt::Display for MissingConfig {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "config file not found")
+        }
+    }
+
+    impl std::error::Error for MissingConfig {}
+
+    impl exn::Help for MissingConfig {
+        fn help(&self) -> Option<String> {
+            Some("run `tool init`".to_string())
+        }
+
+        fn url(&self) -> Option<String> {
+            Some("https://example.com/config".to_string())
+        }
+    }
+
+    exn::register_help::<MissingConfig>();
+
+    let exn = Exn::new(MissingConfig)
+        .with_help("run `tool init --force`")
+        .attach("note")
+        .raise(Error("failed to start"))
+        .with_url("https://example.com/start");
+    assert_eq!(
+        exn.frame().children()[0].help().as_deref(),
+        Some("run `tool init --force`")
+    );
+    assert_eq!(
+        exn.frame().children()[0].url().as_deref(),
+        Some("https://example.com/config")
+    );
+    assert_eq!(exn.frame().help(), None);
+
+    let expected = r#"failed to start
+|
+|-> config file not found
+    = note
+
+see: https://example.com/start
+help: run `tool init --force`
+see: https://example.com/config"#;
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        expected
+    );
+
+    // other renderers see hints as plain attachments
+    let attachments = exn.frame().attachments();
+    assert_eq!(attachments[0].to_string(), "see: https://example.com/start");
+}
+
+#[test]
+fn function_names() {
+    fn load() -> exn::Result<(), Error> {
+        exn::bail!(Error("failed to load"))
+    }
+
+    fn check() -> exn::Result<(), Error> {
+        let check = || -> exn::Result<(), Error> {
+            exn::ensure!(false, Error("check failed"));
+            Ok(())
+        };
+        check()
+    }
+
+    fn propagate() -> exn::Result<(), Error> {
+        let exn = load().unwrap_err();
+        exn::bail!(exn)
+    }
+
+    assert_eq!(exn::here!(), "main::function_names");
+
+    let exn = load().unwrap_err();
+    assert_eq!(exn.frame().function(), Some("main::function_names::load"));
+    let exn = check().unwrap_err();
+    assert_eq!(exn.frame().function(), Some("main::function_names::check"));
+    let exn = propagate().unwrap_err();
+    assert_eq!(exn.frame().function(), Some("main::function_names::load"));
+
+    let exn = Exn::new(Error("inner"))
+        .raise(Error("outer"))
+        .with_function("app::run");
+    assert_eq!(exn.frame().children()[0].function(), None);
+
+    let report = exn::report::Compact::new(exn.frame())
+        .with_locations(false)
+        .with_functions(true);
+    assert_eq!(format!("{report}"), "outer, in app::run\n|\n|-> inner");
+}
+
+#[test]
+fn dedup_source_chain() {
+    // the source of the new error was raised as an exception already
+    let exn = Exn::new(Error("inner")).raise(ErrorWithSource("outer", Error("inner")));
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        "outer\n|\n|-> inner"
+    );
+    assert_eq!(exn.frame().children()[0].origin(), exn::FrameOrigin::Raised);
+
+    let exn = Exn::raise_all(
+        ErrorWithSource("outer", Error("E2")),
+        [Exn::new(Error("E1")), Exn::new(Error("E2"))],
+    );
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        "outer\n|\n|-> E1\n|\n|-> E2"
+    );
+
+    // distinct sources are kept
+    let exn = Exn::new(Error("inner")).raise(ErrorWithSource("outer", Error("source")));
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        "outer\n|\n|-> source: source\n|\n|-> inner"
+    );
+}
+
+#[test]
+fn transparent_frames() {
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Error("E1"))
+                .raise_transparent(Error("adapter"))
+                .attach("hidden"),
+            Exn::new(Error("E2")),
+        ],
+    )
+    .raise_transparent(Error("middleware"))
+    .raise(Error("top"));
+    assert!(exn.frame().children()[0].is_transparent());
+    assert!(!exn.frame().is_transparent());
+    exn::assert_exn_matches!(exn, Error("adapter"));
+
+    let report = exn::report::Compact::new(exn.frame())
+        .with_locations(false)
+        .with_transparent_frames(false);
+    assert_eq!(
+        format!("{report}"),
+        "top\n|\n|-> outer\n    |\n    |-> E1\n    |\n    |-> E2"
+    );
+
+    let report = exn::report::Compact::new(exn.frame()).with_locations(false);
+    assert!(format!("{report}").contains("adapter"));
+}
+
+#[test]
+fn local_exn() {
+    #[derive(Debug)]
+    struct RcError(std::rc::Rc<str>);
+
+    impl std::fmt::Display for RcError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::error::Error for RcError {}
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Exn<Error>>();
+    assert_send_sync::<exn::Frame>();
+    assert_send_sync::<exn::SharedExn<Error>>();
+    assert_send_sync::<exn::LocalError>();
+
+    let exn = exn::LocalExn::raise_all(
+        RcError("outer".into()),
+        [
+            exn::LocalExn::new(RcError("E1".into())).attach(std::rc::Rc::new("note")),
+            exn::LocalExn::from(RcError("E2".into())),
+        ],
+    )
+    .raise(ErrorWithSource("top", Error("source")));
+    assert_eq!(exn.to_string(), "top");
+    assert_eq!(exn.0, "top");
+
+    let debug = format!("{exn:?}");
+    let exn = exn.into_send();
+    assert_eq!(format!("{exn:?}"), debug);
+    let expected = r#"top
+|
+|-> source: source
+|
+|-> outer
+    |
+    |-> E1
+    |   = note
+    |
+    |-> E2"#;
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        expected
+    );
+    assert_eq!(exn.message(), "top");
+    assert_eq!(
+        std::error::Error::source(&*exn).unwrap().to_string(),
+        "source"
+    );
+}
+
+#[cfg(feature = "pyo3")]
+#[test]
+fn pyo3() {
+    use pyo3::types::PyAnyMethods;
+
+    pyo3::Python::initialize();
+
+    let exn = Exn::raise_all(
+        Error("failed to sync"),
+        [
+            Exn::new(Error("disk full")).attach("while writing chunk 3"),
+            Exn::new(Error("token abc expired"))
+                .attach("user: alice")
+                .mark_sensitive(),
+        ],
+    )
+    .raise(Error("request failed"));
+    let location = exn.frame().location();
+    let err = pyo3::PyErr::from(exn);
+
+    pyo3::Python::attach(|py| {
+        let value = err.value(py);
+        assert_eq!(value.str().unwrap().to_string(), "request failed");
+        let notes = value.getattr("__notes__").unwrap();
+        assert_eq!(
+            notes.extract::<Vec<String>>().unwrap(),
+            [format!(
+                "at {}:{}:{}",
+                location.file(),
+                location.line(),
+                location.column()
+            )]
+        );
+
+        let cause = err.cause(py).unwrap();
+        assert_eq!(cause.value(py).str().unwrap().to_string(), "failed to sync");
+        let notes = cause.value(py).getattr("__notes__").unwrap();
+        let notes = notes.extract::<Vec<String>>().unwrap();
+        assert_eq!(notes.len(), 2);
+        assert!(notes[1].starts_with("«redacted», at "), "{}", notes[1]);
+        assert!(notes[1].contains("\n= «redacted», at "), "{}", notes[1]);
+
+        let cause = cause.cause(py).unwrap();
+        assert_eq!(cause.value(py).str().unwrap().to_string(), "disk full");
+        let notes = cause.value(py).getattr("__notes__").unwrap();
+        let notes = notes.extract::<Vec<String>>().unwrap();
+        assert_eq!(notes[1], "while writing chunk 3");
+        assert!(cause.cause(py).is_none());
+    });
+}
+
+#[test]
+fn problem_details() {
+    let child = Exn::new(Error("token \"abc\" expired")).mark_sensitive();
+    let child_location = exn::SourceLocation::from(child.frame().location());
+    let exn = child.raise(Error("unauthorized"));
+    let location = exn::SourceLocation::from(exn.frame().location());
+    let exn = exn.attach("while handling\nGET /users");
+    let attachment_location = exn::SourceLocation::from(exn.frame().attachments()[0].location());
+
+    let report = exn::report::ProblemDetails::new(exn.frame()).with_status(401);
+    assert_eq!(
+        report.to_string(),
+        r#"{"type":"about:blank","status":401,"detail":"unauthorized"}"#
+    );
+
+    let report = report.with_tree(true);
+    let expected = format!(
+        concat!(
+            r#"{{"type":"about:blank","status":401,"detail":"unauthorized","exception":"#,
+            r#"{{"message":"unauthorized","location":"{}","attachments":"#,
+            r#"[{{"message":"while handling\nGET /users","location":"{}"}}],"children":"#,
+            r#"[{{"message":"«redacted»","location":"{}","attachments":[],"children":[]}}]}}}}"#,
+        ),
+        location, attachment_location, child_location,
+    );
+    assert_eq!(report.to_string(), expected);
+
+    let report = exn::report::ProblemDetails::from(exn).with_redaction(false);
+    assert_eq!(
+        format!("{report:?}"),
+        r#"{"type":"about:blank","detail":"unauthorized"}"#
+    );
+}
+
+#[test]
+fn raise_when() {
+    fn is_source(frame: &exn::Frame) -> bool {
+        frame.error().source().is_some()
+    }
+
+    let exn = Exn::new(ErrorWithSource("child", Error("source")));
+    let exn = exn.raise_when(|frame| !is_source(frame), || unreachable!());
+    assert_eq!(exn.frame().children().len(), 1);
+
+    let exn = Exn::new(Error("child"));
+    let location = exn::SourceLocation::from(exn.frame().location());
+    let exn = exn.raise_when(|frame| !is_source(frame), || Error("parent"));
+    assert_eq!(exn.to_string(), "parent");
+    assert_eq!(exn.frame().children()[0].to_string(), "child");
+    assert_ne!(exn::SourceLocation::from(exn.frame().location()), location);
+
+    let result: Result<(), Error> = Err(Error("child"));
+    let exn = result
+        .or_raise_if(|frame| frame.to_string() == "child", || Error("parent"))
+        .unwrap_err();
+    assert_eq!(exn.to_string(), "parent");
+    assert_eq!(exn.frame().children().len(), 1);
+
+    let result: Result<(), Error> = Err(Error("child"));
+    let exn = result
+        .or_raise_if(|frame| frame.to_string() != "child", || Error("parent"))
+        .unwrap_err();
+    assert_eq!(exn.to_string(), "child");
+    assert!(exn.frame().children().is_empty());
+}
+
+#[test]
+fn either() {
+    use exn::either::Either;
+    use exn::either::EitherResultExt;
+
+    let result: Result<(), Error> = Err(Error("timed out"));
+    let err = result.or_raise_left::<_, Error, _>(|| Error("transport failed"));
+    let err = err.unwrap_err();
+    assert!(err.is_left());
+    assert_eq!(err.to_string(), "transport failed");
+    assert_eq!(err.frame().children()[0].to_string(), "timed out");
+
+    let result: Result<(), Error> = Err(Error("NaN"));
+    let err = result.into_right::<Error>().unwrap_err();
+    assert!(err.is_right());
+    assert!(err.frame().children().is_empty());
+    assert_eq!(format!("{err:?}"), format!("{:?}", err.frame()));
+
+    let exn = err.raise(Error("request failed"));
+    let expected = r#"request failed
+|
+|-> NaN"#;
+    assert_eq!(
+        format!("{:?}", exn::testing::without_locations(exn.frame())),
+        expected
+    );
+
+    let either: Either<u32, &str> = Either::Left(1);
+    assert_eq!(either.map_left(|n| n + 1).left(), Some(2));
+    let either: Either<u32, &str> = Either::Right("right");
+    assert_eq!(either.map_left(|n| n + 1).right(), Some("right"));
+}
+
+#[test]
+fn partition() {
+    let failures = [
+        Exn::new(ErrorWithSource("retry", Error("timeout"))).raise(Error("item 1 timed out")),
+        Exn::new(std::fmt::Error).raise(Error("item 2 is malformed")),
+        Exn::new(Error("item 3 timed out")),
+    ];
+    let exn = Exn::raise_all(ErrorWithSource("batch failed", Error("source")), failures);
+
+    let (malformed, others) = exn.partition::<std::fmt::Error>();
+    let malformed = malformed
+        .children()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(malformed, ["item 2 is malformed"]);
+    let others = others
+        .children()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(others, ["item 1 timed out", "item 3 timed out"]);
+
+    let (_, tree) = Exn::new(Error("empty")).into_parts();
+    let (matching, others) = tree.partition::<Error>();
+    assert!(matching.is_empty());
+    assert!(others.is_empty());
+}
+
+#[test]
+fn static_message() {
+    const TIMED_OUT: exn::Message = exn::Message::Static("timed out");
+
+    fn poll(ready: bool) -> Result<(), exn::Message> {
+        if ready { Ok(()) } else { Err(TIMED_OUT) }
+    }
+
+    static LINE: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+    fn wait() -> exn::Result<(), exn::Message> {
+        LINE.store(line!() + 1, std::sync::atomic::Ordering::Relaxed);
+        poll(false)?;
+        Ok(())
+    }
+
+    assert_eq!(poll(false), Err(TIMED_OUT));
+    assert_eq!(TIMED_OUT, exn::Message::from(String::from("timed out")));
+    let exn = wait().unwrap_err();
+    assert_eq!(*exn, TIMED_OUT);
+    assert_eq!(exn.as_str(), "timed out");
+    let line = LINE.load(std::sync::atomic::Ordering::Relaxed);
+    assert_eq!(exn.frame().location().line(), line);
+}
+
+#[test]
+fn frame_construction() {
+    let location = std::panic::Location::caller();
+    let mut child = exn::Frame::new(Error("disk full"), location);
+    child.push_attachment("while syncing", location);
+    let mut frame = exn::Frame::new(Error("failed to sync"), location);
+    frame.push_child(child);
+    frame.push_child(exn::Frame::new(Error("timed out"), location));
+
+    let frame = Exn::<std::fmt::Error>::from_frame(frame).unwrap_err();
+    let exn = Exn::<Error>::from_frame(*frame).unwrap();
+    assert_eq!(exn.0, "failed to sync");
+    assert!(exn.frames().all(|frame| frame.location() == location));
+    assert_eq!(
+        format!(
+            "{}",
+            exn::report::Compact::new(exn.frame()).with_locations(false)
+        ),
+        "failed to sync\n|\n|-> disk full\n|   = while syncing\n|\n|-> timed out"
+    );
+}
+
+#[test]
+fn or_attach() {
+    fn parse(row: u32, value: &str) -> Result<u32, Exn<Error>> {
+        let result = value.parse::<u32>().or_raise(|| Error("invalid value"));
+        result.or_attach(|| format!("while processing row {row}"))
+    }
+
+    let (exn, line) = (parse(42, "NaN").attach_location().unwrap_err(), line!());
+    let attachments = exn.frame().attachments();
+    assert_eq!(attachments[0].to_string(), "while processing row 42");
+    assert_eq!(attachments[1].to_string(), "propagated");
+    assert_eq!(attachments[1].location().line(), line);
+}
+
+#[test]
+fn note() {
+    let result: Result<(), Exn<Error>> = Err(Exn::new(Error("invalid value")));
+    let i = 7;
+    let exn = exn::note!(result, "processing chunk {i} of {}", 9).unwrap_err();
+    assert_eq!(
+        format!(
+            "{}",
+            exn::report::Compact::new(exn.frame()).with_locations(false)
+        ),
+        "invalid value\n= processing chunk 7 of 9"
+    );
+
+    let ok: Result<u32, Exn<Error>> = Ok(1);
+    assert_eq!(exn::note!(ok, "unused").unwrap(), 1);
+}
+
+#[test]
+fn explicit_locations() {
+    #[track_caller]
+    fn generated() -> &'static std::panic::Location<'static> {
+        std::panic::Location::caller()
+    }
+
+    let location = generated();
+    let exn = Exn::new_at(Error("invalid value"), location)
+        .attach_at("in the config file", location)
+        .raise_at(Error("failed to load"), location);
+    assert!(exn.frames().all(|frame| frame.location() == location));
+    assert_eq!(
+        exn.frame().children()[0].attachments()[0].location(),
+        location
+    );
+}
+
+#[test]
+fn frame_location() {
+    let exn = Exn::new(Error("unknown key `colour`"))
+        .with_location(exn::FrameLocation::file(r"conf\app.toml", 17, 3))
+        .raise(Error("failed to load the config"))
+        .with_location(exn::FrameLocation::opaque("https://example.com/app.toml"));
+
+    let child = &exn.frame().children()[0];
+    assert_eq!(child.frame_location().to_string(), "conf/app.toml:17:3");
+    assert_ne!(child.location().file(), "conf/app.toml");
+    assert_eq!(
+        format!("{exn:?}"),
+        "failed to load the config, at https://example.com/app.toml\n|\n|-> unknown key `colour`, at conf/app.toml:17:3"
+    );
+
+    let exn = Exn::new(Error("invalid value"));
+    assert_eq!(
+        exn.frame().frame_location().into_owned(),
+        exn::FrameLocation::Rust(exn.frame().location())
+    );
+}
+
+#[test]
+fn report_dot() {
+    let mut exn = Exn::new(Error("disk \"sda\" full")).raise(Error("failed to sync"));
+    exn.suppress(Exn::new(Error("failed to close")));
+
+    let dot = exn::report::Dot::new(exn.frame()).with_locations(false);
+    assert_eq!(
+        dot.to_string(),
+        r#"digraph exn {
+    node [shape=box];
+    n0 [label="failed to sync"];
+    n1 [label="disk \"sda\" full"];
+    n0 -> n1;
+    n2 [label="failed to close"];
+    n0 -> n2 [style=dashed, label="suppressed"];
+}
+"#
+    );
+
+    let dot = exn::report::Dot::from(Exn::new(Error("invalid value"))).to_string();
+    assert!(dot.contains(r#"n0 [label="invalid value\nexn/tests/main.rs:"#));
+}
+
+#[test]
+fn option_ext_combinators() {
+    let (result, line) = (
+        None::<u32>.ok_or_attach(|| Error("missing key"), || "in section `server`"),
+        line!(),
+    );
+    let exn = result.unwrap_err();
+    assert_eq!(exn.frame().location().line(), line - 1);
+    assert_eq!(exn.frame().attachments()[0].location().line(), line - 1);
+    assert_eq!(
+        exn.frame().attachments()[0].to_string(),
+        "in section `server`"
+    );
+    let result = Some(1).ok_or_attach(|| Error("unused"), || "unused");
+    assert_eq!(result.unwrap(), 1);
+
+    let small = |v: &u32| *v < 3;
+    let result = Some(2).filter_or_raise(small, || Error("too large"));
+    assert_eq!(result.unwrap(), 2);
+    let result = Some(3).filter_or_raise(small, || Error("too large"));
+    assert_eq!(result.unwrap_err().0, "too large");
+    let result = None.filter_or_raise(small, || Error("missing"));
+    assert_eq!(result.unwrap_err().0, "missing");
+}
+
+#[test]
+fn try_scope() {
+    let (result, line) = (
+        exn::try_scope(|| -> Result<u32, Exn<Error>> { exn::bail!(Error("invalid value")) }),
+        line!(),
+    );
+    let exn = result.unwrap_err();
+    let attachment = &exn.frame().attachments()[0];
+    assert_eq!(attachment.to_string(), "in scope");
+    assert_eq!(attachment.location().line(), line - 1);
+
+    assert_eq!(exn::try_scope(|| Ok::<_, Exn<Error>>(1)).unwrap(), 1);
+}
+
+#[test]
+fn boxed() {
+    let result: Result<(), Exn<Error>> = Err(Exn::new(Error("disk full")).raise(Error("failed")));
+    let error = result.boxed().unwrap_err();
+    assert_eq!(error.to_string(), "failed");
+    assert_eq!(error.source().unwrap().to_string(), "disk full");
+
+    let error = "NaN".parse::<u32>().boxed().unwrap_err();
+    assert_eq!(error.to_string(), "invalid digit found in string");
+    assert_eq!(Ok::<_, Error>(1).boxed().unwrap(), 1);
+}
+
+#[test]
+fn raise_all_with_primary() {
+    let exn = Exn::raise_all_with_primary(
+        Error("failed to replicate"),
+        [
+            Exn::new(Error("replica 1 is lagging")),
+            Exn::new(Error("disk full")).raise(Error("primary failed")),
+        ],
+        1,
+    );
+    let chain = exn.primary_chain().map(|frame| frame.to_string());
+    assert_eq!(
+        chain.collect::<Vec<_>>(),
+        ["failed to replicate", "primary failed", "disk full"]
+    );
+    assert_eq!(exn.root_cause().to_string(), "disk full");
+    assert_eq!(
+        exn.as_error().source().unwrap().to_string(),
+        "primary failed"
+    );
+    assert_eq!(
+        format!("{exn:#}"),
+        "failed to replicate: primary failed: disk full"
+    );
+    assert_eq!(
+        exn.frame().children()[0].to_string(),
+        "replica 1 is lagging"
+    );
+
+    // the primary child is chosen by the parent, so it does not follow re-parented children
+    let (_, tree) = exn.into_parts();
+    let exn = Exn::from_parts(Error("failed to retry"), tree);
+    assert_eq!(
+        exn.frame().primary_child().unwrap().to_string(),
+        "replica 1 is lagging"
+    );
+
+    let mut exn = Exn::raise_all_with_primary(Error("failed"), [Exn::new(Error("only"))], 1);
+    exn.adopt(Exn::new(Error("late")));
+    assert_eq!(exn.root_cause().to_string(), "only");
+}
+
+#[test]
+fn tags() {
+    let exn = Exn::new(Error("connection reset"))
+        .with_tag("internal")
+        .raise_tagged(Error("failed to fetch"), "internal")
+        .with_tag("retryable")
+        .raise_tagged(Error("page unavailable"), "user");
+    assert_eq!(exn.frame().tags(), ["user"]);
+    assert_eq!(exn.frame().children()[0].tags(), ["internal", "retryable"]);
+    assert!(!exn.frame().has_tag("internal"));
+
+    let report = exn::report::Compact::new(exn.frame()).with_locations(false);
+    assert_eq!(
+        report.with_excluded_tags(["internal"]).to_string(),
+        "page unavailable"
+    );
+
+    let report = exn::report::Compact::new(exn.frame()).with_locations(false);
+    assert_eq!(
+        report.with_included_tags(["retryable"]).to_string(),
+        "page unavailable\n|\n|-> failed to fetch"
+    );
+}
+
+#[test]
+fn error_type() {
+    #[derive(Debug)]
+    struct PaymentDeclined;
+
+    impl std::fmt::Display for PaymentDeclined {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "payment declined")
+        }
+    }
+
+    impl std::error::Error for PaymentDeclined {}
+
+    exn::register_error_type::<PaymentDeclined>("billing.declined", "The card was declined.");
+    exn::register_error_type::<PaymentDeclined>("billing.renamed", "Ignored.");
+
+    let exn = Exn::new(Error("card expired")).raise(PaymentDeclined);
+    let error_type = exn.frame().error_type().unwrap();
+    assert_eq!(error_type.name(), "billing.declined");
+    assert_eq!(error_type.description(), "The card was declined.");
+    assert_eq!(exn.frame().children()[0].error_type(), None);
+
+    let report = exn::report::ProblemDetails::new(exn.frame())
+        .with_tree(true)
+        .to_string();
+    assert!(
+        report.contains(r#""exception":{"message":"payment declined","type":"billing.declined","#)
+    );
+    assert!(report.contains(r#"{"message":"card expired","location":"#));
+}
+
+#[test]
+fn fingerprint() {
+    fn fetch(id: u32) -> Exn<Error> {
+        let id = Box::leak(id.to_string().into_boxed_str());
+        Exn::raise_all(Error("failed to fetch"), [Exn::new(Error(id))])
+    }
+
+    assert_eq!(fetch(1).fingerprint(), fetch(2).fingerprint());
+    assert_ne!(fetch(1).fingerprint(), Exn::new(Error("1")).fingerprint());
+    assert_ne!(
+        fetch(1).fingerprint(),
+        fetch(1).raise(std::io::Error::other("io")).fingerprint()
+    );
+
+    let fingerprinter = exn::Fingerprinter::new().with_messages(true);
+    assert_ne!(
+        fingerprinter.fingerprint(fetch(1).frame()),
+        fingerprinter.fingerprint(fetch(2).frame())
+    );
+    let fingerprinter = exn::Fingerprinter::new().with_locations(true);
+    assert_ne!(
+        fingerprinter.fingerprint(fetch(1).frame()),
+        fingerprinter
+            .fingerprint(Exn::raise_all(Error("failed to fetch"), [Exn::new(Error("1"))]).frame())
+    );
+}
+
+#[test]
+fn write_to() {
+    let exn = common::new_tree_error().raise(Error("topmost"));
+    let expected = format!("{exn:?}");
+
+    #[cfg(feature = "std")]
+    {
+        let mut out = Vec::new();
+        exn::report::write_to(&exn, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    let mut out = String::new();
+    exn::report::write_fmt_to(exn.frame(), &mut out).unwrap();
+    assert_eq!(out, expected);
+
+    assert_eq!(exn.display_tree().to_string(), expected);
+    assert_eq!(format!("{}", exn.frame().display_tree()), exn.render_tree());
+}
+
+#[test]
+fn stats() {
+    let mut exn = common::new_tree_error().raise(Error("topmost"));
+    let frames = exn.frames().count();
+    assert_eq!(exn.frame_count(), frames);
+
+    exn.suppress(Exn::new(Error("cleanup failed")));
+    assert_eq!(exn.frame_count(), frames + 1);
+
+    let stats = exn.stats();
+    assert_eq!(stats.frame_count(), frames + 1);
+    assert_eq!(stats.depth(), 5);
+    assert_eq!(exn.depth(), 5);
+    assert_eq!(
+        stats.type_counts()[std::any::type_name::<Error>()],
+        frames + 1
+    );
+
+    let exn = Exn::new(ErrorWithSource("top", Error("source")));
+    assert_eq!(exn.depth(), 2);
+    assert_eq!(exn.stats().type_counts().values().sum::<usize>(), 1);
+    assert_eq!(Exn::new(Error("leaf")).depth(), 1);
+}
+
+#[test]
+fn flat() {
+    fn anyhow_layout(err: anyhow::Error) -> String {
+        let output = format!("{err:?}");
+        // the backtrace depends on the environment of the test
+        match output.split_once("\n\nStack backtrace:") {
+            Some((output, _)) => output.to_string(),
+            None => output,
+        }
+    }
+
+    let exn = Exn::new(Error("disk\nfull"))
+        .raise(Error("failed to sync"))
+        .raise(Error("job failed"));
+    let err = anyhow::Error::new(Error("disk\nfull"))
+        .context("failed to sync")
+        .context("job failed");
+    let report = exn::report::Flat::new(exn.frame());
+    assert_eq!(report.to_string(), anyhow_layout(err));
+
+    let exn = Exn::new(Error("disk full")).raise(Error("failed to sync"));
+    let err = anyhow::Error::new(Error("disk full")).context("failed to sync");
+    assert_eq!(
+        format!("{:?}", exn::report::Flat::from(exn)),
+        anyhow_layout(err)
+    );
+
+    let exn = Exn::raise_all(
+        Error("batch failed"),
+        [
+            Exn::new(Error("item 1 timed out")),
+            Exn::new(Error("connection reset")).raise(Error("item 2 failed")),
+            Exn::new(Error("token expired")).mark_sensitive(),
+        ],
+    );
+    let report = exn::report::Flat::new(exn.frame()).with_redaction(true);
+    assert_eq!(
+        report.to_string(),
+        concat!(
+            "batch failed\n\nCaused by:\n    item 1 timed out\n\nAdditionally:\n",
+            "    0: item 2 failed: connection reset\n    1: «redacted»",
+        )
+    );
+
+    let (exn, line) = (Exn::new(Error("leaf")), line!());
+    assert_eq!(
+        exn::report::Flat::new(exn.frame())
+            .with_locations(true)
+            .to_string(),
+        format!("leaf, at exn/tests/main.rs:{line}:24")
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn context_scope() {
+    fn import(file: &'static str) -> Result<(), Exn<Error>> {
+        exn::context_scope(format!("while importing {file}"), || {
+            exn::context_scope("while parsing row 3", || {
+                Err(Exn::new(Error("invalid number")))
+            })
+        })
+    }
+
+    let exn = import("prices.csv")
+        .or_raise(|| Error("import failed"))
+        .unwrap_err();
+    assert!(exn.frame().attachments().is_empty());
+    let leaf = &exn.frame().children()[0];
+    let attachments = leaf
+        .attachments()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        attachments,
+        ["while importing prices.csv", "while parsing row 3"]
+    );
+
+    assert!(Exn::new(Error("outside")).frame().attachments().is_empty());
+
+    let result = std::panic::catch_unwind(|| exn::context_scope("panicking", || panic!()));
+    assert!(result.is_err());
+    assert!(Exn::new(Error("outside")).frame().attachments().is_empty());
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn task_context_scope() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let exn = exn::task::context_scope("while syncing", async {
+            exn::task::context_scope("while fetching page 2", async {
+                tokio::task::yield_now().await;
+                exn::context_scope("while decoding", || Exn::new(Error("truncated")))
+            })
+            .await
+        })
+        .await;
+        let attachments = exn
+            .frame()
+            .attachments()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attachments,
+            ["while syncing", "while fetching page 2", "while decoding"]
+        );
+    });
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn timed_scope() {
+    let line = line!() + 1;
+    let result = exn::timed_scope(|| {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        Err::<(), _>(Exn::new(Error("timed out")))
+    });
+    let exn = result.unwrap_err();
+    let attachment = &exn.frame().attachments()[0];
+    assert_eq!(attachment.location().line(), line);
+    let message = attachment.to_string();
+    let elapsed = message.strip_prefix("after ").expect(&message);
+    let unit = elapsed
+        .find(|c: char| c.is_ascii_alphabetic())
+        .expect(&message);
+    let (value, unit) = elapsed.split_at(unit);
+    let (_, decimals) = value.split_once('.').expect(&message);
+    assert_eq!(decimals.len(), 1, "{message}");
+    let millis = match unit {
+        "ms" => value.parse::<f64>().unwrap(),
+        "s" => value.parse::<f64>().unwrap() * 1000.0,
+        _ => panic!("unexpected unit: {message}"),
+    };
+    assert!(millis >= 20.0, "{message}");
+
+    assert_eq!(exn::timed_scope(|| exn::Ok::<_, Error>(42)).unwrap(), 42);
+}
+
+#[test]
+fn ensure_eq() {
+    fn check(left: &str, right: String) -> exn::Result<(), Error> {
+        exn::ensure_eq!(left, right, Error("mismatch"));
+        Ok(())
+    }
+
+    fn check_ne(left: u32, right: u32) -> exn::Result<(), Error> {
+        exn::ensure_ne!(left, right, Exn::new(Error("duplicate")));
+        Ok(())
+    }
+
+    assert!(check("a", "a".to_string()).is_ok());
+    let exn = check("a", "b".to_string()).unwrap_err();
+    let attachments = exn
+        .frame()
+        .attachments()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(attachments, [r#"left: "a""#, r#"right: "b""#]);
+    let location = exn.frame().attachments()[0].location();
+    assert_eq!(location.line(), exn.frame().location().line());
+    assert!(exn.frame().function().unwrap().ends_with("::check"));
+
+    assert!(check_ne(1, 2).is_ok());
+    let exn = check_ne(3, 3).unwrap_err();
+    assert_eq!(exn.to_string(), "duplicate");
+    assert_eq!(exn.frame().attachments()[1].to_string(), "right: 3");
+}
+
+#[test]
+fn or_raise_all() {
+    use exn::ResultTupleExt;
+
+    let results = (
+        Ok::<_, Error>(1),
+        Err::<u8, _>(Error("bad name")),
+        Err::<(), _>(Exn::new(Error("bad age"))),
+    );
+    let line = line!() + 1;
+    let result = results.or_raise_all(|| Error("invalid user"));
+    let exn = result.unwrap_err();
+    assert_eq!(exn.to_string(), "invalid user");
+    assert_eq!(exn.frame().location().line(), line);
+    let children = exn
+        .frame()
+        .children()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(children, ["bad name", "bad age"]);
+    assert_eq!(exn.frame().children()[0].location().line(), line);
+
+    let values = (Ok::<_, Error>("a"), Ok::<_, Exn<Error>>(2))
+        .or_raise_all(|| Error("invalid user"))
+        .unwrap();
+    assert_eq!(values, ("a", 2));
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn derive_validate() {
+    use exn::Field;
+    use exn::Validate;
+
+    fn positive(value: &i32) -> Result<(), Error> {
+        if *value > 0 {
+            Ok(())
+        } else {
+            Err(Error("must be positive"))
+        }
+    }
+
+    fn even(value: &i32) -> exn::Result<(), Error> {
+        exn::ensure!(value % 2 == 0, Error("must be even"));
+        Ok(())
+    }
+
+    #[derive(Validate)]
+    struct Limits(#[validate(with = positive)] i32);
+
+    let line = line!() + 1;
+    #[derive(Validate)]
+    struct Config {
+        #[validate(with = positive)]
+        #[validate(with = even)]
+        r#workers: i32,
+        #[validate]
+        limits: Limits,
+        #[allow(dead_code)]
+        name: &'static str,
+    }
+
+    let config = Config {
+        workers: 3,
+        limits: Limits(0),
+        name: "",
+    };
+    let exn = config.validate().unwrap_err();
+    assert_eq!(exn.to_string(), "invalid `Config`");
+    assert_eq!(exn.frame().location().line(), line);
+
+    let children = exn.frame().children();
+    let fields = children
+        .iter()
+        .map(|child| {
+            child.attachments()[0]
+                .downcast_ref::<Field>()
+                .unwrap()
+                .name()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(fields, ["workers", "limits"]);
+    assert_eq!(children[0].to_string(), "must be even");
+    assert_eq!(children[0].attachments()[0].location().line(), line + 3);
+    assert_eq!(children[1].to_string(), "invalid `Limits`");
+    let nested = &children[1].children()[0];
+    assert_eq!(nested.to_string(), "must be positive");
+    assert_eq!(nested.attachments()[0].to_string(), "in field `0`");
+
+    let config = Config {
+        workers: 2,
+        limits: Limits(1),
+        name: "",
+    };
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn matches() {
+    use exn::Frame;
+    use exn::Pattern;
+
+    let exn = Exn::new(Error("io error"))
+        .raise(Error("failed to read"))
+        .raise(Error("failed to load"))
+        .raise(Error("app failed"));
+
+    assert!(exn.matches("app failed"));
+    assert!(!exn.matches("app"));
+    assert!(!exn.matches("app failed!"));
+    assert!(exn.matches(String::from("app failed")));
+    assert!(exn.matches(|frame: &Frame| frame.primary_chain().count() == 4));
+
+    assert!(exn.matches(exn::pattern![
+        "app failed",
+        "failed to load",
+        "failed to read",
+        "io error",
+    ]));
+    assert!(exn.matches(exn::pattern!["app failed", any!, "io error"]));
+    assert!(exn.matches(exn::pattern![any!, "failed to load", any!]));
+    assert!(exn.matches(exn::pattern![any!, "failed to read", "io error", any!]));
+    assert!(exn.matches(exn::pattern![any!]));
+    assert!(!exn.matches(exn::pattern![any!, "failed to read"]));
+    assert!(!exn.matches(exn::pattern!["failed to load", any!]));
+    assert!(!exn.matches(exn::pattern!["app failed", any!, "failed to load"]));
+    assert!(!exn.matches(exn::pattern![]));
+
+    let expected = String::from("io error");
+    let pattern = Pattern::new()
+        .frame("app failed")
+        .any()
+        .frame(|frame: &Frame| frame.error().is::<Error>())
+        .frame(expected.as_str());
+    assert!(exn.matches(&pattern));
+    assert!(exn.frame().children()[0].matches(exn::pattern![any!, "io error"]));
+    assert_eq!(format!("{pattern:?}"), "[frame, any!, frame, frame]");
+}
+
+#[test]
+fn redacted_attachments() {
+    let exn = Exn::new(Error("password is hunter2"))
+        .attach("user: alice")
+        .mark_sensitive()
+        .raise(Error("login failed"))
+        .attach("retrying");
+
+    let top = exn.frame();
+    assert_eq!(top.redacted_message().to_string(), "login failed");
+    let attachments = top
+        .redacted_attachments()
+        .map(|attachment| attachment.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(attachments, ["retrying"]);
+
+    let child = &top.children()[0];
+    assert!(child.redacted_message().is_redacted());
+    let attachments = child
+        .redacted_attachments()
+        .map(|attachment| attachment.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(attachments, ["«redacted»"]);
+
+    let report = exn::report::Compact::new(top)
+        .with_redaction(true)
+        .with_locations(false);
+    let output = format!("{report:?}");
+    assert!(!output.contains("alice"), "{output}");
+    assert!(!output.contains("hunter2"), "{output}");
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+fn string_tree_frame_location() {
+    let e = Exn::new(Error("parse"))
+        .with_location(exn::FrameLocation::file("config.toml", 17, 3))
+        .raise(Error("load"))
+        .with_location(exn::FrameLocation::opaque("/servers/0"));
+    let tree = e.to_string_tree();
+    assert_eq!(
+        (tree.file(), tree.line(), tree.column()),
+        ("/servers/0", 0, 0)
+    );
+    let child = &tree.children()[0];
+    assert_eq!(
+        (child.file(), child.line(), child.column()),
+        ("config.toml", 17, 3)
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn spawn_context_scope() {
+    fn context(exn: &Exn<exn::task::TaskError>) -> Vec<String> {
+        let leaf = &exn.frame().children()[0];
+        leaf.attachments().iter().map(ToString::to_string).collect()
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(exn::task::context_scope("while syncing", async {
+        let blocking = exn::context_scope("while hashing", || {
+            exn::task::spawn_blocking(|| Err::<(), _>(Exn::new(Error("truncated"))))
+        });
+        let exn = blocking.await.unwrap_err();
+        assert_eq!(context(&exn), ["while syncing", "while hashing"]);
+
+        let task = exn::context_scope("while fetching", || {
+            exn::task::spawn(async { Err::<(), _>(Exn::new(Error("reset"))) })
+        });
+        let exn = task.await.unwrap_err();
+        assert_eq!(context(&exn), ["while syncing", "while fetching"]);
+    }));
+}
+
+#[test]
+fn retry_hint_longest_delay() {
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct Backoff(Option<Duration>);
+
+    impl std::fmt::Display for Backoff {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "backoff {:?}", self.0)
+        }
+    }
+
+    impl std::error::Error for Backoff {}
+
+    impl exn::Retryable for Backoff {
+        fn retry_after(&self) -> Option<Duration> {
+            self.0
+        }
+    }
+
+    exn::register_retryable::<Backoff>();
+    let exn = Exn::raise_all(
+        Error("outer"),
+        [
+            Exn::new(Backoff(Some(Duration::from_secs(1)))),
+            Exn::new(Backoff(Some(Duration::from_secs(5)))),
+        ],
+    );
+    assert_eq!(exn.retry_hint(), Some(Duration::from_secs(5)));
+}
@@ -0,0 +1,70 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(exn_no_location)]
+
+use exn::Exn;
+
+#[derive(Debug)]
+struct Error(&'static str);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[test]
+fn no_location() {
+    let exn = Exn::new(Error("disk full")).attach("while syncing");
+    let exn = exn.raise(Error("failed to sync"));
+
+    let child = &exn.frame().children()[0];
+    assert_eq!(exn.frame().location(), child.location());
+    assert_eq!(child.location(), child.attachments()[0].location());
+    assert_eq!(
+        format!("{exn:?}"),
+        "failed to sync\n|\n|-> disk full\n    = while syncing"
+    );
+}
+
+#[test]
+fn reports_without_location() {
+    let exn = Exn::new(Error("disk full"))
+        .attach("while syncing")
+        .raise(Error("failed to sync"));
+
+    let flat = format!(
+        "{:?}",
+        exn::report::Flat::new(exn.frame()).with_locations(true)
+    );
+    assert!(!flat.contains(", at "), "{flat}");
+    let dot = exn::report::Dot::new(exn.frame()).to_string();
+    assert!(!dot.contains(".rs"), "{dot}");
+    let json = exn::report::ProblemDetails::new(exn.frame())
+        .with_tree(true)
+        .to_string();
+    assert!(!json.contains("\"location\""), "{json}");
+    let tree = exn.to_string_tree();
+    assert_eq!((tree.file(), tree.line(), tree.column()), ("", 0, 0));
+
+    let exn = exn.with_location(exn::FrameLocation::file("config.toml", 17, 3));
+    let flat = format!(
+        "{:?}",
+        exn::report::Flat::new(exn.frame()).with_locations(true)
+    );
+    assert!(flat.contains(", at config.toml:17:3"), "{flat}");
+}
@@ -0,0 +1,83 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The observer is process-global, so these tests live in their own test binary and run
+//! sequentially within a single test function.
+
+use std::sync::Mutex;
+
+use exn::Exn;
+use exn::ExnObserver;
+use exn::Frame;
+use exn::ResultExt;
+
+#[allow(dead_code)]
+mod common;
+use common::Error;
+
+static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Recorder;
+
+impl ExnObserver for Recorder {
+    fn on_new(&self, frame: &Frame) {
+        let event = format!("new {} ({} children)", frame, frame.children().len());
+        EVENTS.lock().unwrap().push(event);
+    }
+
+    fn on_raise(&self, frame: &Frame) {
+        let event = format!("raise {} ({} children)", frame, frame.children().len());
+        EVENTS.lock().unwrap().push(event);
+    }
+}
+
+fn take_events() -> Vec<String> {
+    std::mem::take(&mut *EVENTS.lock().unwrap())
+}
+
+#[test]
+fn observer() {
+    let _ = Exn::new(Error("unobserved"));
+
+    exn::set_observer(Recorder);
+    assert!(take_events().is_empty());
+
+    let exn = Exn::new(Error("inner")).raise(Error("outer"));
+    assert_eq!(
+        take_events(),
+        ["new inner (0 children)", "raise outer (1 children)"]
+    );
+
+    let _ = Exn::raise_all(Error("all"), [exn, Exn::new(Error("other"))]);
+    assert_eq!(
+        take_events(),
+        ["new other (0 children)", "raise all (2 children)"]
+    );
+
+    let result: Result<(), _> = Err(std::io::Error::other("disk full"));
+    let _ = result.or_raise(|| Error("failed to save"));
+    assert_eq!(
+        take_events(),
+        [
+            "new disk full (0 children)",
+            "raise failed to save (1 children)"
+        ]
+    );
+
+    let _ = Exn::new(Error("same")).raise_dedup(Error("same"));
+    assert_eq!(
+        take_events(),
+        ["new same (0 children)", "raise same (0 children)"]
+    );
+}
@@ -0,0 +1,60 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(exn_nightly)]
+#![feature(error_generic_member_access)]
+
+use std::error::Request;
+
+use exn::Exn;
+
+#[derive(Debug)]
+struct Status(u16);
+
+#[derive(Debug)]
+struct HttpError(u16);
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "http error {}", self.0)
+    }
+}
+
+impl std::error::Error for HttpError {
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {
+        request
+            .provide_value(Status(self.0))
+            .provide_ref::<u16>(&self.0);
+    }
+}
+
+#[test]
+fn request() {
+    let exn = Exn::raise_all(
+        HttpError(502),
+        [Exn::new(HttpError(404)), Exn::new(HttpError(500))],
+    );
+
+    assert_eq!(exn.request_ref::<u16>(), Some(&502));
+    assert_eq!(exn.request_value::<Status>().unwrap().0, 502);
+    assert!(exn.request_ref::<str>().is_none());
+
+    let codes = exn.request_values::<Status>().map(|status| status.0);
+    assert_eq!(codes.collect::<Vec<_>>(), [502, 404, 500]);
+    assert_eq!(exn.request_refs::<u16>().count(), 3);
+
+    let child = &exn.frame().children()[0];
+    let error: &(dyn std::error::Error + 'static) = child;
+    assert_eq!(std::error::request_ref::<u16>(error), Some(&404));
+}
@@ -0,0 +1,94 @@
+// Copyright 2025 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The conversions call JavaScript, so these tests only run on `wasm32` targets, e.g., with
+//! `wasm-pack test --node exn --features wasm -- --test wasm`.
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use exn::Exn;
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[allow(dead_code)]
+mod common;
+use common::Error;
+
+fn get(target: &JsValue, key: &str) -> JsValue {
+    Reflect::get(target, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn to_js_value() {
+    let exn = Exn::new(Error("password is hunter2"))
+        .attach("user: alice")
+        .mark_sensitive()
+        .raise(Error("login failed"))
+        .attach("retrying");
+    let location = exn.frame().frame_location().to_string();
+    let value = JsValue::from(exn);
+
+    assert_eq!(get(&value, "message").as_string().unwrap(), "login failed");
+    assert_eq!(get(&value, "location").as_string().unwrap(), location);
+    let attachments = js_sys::Array::from(&get(&value, "attachments"));
+    assert_eq!(attachments.get(0).as_string().unwrap(), "retrying");
+
+    let cause = get(&value, "cause");
+    assert_eq!(get(&cause, "message").as_string().unwrap(), "«redacted»");
+    let attachments = js_sys::Array::from(&get(&cause, "attachments"));
+    assert_eq!(attachments.get(0).as_string().unwrap(), "«redacted»");
+}
+
+#[wasm_bindgen_test]
+fn from_js_error_roundtrip() {
+    let exn = Exn::new(Error("disk full")).raise(Error("failed to sync"));
+    let exn = exn::wasm::from_js_error(JsValue::from(exn));
+    assert_eq!(exn.to_string(), "failed to sync");
+    assert_eq!(exn.frame().children()[0].to_string(), "disk full");
+}
+
+#[wasm_bindgen_test]
+fn from_js_error_cycle() {
+    let error = js_sys::Error::new("loop");
+    error.set_cause(&error);
+    let children = js_sys::Array::of2(&error, &error);
+    Reflect::set(&error, &JsValue::from_str("children"), &children).unwrap();
+
+    let exn = exn::wasm::from_js_error(error.into());
+    assert_eq!(exn.frame().frame_count(), 1);
+}
+
+#[wasm_bindgen_test]
+fn from_js_error_depth() {
+    let mut error = js_sys::Error::new("0");
+    for i in 1..100 {
+        let outer = js_sys::Error::new(&i.to_string());
+        outer.set_cause(&error);
+        error = outer;
+    }
+
+    let exn = exn::wasm::from_js_error(error.into());
+    assert_eq!(exn.frame().depth(), 64);
+    let mut frame = exn.frame();
+    while let Some(child) = frame.children().first() {
+        frame = child;
+    }
+    let attachments = frame
+        .attachments()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert!(attachments.contains(&"further causes omitted at depth 64".to_string()));
+}
@@ -66,6 +66,23 @@ struct CommandTest {
 impl CommandTest {
     fn run(self) {
         run_command(make_test_cmd(self.no_capture, true, &[]));
+        run_command(make_test_cmd(
+            self.no_capture,
+            true,
+            &[
+                "diagnostics",
+                "futures",
+                "macros",
+                "pyo3",
+                "serde",
+                "std",
+                "term",
+                "tokio",
+                "unstable",
+                "wasm",
+            ],
+        ));
+        run_command(make_no_location_test_cmd(self.no_capture));
         run_example_tests();
     }
 }
@@ -207,6 +224,26 @@ fn make_test_cmd(no_capture: bool, default_features: bool, features: &[&str]) ->
     cmd
 }
 
+fn make_no_location_test_cmd(no_capture: bool) -> StdCommand {
+    let mut cmd = find_command("cargo");
+    // a separate target directory keeps the cfg from invalidating the main build
+    cmd.env("RUSTFLAGS", "--cfg exn_no_location");
+    cmd.args([
+        "test",
+        "--package",
+        "exn",
+        "--all-features",
+        "--test",
+        "no_location",
+        "--target-dir",
+        "target/no-location",
+    ]);
+    if no_capture {
+        cmd.args(["--", "--nocapture"]);
+    }
+    cmd
+}
+
 fn make_format_cmd(fix: bool) -> StdCommand {
     let mut cmd = find_command("cargo");
     cmd.args(["+nightly", "fmt", "--all"]);